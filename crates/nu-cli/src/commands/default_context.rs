@@ -17,8 +17,11 @@ pub fn add_cli_context(mut engine_state: EngineState) -> EngineState {
             CommandlineGetCursor,
             CommandlineSetCursor,
             History,
+            HistoryDelete,
             HistoryImport,
+            HistorySearch,
             HistorySession,
+            HistoryStats,
             Keybindings,
             KeybindingsDefault,
             KeybindingsList,
@@ -140,7 +140,12 @@ impl Command for History {
     }
 }
 
-fn create_history_record(idx: usize, entry: HistoryItem, long: bool, head: Span) -> Value {
+pub(super) fn create_history_record(
+    idx: usize,
+    entry: HistoryItem,
+    long: bool,
+    head: Span,
+) -> Value {
     //1. Format all the values
     //2. Create a record of either short or long columns and values
 
@@ -0,0 +1,143 @@
+use nu_engine::command_prelude::*;
+use nu_protocol::{shell_error::io::IoError, HistoryFileFormat};
+use reedline::{
+    CommandLineSearch, History as ReedlineHistory, HistoryItemId, SearchDirection, SearchFilter,
+    SearchQuery, SqliteBackedHistory,
+};
+
+#[derive(Clone)]
+pub struct HistoryDelete;
+
+impl Command for HistoryDelete {
+    fn name(&self) -> &str {
+        "history delete"
+    }
+
+    fn description(&self) -> &str {
+        "Delete history entries by ID or by matching text."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"This is useful for purging secrets that were accidentally typed on the command
+line. An integer argument deletes the entry with that `item_id` (as shown by
+`history --long`); a string argument deletes every entry containing it.
+
+Only the sqlite history backend supports deleting individual entries. To avoid
+recording a command in the first place, start the line with a space, or add a
+regex for it to `$env.config.history.ignore`."#
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build("history delete")
+            .input_output_types(vec![(Type::Nothing, Type::Int)])
+            .required(
+                "match",
+                SyntaxShape::Any,
+                "The item ID (int) or text (string) of entries to delete.",
+            )
+            .category(Category::History)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                example: "history delete 123",
+                description: "Delete the history entry with item ID 123.",
+                result: None,
+            },
+            Example {
+                example: "history delete 'password'",
+                description: "Delete every history entry that contains 'password'.",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+
+        let Some(history) = engine_state.history_config() else {
+            return Ok(PipelineData::empty());
+        };
+        let Some(history_path) = history.file_path() else {
+            return Err(ShellError::ConfigDirNotFound { span: Some(head) });
+        };
+
+        if history.file_format != HistoryFileFormat::Sqlite {
+            return Err(ShellError::GenericError {
+                error: "Deleting individual history entries requires the sqlite history".into(),
+                msg: "the plain text history doesn't support this".into(),
+                span: Some(head),
+                help: Some(
+                    "set $env.config.history.file_format to \"sqlite\", or use `history --clear`"
+                        .into(),
+                ),
+                inner: vec![],
+            });
+        }
+
+        let matcher: Value = call.req(engine_state, stack, 0)?;
+
+        let mut history_writer = SqliteBackedHistory::with_file(history_path.clone(), None, None)
+            .map_err(|_| {
+            IoError::new(std::io::ErrorKind::NotFound, head, history_path.clone())
+        })?;
+
+        let ids: Vec<HistoryItemId> = match matcher {
+            Value::Int { val, .. } => vec![HistoryItemId::new(val)],
+            Value::String { ref val, .. } => {
+                let mut filter = SearchFilter::anything(None);
+                filter.command_line = Some(CommandLineSearch::Substring(val.clone()));
+                history_writer
+                    .search(SearchQuery {
+                        direction: SearchDirection::Forward,
+                        start_time: None,
+                        end_time: None,
+                        start_id: None,
+                        end_id: None,
+                        limit: None,
+                        filter,
+                    })
+                    .map_err(|err| ShellError::GenericError {
+                        error: "Failed to search history".into(),
+                        msg: err.to_string(),
+                        span: Some(head),
+                        help: None,
+                        inner: vec![],
+                    })?
+                    .into_iter()
+                    .filter_map(|entry| entry.id)
+                    .collect()
+            }
+            other => {
+                return Err(ShellError::RuntimeTypeMismatch {
+                    expected: Type::custom("int or string"),
+                    actual: other.get_type(),
+                    span: other.span(),
+                })
+            }
+        };
+
+        let mut deleted: i64 = 0;
+        for id in ids {
+            history_writer
+                .delete(id)
+                .map_err(|err| ShellError::GenericError {
+                    error: "Failed to delete history entry".into(),
+                    msg: err.to_string(),
+                    span: Some(head),
+                    help: None,
+                    inner: vec![],
+                })?;
+            deleted += 1;
+        }
+
+        Ok(Value::int(deleted, head).into_pipeline_data())
+    }
+}
@@ -0,0 +1,194 @@
+use nu_engine::command_prelude::*;
+use nu_protocol::{shell_error::io::IoError, HistoryFileFormat};
+use reedline::{
+    CommandLineSearch, FileBackedHistory, History as ReedlineHistory, SearchDirection,
+    SearchFilter, SearchQuery, SqliteBackedHistory,
+};
+
+use super::{fields, history_::create_history_record};
+
+#[derive(Clone)]
+pub struct HistorySearch;
+
+impl Command for HistorySearch {
+    fn name(&self) -> &str {
+        "history search"
+    }
+
+    fn description(&self) -> &str {
+        "Search the command history, with filters."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Searches are filtered by the history backend itself, which is faster than piping
+`history` through `where` for large histories.
+
+The `--cwd` and `--successful` filters rely on metadata that is only recorded
+when `$env.config.history.file_format` is "sqlite"."#
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build("history search")
+            .input_output_types(vec![(Type::Nothing, Type::table())])
+            .allow_variants_without_examples(true)
+            .optional(
+                "query",
+                SyntaxShape::String,
+                "Show only entries containing this substring.",
+            )
+            .switch(
+                "prefix",
+                "Match the query against the start of the command line instead",
+                Some('p'),
+            )
+            .named(
+                "cwd",
+                SyntaxShape::String,
+                "Show only entries that were run in this directory",
+                Some('d'),
+            )
+            .switch(
+                "successful",
+                "Show only entries that ran successfully (exit status 0)",
+                Some('s'),
+            )
+            .named(
+                "max-results",
+                SyntaxShape::Int,
+                "Limit the number of results, most recent first",
+                Some('n'),
+            )
+            .switch(
+                "long",
+                "Show long listing of entries for sqlite history",
+                Some('l'),
+            )
+            .category(Category::History)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                example: "history search cargo",
+                description: "Show history entries that contain 'cargo'.",
+                result: None,
+            },
+            Example {
+                example: "history search --prefix 'git ' --max-results 10",
+                description: "Show the last 10 commands that started with 'git '.",
+                result: None,
+            },
+            Example {
+                example: "history search --cwd /tmp --successful",
+                description: "Show commands that ran successfully in /tmp.",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+
+        let Some(history) = engine_state.history_config() else {
+            return Ok(PipelineData::empty());
+        };
+        let Some(history_path) = history.file_path() else {
+            return Err(ShellError::ConfigDirNotFound { span: Some(head) });
+        };
+
+        let query: Option<String> = call.opt(engine_state, stack, 0)?;
+        let prefix = call.has_flag(engine_state, stack, "prefix")?;
+        let cwd: Option<String> = call.get_flag(engine_state, stack, "cwd")?;
+        let successful = call.has_flag(engine_state, stack, "successful")?;
+        let max_results: Option<i64> = call.get_flag(engine_state, stack, "max-results")?;
+        let long = call.has_flag(engine_state, stack, "long")?;
+
+        let mut filter = SearchFilter::anything(None);
+        filter.command_line = query.map(|query| {
+            if prefix {
+                CommandLineSearch::Prefix(query)
+            } else {
+                CommandLineSearch::Substring(query)
+            }
+        });
+        filter.cwd_exact = cwd;
+        filter.exit_successful = successful.then_some(true);
+
+        let search_query = SearchQuery {
+            direction: SearchDirection::Backward,
+            start_time: None,
+            end_time: None,
+            start_id: None,
+            end_id: None,
+            limit: max_results,
+            filter,
+        };
+
+        let history_reader: Box<dyn ReedlineHistory> = match history.file_format {
+            HistoryFileFormat::Sqlite => {
+                SqliteBackedHistory::with_file(history_path.clone(), None, None)
+                    .map(|inner| {
+                        let boxed: Box<dyn ReedlineHistory> = Box::new(inner);
+                        boxed
+                    })
+                    .ok()
+            }
+            HistoryFileFormat::Plaintext => {
+                FileBackedHistory::with_file(history.max_size as usize, history_path.clone())
+                    .map(|inner| {
+                        let boxed: Box<dyn ReedlineHistory> = Box::new(inner);
+                        boxed
+                    })
+                    .ok()
+            }
+        }
+        .ok_or(IoError::new(
+            std::io::ErrorKind::NotFound,
+            head,
+            history_path.clone(),
+        ))?;
+
+        let entries =
+            history_reader
+                .search(search_query)
+                .map_err(|err| ShellError::GenericError {
+                    error: "Failed to search history".into(),
+                    msg: err.to_string(),
+                    span: Some(head),
+                    help: (history.file_format == HistoryFileFormat::Plaintext).then(|| {
+                        "the `--cwd` and `--successful` filters need \
+                        $env.config.history.file_format set to \"sqlite\""
+                            .into()
+                    }),
+                    inner: vec![],
+                })?;
+
+        let signals = engine_state.signals().clone();
+        match history.file_format {
+            HistoryFileFormat::Plaintext => Ok(entries
+                .into_iter()
+                .enumerate()
+                .map(move |(idx, entry)| {
+                    Value::record(
+                        record! {
+                            fields::COMMAND_LINE => Value::string(entry.command_line, head),
+                            "index" => Value::int(idx as i64, head),
+                        },
+                        head,
+                    )
+                })
+                .into_pipeline_data(head, signals)),
+            HistoryFileFormat::Sqlite => Ok(entries
+                .into_iter()
+                .enumerate()
+                .map(move |(idx, entry)| create_history_record(idx, entry, long, head))
+                .into_pipeline_data(head, signals)),
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use std::collections::{HashMap, HashSet};
+
+use nu_engine::command_prelude::*;
+use nu_protocol::{shell_error::io::IoError, HistoryFileFormat};
+use reedline::{
+    FileBackedHistory, History as ReedlineHistory, SearchDirection, SearchQuery,
+    SqliteBackedHistory,
+};
+
+#[derive(Clone)]
+pub struct HistoryStats;
+
+impl Command for HistoryStats {
+    fn name(&self) -> &str {
+        "history stats"
+    }
+
+    fn description(&self) -> &str {
+        "Summarize the command history."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Commands are counted by their first word, so `git push` and `git pull` both count
+towards `git`.
+
+The `sessions` and `by_hour` fields are only present when
+`$env.config.history.file_format` is "sqlite", since the plain text history
+doesn't record timestamps or sessions."#
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build("history stats")
+            .input_output_types(vec![(Type::Nothing, Type::record())])
+            .named(
+                "top",
+                SyntaxShape::Int,
+                "How many of the most used commands to show (default 10)",
+                Some('t'),
+            )
+            .category(Category::History)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                example: "history stats",
+                description: "Summarize the command history.",
+                result: None,
+            },
+            Example {
+                example: "history stats --top 3 | get top_commands",
+                description: "Show the three most used commands.",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+
+        let Some(history) = engine_state.history_config() else {
+            return Ok(PipelineData::empty());
+        };
+        let Some(history_path) = history.file_path() else {
+            return Err(ShellError::ConfigDirNotFound { span: Some(head) });
+        };
+
+        let top: usize = call
+            .get_flag::<i64>(engine_state, stack, "top")?
+            .map(|top| top.max(0) as usize)
+            .unwrap_or(10);
+
+        let history_reader: Box<dyn ReedlineHistory> = match history.file_format {
+            HistoryFileFormat::Sqlite => {
+                SqliteBackedHistory::with_file(history_path.clone(), None, None)
+                    .map(|inner| {
+                        let boxed: Box<dyn ReedlineHistory> = Box::new(inner);
+                        boxed
+                    })
+                    .ok()
+            }
+            HistoryFileFormat::Plaintext => {
+                FileBackedHistory::with_file(history.max_size as usize, history_path.clone())
+                    .map(|inner| {
+                        let boxed: Box<dyn ReedlineHistory> = Box::new(inner);
+                        boxed
+                    })
+                    .ok()
+            }
+        }
+        .ok_or(IoError::new(
+            std::io::ErrorKind::NotFound,
+            head,
+            history_path.clone(),
+        ))?;
+
+        let entries = history_reader
+            .search(SearchQuery::everything(SearchDirection::Forward, None))
+            .map_err(|err| ShellError::GenericError {
+                error: "Failed to read history".into(),
+                msg: err.to_string(),
+                span: Some(head),
+                help: None,
+                inner: vec![],
+            })?;
+
+        let mut command_counts: HashMap<String, i64> = HashMap::new();
+        let mut sessions: HashSet<String> = HashSet::new();
+        let mut by_hour = [0i64; 24];
+        let mut have_timestamps = false;
+
+        for entry in &entries {
+            if let Some(command) = entry.command_line.split_whitespace().next() {
+                *command_counts.entry(command.into()).or_default() += 1;
+            }
+            if let Some(session_id) = entry.session_id {
+                sessions.insert(session_id.to_string());
+            }
+            if let Some(time) = entry.start_timestamp {
+                use chrono::Timelike;
+                // Bucket by the user's local time, not UTC, so the histogram reflects their day
+                by_hour[time.with_timezone(&chrono::Local).hour() as usize] += 1;
+                have_timestamps = true;
+            }
+        }
+
+        let mut counts: Vec<(String, i64)> = command_counts.into_iter().collect();
+        // Sort by count descending, then by name so that ties are deterministic
+        counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+        let top_commands = counts
+            .iter()
+            .take(top)
+            .map(|(command, count)| {
+                Value::record(
+                    record! {
+                        "command" => Value::string(command, head),
+                        "count" => Value::int(*count, head),
+                    },
+                    head,
+                )
+            })
+            .collect();
+
+        let mut stats = record! {
+            "total_entries" => Value::int(entries.len() as i64, head),
+            "unique_commands" => Value::int(counts.len() as i64, head),
+            "top_commands" => Value::list(top_commands, head),
+        };
+
+        if history.file_format == HistoryFileFormat::Sqlite {
+            stats.push("sessions", Value::int(sessions.len() as i64, head));
+            if have_timestamps {
+                let by_hour = by_hour
+                    .iter()
+                    .enumerate()
+                    .map(|(hour, count)| {
+                        Value::record(
+                            record! {
+                                "hour" => Value::int(hour as i64, head),
+                                "count" => Value::int(*count, head),
+                            },
+                            head,
+                        )
+                    })
+                    .collect();
+                stats.push("by_hour", Value::list(by_hour, head));
+            }
+        }
+
+        Ok(Value::record(stats, head).into_pipeline_data())
+    }
+}
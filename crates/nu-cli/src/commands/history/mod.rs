@@ -1,8 +1,14 @@
 mod fields;
 mod history_;
+mod history_delete;
 mod history_import;
+mod history_search;
 mod history_session;
+mod history_stats;
 
 pub use history_::History;
+pub use history_delete::HistoryDelete;
 pub use history_import::HistoryImport;
+pub use history_search::HistorySearch;
 pub use history_session::HistorySession;
+pub use history_stats::HistoryStats;
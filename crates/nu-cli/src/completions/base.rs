@@ -39,6 +39,24 @@ pub enum SuggestionKind {
     Variable,
 }
 
+impl SuggestionKind {
+    /// The user-facing name of the kind, shown in menu descriptions when
+    /// `$env.config.completions.show_kind` is enabled.
+    pub fn name(&self) -> &'static str {
+        match self {
+            SuggestionKind::Command(_) => "command",
+            SuggestionKind::Value(_) => "value",
+            SuggestionKind::CellPath => "cell-path",
+            SuggestionKind::Directory => "directory",
+            SuggestionKind::File => "file",
+            SuggestionKind::Flag => "flag",
+            SuggestionKind::Module => "module",
+            SuggestionKind::Operator => "operator",
+            SuggestionKind::Variable => "variable",
+        }
+    }
+}
+
 impl From<Suggestion> for SemanticSuggestion {
     fn from(suggestion: Suggestion) -> Self {
         Self {
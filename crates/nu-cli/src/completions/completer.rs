@@ -1,17 +1,18 @@
 use crate::completions::{
     base::{SemanticSuggestion, SuggestionKind},
     AttributableCompletion, AttributeCompletion, CellPathCompletion, CommandCompletion, Completer,
-    CompletionOptions, CustomCompletion, DirectoryCompletion, DotNuCompletion,
-    ExportableCompletion, FileCompletion, FlagCompletion, OperatorCompletion, VariableCompletion,
+    CompletionOptions, CustomCompletion, DirectoryCompletion, DotNuCompletion, EnumCompletion,
+    ExportableCompletion, FileCompletion, FlagCompletion, OperatorCompletion, PluginCompletion,
+    VariableCompletion,
 };
 use nu_color_config::{color_record_to_nustyle, lookup_ansi_color_style};
-use nu_engine::eval_block;
+use nu_engine::{eval_block, ClosureEvalOnce};
 use nu_parser::{flatten_expression, parse, parse_module_file_or_dir};
 use nu_protocol::{
     ast::{Argument, Block, Expr, Expression, FindMapResult, ListItem, Traverse},
     debugger::WithoutDebug,
     engine::{Closure, EngineState, Stack, StateWorkingSet},
-    PipelineData, Span, Type, Value,
+    record, IntoPipelineData, PipelineData, Span, Type, Value,
 };
 use reedline::{Completer as ReedlineCompleter, Suggestion};
 use std::sync::Arc;
@@ -170,6 +171,8 @@ impl NuCompleter {
     pub fn fetch_completions_at(&self, line: &str, pos: usize) -> Vec<SemanticSuggestion> {
         let mut working_set = StateWorkingSet::new(&self.engine_state);
         let offset = working_set.next_span_start();
+        // Keep the untrimmed line around: plugin completions are handed the full line
+        let full_line = line;
         // TODO: Callers should be trimming the line themselves
         let line = if line.len() > pos { &line[..pos] } else { line };
         let block = parse(
@@ -179,7 +182,94 @@ impl NuCompleter {
             format!("{}a", line).as_bytes(),
             false,
         );
-        self.fetch_completions_by_block(block, &working_set, pos, offset, line, true)
+        let suggestions = self.fetch_completions_by_block(
+            block,
+            &working_set,
+            pos,
+            offset,
+            line,
+            full_line,
+            true,
+        );
+        self.apply_custom_sorter(suggestions, line, pos)
+    }
+
+    /// Reorder suggestions with the user's `$env.config.completions.sorter` closure, if set.
+    ///
+    /// The closure receives the candidates as a list of `{value, description}` records on input,
+    /// plus the line being edited and the cursor position as arguments, and returns values (as
+    /// strings or the same records) in the desired order. Candidates the closure doesn't mention
+    /// keep their relative order at the end, and anything else it returns is ignored. If the
+    /// closure errors or returns something that isn't a list, the original order is kept.
+    fn apply_custom_sorter(
+        &self,
+        suggestions: Vec<SemanticSuggestion>,
+        line: &str,
+        pos: usize,
+    ) -> Vec<SemanticSuggestion> {
+        let Some(sorter) = self.engine_state.get_config().completions.sorter.clone() else {
+            return suggestions;
+        };
+        if suggestions.is_empty() {
+            return suggestions;
+        }
+
+        let span = Span::unknown();
+        let candidates = Value::list(
+            suggestions
+                .iter()
+                .map(|suggestion| {
+                    Value::record(
+                        record! {
+                            "value" => Value::string(&suggestion.suggestion.value, span),
+                            "description" => match &suggestion.suggestion.description {
+                                Some(description) => Value::string(description, span),
+                                None => Value::nothing(span),
+                            },
+                        },
+                        span,
+                    )
+                })
+                .collect(),
+            span,
+        );
+
+        let result = ClosureEvalOnce::new(&self.engine_state, &self.stack, sorter)
+            .add_arg(Value::string(line, span))
+            .add_arg(Value::int(pos as i64, span))
+            .run_with_input(candidates.into_pipeline_data())
+            .and_then(|data| data.into_value(span));
+
+        let order = match result {
+            Ok(Value::List { vals, .. }) => vals,
+            Ok(_) => return suggestions,
+            Err(err) => {
+                log::warn!("completions.sorter closure failed: {err}");
+                return suggestions;
+            }
+        };
+
+        let mut remaining: Vec<Option<SemanticSuggestion>> =
+            suggestions.into_iter().map(Some).collect();
+        let mut sorted = Vec::with_capacity(remaining.len());
+        for val in order {
+            let wanted = match &val {
+                Value::String { val, .. } => val,
+                Value::Record { val, .. } => match val.get("value") {
+                    Some(Value::String { val, .. }) => val,
+                    _ => continue,
+                },
+                _ => continue,
+            };
+            if let Some(slot) = remaining
+                .iter_mut()
+                .find(|slot| slot.as_ref().is_some_and(|s| &s.suggestion.value == wanted))
+            {
+                sorted.extend(slot.take());
+            }
+        }
+        sorted.extend(remaining.into_iter().flatten());
+        sorted
     }
 
     /// For completion in LSP server.
@@ -200,9 +290,18 @@ impl NuCompleter {
             return vec![];
         };
         let offset = file_span.start;
-        self.fetch_completions_by_block(block.clone(), &working_set, pos, offset, contents, false)
+        self.fetch_completions_by_block(
+            block.clone(),
+            &working_set,
+            pos,
+            offset,
+            contents,
+            contents,
+            false,
+        )
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn fetch_completions_by_block(
         &self,
         block: Arc<Block>,
@@ -210,6 +309,7 @@ impl NuCompleter {
         pos: usize,
         offset: usize,
         contents: &str,
+        full_line: &str,
         extra_placeholder: bool,
     ) -> Vec<SemanticSuggestion> {
         // Adjust offset so that the spans of the suggestions will start at the right
@@ -235,6 +335,8 @@ impl NuCompleter {
             offset,
             pos_to_search,
             text,
+            full_line,
+            pos,
             extra_placeholder,
         )
     }
@@ -246,7 +348,9 @@ impl NuCompleter {
     /// * `offset` - start offset of current working_set span
     /// * `pos` - cursor position, should be > offset
     /// * `prefix_str` - all the text before the cursor, within the `element_expression`
+    /// * `full_line` - the whole line (or file) being edited, with `cursor` an index into it
     /// * `strip` - whether to strip the extra placeholder from a span
+    #[allow(clippy::too_many_arguments)]
     fn complete_by_expression(
         &self,
         working_set: &StateWorkingSet,
@@ -254,6 +358,8 @@ impl NuCompleter {
         offset: usize,
         pos: usize,
         prefix_str: &str,
+        full_line: &str,
+        cursor: usize,
         strip: bool,
     ) -> Vec<SemanticSuggestion> {
         let mut suggestions: Vec<SemanticSuggestion> = vec![];
@@ -397,6 +503,32 @@ impl NuCompleter {
                             Argument::Positional(expr) => {
                                 let command_head = working_set.get_decl(call.decl_id).name();
                                 positional_arg_indices.push(arg_idx);
+                                // values declared with the `@enum` attribute take priority
+                                let mut enum_completion = EnumCompletion {
+                                    decl_id: call.decl_id,
+                                    positional_index: positional_arg_indices.len() - 1,
+                                };
+                                let enum_suggestions =
+                                    self.process_completion(&mut enum_completion, &ctx);
+                                if !enum_suggestions.is_empty() {
+                                    suggestions.extend(enum_suggestions);
+                                    break;
+                                }
+                                // plugin commands can provide candidates of their own, over
+                                // the plugin protocol
+                                if working_set.get_decl(call.decl_id).is_plugin() {
+                                    let mut plugin_completion = PluginCompletion {
+                                        decl_id: call.decl_id,
+                                        line: full_line.into(),
+                                        position: cursor.min(full_line.len()),
+                                    };
+                                    let plugin_suggestions =
+                                        self.process_completion(&mut plugin_completion, &ctx);
+                                    if !plugin_suggestions.is_empty() {
+                                        suggestions.extend(plugin_suggestions);
+                                        break;
+                                    }
+                                }
                                 self.argument_completion_helper(
                                     PositionalArguments {
                                         command_head,
@@ -718,9 +850,21 @@ impl NuCompleter {
 
 impl ReedlineCompleter for NuCompleter {
     fn complete(&mut self, line: &str, pos: usize) -> Vec<Suggestion> {
+        let show_kind = self.engine_state.get_config().completions.show_kind;
         self.fetch_completions_at(line, pos)
             .into_iter()
-            .map(|s| s.suggestion)
+            .map(|semantic| {
+                let mut suggestion = semantic.suggestion;
+                if show_kind {
+                    if let Some(kind) = &semantic.kind {
+                        suggestion.description = Some(match suggestion.description {
+                            Some(description) => format!("({}) {description}", kind.name()),
+                            None => format!("({})", kind.name()),
+                        });
+                    }
+                }
+                suggestion
+            })
             .collect()
     }
 }
@@ -0,0 +1,69 @@
+use crate::completions::{
+    completion_options::NuMatcher, Completer, CompletionOptions, SemanticSuggestion, SuggestionKind,
+};
+use nu_protocol::{
+    engine::{Stack, StateWorkingSet},
+    DeclId, Span, Value,
+};
+use reedline::Suggestion;
+
+/// Completes positional arguments of custom commands whose values are declared with the
+/// `@enum` attribute.
+#[derive(Clone)]
+pub struct EnumCompletion {
+    pub decl_id: DeclId,
+    pub positional_index: usize,
+}
+
+impl Completer for EnumCompletion {
+    fn fetch(
+        &mut self,
+        working_set: &StateWorkingSet,
+        _stack: &Stack,
+        prefix: impl AsRef<str>,
+        span: Span,
+        offset: usize,
+        options: &CompletionOptions,
+    ) -> Vec<SemanticSuggestion> {
+        let decl = working_set.get_decl(self.decl_id);
+        let Some(param) = decl
+            .signature()
+            .get_positional(self.positional_index)
+            .cloned()
+        else {
+            return vec![];
+        };
+
+        let mut matcher = NuMatcher::new(prefix, options);
+        for (name, value) in decl.attributes() {
+            if name != "enum" {
+                continue;
+            }
+            let Value::Record { val: allowed, .. } = value else {
+                continue;
+            };
+            let Some(Value::List { vals: choices, .. }) = allowed.get(&param.name) else {
+                continue;
+            };
+            for choice in choices {
+                let Ok(choice) = choice.coerce_str() else {
+                    continue;
+                };
+                matcher.add_semantic_suggestion(SemanticSuggestion {
+                    suggestion: Suggestion {
+                        value: choice.into_owned(),
+                        description: Some(param.desc.clone()),
+                        span: reedline::Span {
+                            start: span.start - offset,
+                            end: span.end - offset,
+                        },
+                        append_whitespace: true,
+                        ..Suggestion::default()
+                    },
+                    kind: Some(SuggestionKind::Value(nu_protocol::Type::String)),
+                });
+            }
+        }
+        matcher.results()
+    }
+}
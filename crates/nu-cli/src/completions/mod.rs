@@ -8,10 +8,12 @@ mod completion_options;
 mod custom_completions;
 mod directory_completions;
 mod dotnu_completions;
+mod enum_completions;
 mod exportable_completions;
 mod file_completions;
 mod flag_completions;
 mod operator_completions;
+mod plugin_completions;
 mod variable_completions;
 
 pub use attribute_completions::{AttributableCompletion, AttributeCompletion};
@@ -23,8 +25,10 @@ pub use completion_options::{CompletionOptions, MatchAlgorithm};
 pub use custom_completions::CustomCompletion;
 pub use directory_completions::DirectoryCompletion;
 pub use dotnu_completions::DotNuCompletion;
+pub use enum_completions::EnumCompletion;
 pub use exportable_completions::ExportableCompletion;
 pub use file_completions::{file_path_completion, FileCompletion};
 pub use flag_completions::FlagCompletion;
 pub use operator_completions::OperatorCompletion;
+pub use plugin_completions::PluginCompletion;
 pub use variable_completions::VariableCompletion;
@@ -0,0 +1,59 @@
+use crate::completions::{
+    completion_options::NuMatcher, Completer, CompletionOptions, SemanticSuggestion, SuggestionKind,
+};
+use nu_protocol::{
+    engine::{Stack, StateWorkingSet},
+    DeclId, Span,
+};
+use reedline::Suggestion;
+
+/// Completes positional arguments of plugin commands by calling back into the plugin with the
+/// current line context (the `CompleteArgument` plugin call).
+#[derive(Clone)]
+pub struct PluginCompletion {
+    pub decl_id: DeclId,
+    pub line: String,
+    pub position: usize,
+}
+
+impl Completer for PluginCompletion {
+    fn fetch(
+        &mut self,
+        working_set: &StateWorkingSet,
+        stack: &Stack,
+        prefix: impl AsRef<str>,
+        span: Span,
+        offset: usize,
+        options: &CompletionOptions,
+    ) -> Vec<SemanticSuggestion> {
+        let decl = working_set.get_decl(self.decl_id);
+        let mut stack = stack.clone();
+        let Some(items) = decl.complete_argument(
+            working_set.permanent_state,
+            &mut stack,
+            &self.line,
+            self.position,
+            prefix.as_ref(),
+        ) else {
+            return vec![];
+        };
+
+        let mut matcher = NuMatcher::new(prefix, options);
+        for item in items {
+            matcher.add_semantic_suggestion(SemanticSuggestion {
+                suggestion: Suggestion {
+                    value: item.value,
+                    description: item.description,
+                    span: reedline::Span {
+                        start: span.start - offset,
+                        end: span.end - offset,
+                    },
+                    append_whitespace: true,
+                    ..Suggestion::default()
+                },
+                kind: Some(SuggestionKind::Value(nu_protocol::Type::String)),
+            });
+        }
+        matcher.results()
+    }
+}
@@ -310,6 +310,8 @@ pub fn migrate_old_plugin_file(engine_state: &EngineState) -> bool {
             name: identity.name().to_owned(),
             filename: identity.filename().to_owned(),
             shell: identity.shell().map(|p| p.to_owned()),
+            // These plugins were registered explicitly with the old plugin file format
+            trusted: true,
             data: PluginRegistryItemData::Valid {
                 metadata: Default::default(),
                 commands,
@@ -16,6 +16,8 @@ pub struct EvaluateCommandsOpts {
     pub table_mode: Option<Value>,
     pub error_style: Option<Value>,
     pub no_newline: bool,
+    pub output_format: Option<Spanned<String>>,
+    pub stdin_format: Option<Spanned<String>>,
 }
 
 /// Run a command (or commands) given to us by the user
@@ -30,6 +32,8 @@ pub fn evaluate_commands(
         table_mode,
         error_style,
         no_newline,
+        output_format,
+        stdin_format,
     } = opts;
 
     // Handle the configured error style early
@@ -80,21 +84,73 @@ pub fn evaluate_commands(
     // Update permanent state
     engine_state.merge_delta(delta)?;
 
+    // Parse stdin into structured data with `from <format>` if requested
+    let input = match stdin_format {
+        Some(format) => convert_pipeline(engine_state, stack, input, "from", format)?,
+        None => input,
+    };
+
     // Run the block
-    let pipeline = eval_block::<WithoutDebug>(engine_state, stack, &block, input)?;
+    // Run the block and drive the output; ctrl-c can land at any point in this, including
+    // while a stream is being printed, so the interrupt hooks run once it's all over
+    let result =
+        eval_block::<WithoutDebug>(engine_state, stack, &block, input).and_then(|mut pipeline| {
+            if let PipelineData::Value(Value::Error { error, .. }, ..) = pipeline {
+                return Err(*error);
+            }
 
-    if let PipelineData::Value(Value::Error { error, .. }, ..) = pipeline {
-        return Err(*error);
-    }
+            if let Some(t_mode) = table_mode {
+                Arc::make_mut(&mut engine_state.config).table.mode =
+                    t_mode.coerce_str()?.parse().unwrap_or_default();
+            }
 
-    if let Some(t_mode) = table_mode {
-        Arc::make_mut(&mut engine_state.config).table.mode =
-            t_mode.coerce_str()?.parse().unwrap_or_default();
-    }
+            // Convert the final pipeline value with `to <format>` if requested, so the output
+            // is machine readable
+            if let Some(format) = output_format {
+                pipeline = convert_pipeline(engine_state, stack, pipeline, "to", format)?;
+            }
 
-    print_pipeline(engine_state, stack, pipeline, no_newline)?;
+            print_pipeline(engine_state, stack, pipeline, no_newline)
+        });
+
+    // An interrupted evaluation still gets its configured cleanup hooks before the process
+    // exits
+    crate::util::run_interrupt_hooks(engine_state, stack);
+    result?;
 
     info!("evaluate {}:{}:{}", file!(), line!(), column!());
 
     Ok(())
 }
+
+/// Pipe data through a `from <format>` or `to <format>` command, for the `--stdin-format` and
+/// `--output-format` flags.
+fn convert_pipeline(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    data: PipelineData,
+    direction: &str,
+    format: Spanned<String>,
+) -> Result<PipelineData, ShellError> {
+    let converter = format!("{direction} {}", format.item);
+    let flag = match direction {
+        "from" => "--stdin-format",
+        _ => "--output-format",
+    };
+    let decl_id = engine_state
+        .find_decl(converter.as_bytes(), &[])
+        .ok_or_else(|| ShellError::GenericError {
+            error: format!("Invalid value for `{flag}`"),
+            msg: format!("no `{converter}` command to convert with"),
+            span: Some(format.span),
+            help: Some("supported formats include json, nuon, csv, and msgpack".into()),
+            inner: vec![],
+        })?;
+    let call = nu_protocol::ast::Call {
+        decl_id,
+        head: format.span,
+        arguments: vec![],
+        parser_info: std::collections::HashMap::new(),
+    };
+    nu_engine::eval_call::<WithoutDebug>(engine_state, stack, &call, data)
+}
@@ -1,4 +1,4 @@
-use crate::util::{eval_source, print_pipeline};
+use crate::util::{eval_source, print_pipeline, run_interrupt_hooks};
 use log::{info, trace};
 use nu_engine::eval_block;
 use nu_parser::parse;
@@ -123,11 +123,17 @@ pub fn evaluate_file(
                     // Allow early return before main is run.
                     return Ok(());
                 }
-                Err(err) => return Err(err),
+                Err(err) => {
+                    run_interrupt_hooks(engine_state, stack);
+                    return Err(err);
+                }
             };
 
         // Print the pipeline output of the last command of the file.
-        print_pipeline(engine_state, stack, pipeline, true)?;
+        if let Err(err) = print_pipeline(engine_state, stack, pipeline, true) {
+            run_interrupt_hooks(engine_state, stack);
+            return Err(err);
+        }
 
         // Invoke the main command with arguments.
         // Arguments with whitespaces are quoted, thus can be safely concatenated by whitespace.
@@ -144,6 +150,9 @@ pub fn evaluate_file(
         eval_source(engine_state, stack, &file, file_path_str, input, true)
     };
 
+    // An interrupted script still gets its configured cleanup hooks before the process exits
+    run_interrupt_hooks(engine_state, stack);
+
     if exit_code != 0 {
         std::process::exit(exit_code);
     }
@@ -0,0 +1,212 @@
+use nu_ansi_term::{Color, Style};
+use nu_parser::lex;
+use reedline::{CommandLineSearch, Hinter, History, SearchDirection, SearchFilter, SearchQuery};
+
+/// A history hinter that knows a little nushell syntax.
+///
+/// Lines that prefix-match a previous command are hinted first, preferring matches from the
+/// current directory (like reedline's `CwdAwareHinter`). When no line prefix matches, the
+/// buffer is tokenized with the nushell lexer and the most recent history entry invoking the
+/// same command is used instead: the arguments already typed are kept, and the entry's
+/// remaining arguments are suggested. So with `cargo build --release` in history, typing
+/// `cargo check ` hints ` --release`.
+pub struct NuHinter {
+    style: Style,
+    current_hint: String,
+    min_chars: usize,
+}
+
+impl NuHinter {
+    /// A builder that sets the style applied to the hint
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    fn search(
+        &self,
+        history: &dyn History,
+        filter: SearchFilter,
+        limit: i64,
+    ) -> Vec<reedline::HistoryItem> {
+        history
+            .search(SearchQuery {
+                direction: SearchDirection::Backward,
+                start_time: None,
+                end_time: None,
+                start_id: None,
+                end_id: None,
+                limit: Some(limit),
+                filter,
+            })
+            .unwrap_or_default()
+    }
+
+    /// The classic suggestion: the most recent history line starting with `line`, preferring
+    /// entries recorded in `cwd`.
+    fn prefix_hint(&self, line: &str, history: &dyn History, cwd: &str) -> Option<String> {
+        let mut filter = SearchFilter::from_text_search(
+            CommandLineSearch::Prefix(line.to_string()),
+            history.session(),
+        );
+        filter.cwd_exact = Some(cwd.to_string());
+        let mut matches = self.search(history, filter, 1);
+        if matches.is_empty() {
+            // either nothing in this directory, or the backend can't filter by cwd
+            let filter = SearchFilter::from_text_search(
+                CommandLineSearch::Prefix(line.to_string()),
+                history.session(),
+            );
+            matches = self.search(history, filter, 1);
+        }
+        let hint = matches
+            .first()?
+            .command_line
+            .get(line.len()..)
+            .unwrap_or_default()
+            .to_string();
+        (!hint.is_empty()).then_some(hint)
+    }
+
+    /// The syntax-aware fallback: tokenize the buffer, find a previous invocation of the same
+    /// command, and suggest the arguments it had after the ones already typed.
+    fn argument_hint(&self, line: &str, history: &dyn History, cwd: &str) -> Option<String> {
+        let typed = tokens_of(line);
+        let (first, rest) = typed.split_first()?;
+        let head = &first.0;
+        // Only plain command heads; lines starting with variables, subexpressions, etc.
+        // don't have a meaningful "same command" in history
+        if !head
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '-' | '_' | '.' | '/'))
+        {
+            return None;
+        }
+
+        let mut filter = SearchFilter::from_text_search(
+            CommandLineSearch::Prefix(head.to_string()),
+            history.session(),
+        );
+        filter.cwd_exact = Some(cwd.to_string());
+        let mut candidates = self.search(history, filter, 20);
+        if candidates.is_empty() {
+            let filter = SearchFilter::from_text_search(
+                CommandLineSearch::Prefix(head.to_string()),
+                history.session(),
+            );
+            candidates = self.search(history, filter, 20);
+        }
+
+        let mid_token = !line.ends_with(char::is_whitespace);
+        let position = typed.len();
+        for candidate in candidates {
+            let previous = tokens_of(&candidate.command_line);
+            if previous.first().map(|(text, ..)| text.as_str()) != Some(head.as_str()) {
+                continue;
+            }
+            if mid_token {
+                // Complete the argument being typed from its counterpart at the same
+                // position, then carry the candidate's remaining arguments along
+                let Some((last, ..)) = rest.last() else {
+                    continue;
+                };
+                let Some((counterpart, start, _)) = previous.get(position - 1) else {
+                    continue;
+                };
+                if !counterpart.starts_with(last.as_str()) || counterpart == last {
+                    continue;
+                }
+                let boundary = start + last.len();
+                if let Some(hint) = candidate.command_line.get(boundary..) {
+                    return Some(hint.to_string());
+                }
+            } else {
+                // A new argument position: suggest the candidate's arguments from here on,
+                // keeping whatever (possibly different) arguments were already typed
+                let Some((_, _, end)) = previous.get(position - 1) else {
+                    continue;
+                };
+                let hint = candidate
+                    .command_line
+                    .get(*end..)
+                    .unwrap_or_default()
+                    .trim_start();
+                if previous.len() > position && !hint.is_empty() {
+                    return Some(hint.to_string());
+                }
+            }
+        }
+        None
+    }
+}
+
+/// The texts and byte ranges of the line's lexed tokens.
+fn tokens_of(line: &str) -> Vec<(String, usize, usize)> {
+    let (tokens, _) = lex(line.as_bytes(), 0, &[], &[], true);
+    tokens
+        .iter()
+        .map(|token| {
+            (
+                String::from_utf8_lossy(&line.as_bytes()[token.span.start..token.span.end])
+                    .into_owned(),
+                token.span.start,
+                token.span.end,
+            )
+        })
+        .collect()
+}
+
+impl Hinter for NuHinter {
+    fn handle(
+        &mut self,
+        line: &str,
+        _pos: usize,
+        history: &dyn History,
+        use_ansi_coloring: bool,
+        cwd: &str,
+    ) -> String {
+        self.current_hint = if line.chars().count() >= self.min_chars && !line.is_empty() {
+            self.prefix_hint(line, history, cwd)
+                .or_else(|| self.argument_hint(line, history, cwd))
+                .unwrap_or_default()
+        } else {
+            String::new()
+        };
+
+        if use_ansi_coloring && !self.current_hint.is_empty() {
+            self.style.paint(&self.current_hint).to_string()
+        } else {
+            self.current_hint.clone()
+        }
+    }
+
+    fn complete_hint(&self) -> String {
+        self.current_hint.clone()
+    }
+
+    fn next_hint_token(&self) -> String {
+        // The whitespace-delimited accept unit for HistoryHintWordComplete (alt+right)
+        let mut reached_content = false;
+        self.current_hint
+            .chars()
+            .take_while(|c| match (c.is_whitespace(), reached_content) {
+                (_, true) if c.is_whitespace() => false,
+                (true, false) => true,
+                _ => {
+                    reached_content = true;
+                    true
+                }
+            })
+            .collect()
+    }
+}
+
+impl Default for NuHinter {
+    fn default() -> Self {
+        NuHinter {
+            style: Style::new().fg(Color::LightGray),
+            current_hint: String::new(),
+            min_chars: 1,
+        }
+    }
+}
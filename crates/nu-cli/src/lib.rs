@@ -4,6 +4,7 @@ mod completions;
 mod config_files;
 mod eval_cmds;
 mod eval_file;
+mod hinter;
 mod menus;
 mod nu_highlight;
 mod print;
@@ -22,6 +22,7 @@ pub struct NushellPrompt {
     default_vi_normal_prompt_indicator: Option<String>,
     default_multiline_indicator: Option<String>,
     render_right_prompt_on_last_line: bool,
+    render_right_prompt_above: bool,
     engine_state: EngineState,
     stack: Stack,
 }
@@ -43,6 +44,7 @@ impl NushellPrompt {
             default_vi_normal_prompt_indicator: None,
             default_multiline_indicator: None,
             render_right_prompt_on_last_line: false,
+            render_right_prompt_above: false,
             engine_state,
             stack,
         }
@@ -77,6 +79,7 @@ impl NushellPrompt {
         self.default_multiline_indicator = prompt_multiline_indicator_string;
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn update_all_prompt_strings(
         &mut self,
         left_prompt_string: Option<String>,
@@ -85,6 +88,7 @@ impl NushellPrompt {
         prompt_multiline_indicator_string: Option<String>,
         prompt_vi: (Option<String>, Option<String>),
         render_right_prompt_on_last_line: bool,
+        render_right_prompt_above: bool,
     ) {
         let (prompt_vi_insert_string, prompt_vi_normal_string) = prompt_vi;
 
@@ -97,6 +101,27 @@ impl NushellPrompt {
         self.default_vi_normal_prompt_indicator = prompt_vi_normal_string;
 
         self.render_right_prompt_on_last_line = render_right_prompt_on_last_line;
+        self.render_right_prompt_above = render_right_prompt_above;
+    }
+
+    /// The right prompt rendered as its own right-aligned line(s), for
+    /// `$env.config.render_right_prompt_above`.
+    fn right_prompt_line(&self) -> Option<String> {
+        let right = self.right_prompt_string.as_deref()?;
+        if right.is_empty() {
+            return None;
+        }
+        let width = crossterm::terminal::size()
+            .map(|(w, _)| w as usize)
+            .unwrap_or(80);
+        let mut above = String::new();
+        for line in right.replace('\r', "").split('\n') {
+            let visible = nu_utils::strip_ansi_likely(line).chars().count();
+            above.push_str(&" ".repeat(width.saturating_sub(visible)));
+            above.push_str(line);
+            above.push_str("\r\n");
+        }
+        Some(above)
     }
 
     fn default_wrapped_custom_string(&self, str: String) -> String {
@@ -111,8 +136,16 @@ impl Prompt for NushellPrompt {
             let _ = enable_vt_processing();
         }
 
+        // With `render_right_prompt_above`, the right prompt becomes a right-aligned line
+        // of its own, prepended to the left prompt
+        let above = if self.render_right_prompt_above {
+            self.right_prompt_line().unwrap_or_default()
+        } else {
+            String::new()
+        };
+
         if let Some(prompt_string) = &self.left_prompt_string {
-            prompt_string.replace('\n', "\r\n").into()
+            format!("{above}{}", prompt_string.replace('\n', "\r\n")).into()
         } else {
             let default = DefaultPrompt::default();
             let prompt = default
@@ -128,22 +161,27 @@ impl Prompt for NushellPrompt {
                     == Some("vscode")
                 {
                     // We're in vscode and we have osc633 enabled
-                    format!("{VSCODE_PRE_PROMPT_MARKER}{prompt}{VSCODE_POST_PROMPT_MARKER}").into()
+                    format!("{above}{VSCODE_PRE_PROMPT_MARKER}{prompt}{VSCODE_POST_PROMPT_MARKER}")
+                        .into()
                 } else if self.shell_integration_osc133 {
                     // If we're in VSCode but we don't find the env var, but we have osc133 set, then use it
-                    format!("{PRE_PROMPT_MARKER}{prompt}{POST_PROMPT_MARKER}").into()
+                    format!("{above}{PRE_PROMPT_MARKER}{prompt}{POST_PROMPT_MARKER}").into()
                 } else {
-                    prompt.into()
+                    format!("{above}{prompt}").into()
                 }
             } else if self.shell_integration_osc133 {
-                format!("{PRE_PROMPT_MARKER}{prompt}{POST_PROMPT_MARKER}").into()
+                format!("{above}{PRE_PROMPT_MARKER}{prompt}{POST_PROMPT_MARKER}").into()
             } else {
-                prompt.into()
+                format!("{above}{prompt}").into()
             }
         }
     }
 
     fn render_prompt_right(&self) -> Cow<str> {
+        // Already rendered above the input line
+        if self.render_right_prompt_above {
+            return Cow::Borrowed("");
+        }
         if let Some(prompt_string) = &self.right_prompt_string {
             prompt_string.replace('\n', "\r\n").into()
         } else {
@@ -2,10 +2,18 @@ use crate::NushellPrompt;
 use log::{trace, warn};
 use nu_engine::ClosureEvalOnce;
 use nu_protocol::{
-    engine::{EngineState, Stack},
-    report_shell_error, Config, PipelineData, Value,
+    engine::{Closure, EngineState, Stack},
+    report_shell_error, Config, PipelineData, ShellError, Value,
 };
 use reedline::Prompt;
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::Duration,
+};
 
 // Name of environment variable where the prompt could be stored
 pub(crate) const PROMPT_COMMAND: &str = "PROMPT_COMMAND";
@@ -59,8 +67,18 @@ fn get_prompt_string(
         .get_env_var(engine_state, prompt)
         .and_then(|v| match v {
             Value::Closure { val, .. } => {
-                let result = ClosureEvalOnce::new(engine_state, stack, val.as_ref().clone())
-                    .run_with_input(PipelineData::Empty);
+                let result = if config.prompt_timeout.as_nanos() > 0 {
+                    eval_prompt_with_timeout(
+                        engine_state,
+                        stack,
+                        config,
+                        val.as_ref().clone(),
+                        Duration::from_nanos(config.prompt_timeout.as_nanos() as u64),
+                    )
+                } else {
+                    ClosureEvalOnce::new(engine_state, stack, val.as_ref().clone())
+                        .run_with_input(PipelineData::Empty)
+                };
 
                 trace!(
                     "get_prompt_string (block) {}:{}:{}",
@@ -96,6 +114,72 @@ fn get_prompt_string(
         })
 }
 
+/// Prompt evaluations currently running on helper threads. A permanently stuck segment's
+/// thread outlives its timeout (each one holding a clone of the engine state), so spawning is
+/// capped rather than letting a new thread pile up on every repaint.
+static OUTSTANDING_PROMPT_EVALS: AtomicUsize = AtomicUsize::new(0);
+const MAX_OUTSTANDING_PROMPT_EVALS: usize = 8;
+
+/// Evaluate a prompt closure on a helper thread so a slow segment (e.g. git status on a huge
+/// repo) can't hang the prompt repaint indefinitely.
+///
+/// On timeout, a placeholder is rendered instead. The evaluation keeps running on the detached
+/// thread, but its result (including any environment changes) is discarded.
+fn eval_prompt_with_timeout(
+    engine_state: &EngineState,
+    stack: &Stack,
+    config: &Config,
+    closure: Closure,
+    timeout: Duration,
+) -> Result<PipelineData, ShellError> {
+    let outstanding = OUTSTANDING_PROMPT_EVALS.load(Ordering::Acquire);
+    if outstanding >= MAX_OUTSTANDING_PROMPT_EVALS {
+        warn!("{outstanding} prompt evaluations are still running; rendering a placeholder");
+        return Ok(PipelineData::Value(
+            Value::string("…", nu_protocol::Span::unknown()),
+            None,
+        ));
+    }
+
+    let (tx, rx) = mpsc::channel();
+    let thread_engine_state = engine_state.clone();
+    let thread_stack = stack.clone();
+    let thread_config = config.clone();
+    OUTSTANDING_PROMPT_EVALS.fetch_add(1, Ordering::AcqRel);
+    thread::Builder::new()
+        .name("prompt eval".into())
+        .spawn(move || {
+            let result = ClosureEvalOnce::new(&thread_engine_state, &thread_stack, closure)
+                .run_with_input(PipelineData::Empty)
+                .and_then(|data| data.collect_string("", &thread_config));
+            let _ = tx.send(result);
+            OUTSTANDING_PROMPT_EVALS.fetch_sub(1, Ordering::AcqRel);
+        })
+        .map_err(|err| {
+            OUTSTANDING_PROMPT_EVALS.fetch_sub(1, Ordering::AcqRel);
+            ShellError::GenericError {
+                error: "Failed to spawn prompt evaluation thread".into(),
+                msg: err.to_string(),
+                span: None,
+                help: None,
+                inner: vec![],
+            }
+        })?;
+
+    match rx.recv_timeout(timeout) {
+        Ok(result) => result.map(|prompt| {
+            PipelineData::Value(Value::string(prompt, nu_protocol::Span::unknown()), None)
+        }),
+        Err(_) => {
+            warn!("prompt closure did not finish within $env.config.prompt_timeout");
+            Ok(PipelineData::Value(
+                Value::string("…", nu_protocol::Span::unknown()),
+                None,
+            ))
+        }
+    }
+}
+
 pub(crate) fn update_prompt(
     config: &Config,
     engine_state: &EngineState,
@@ -157,6 +241,7 @@ pub(crate) fn update_prompt(
         prompt_multiline_string,
         (prompt_vi_insert_string, prompt_vi_normal_string),
         config.render_right_prompt_on_last_line,
+        config.render_right_prompt_above,
     );
     trace!("update_prompt {}:{}:{}", file!(), line!(), column!());
 }
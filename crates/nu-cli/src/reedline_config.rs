@@ -683,8 +683,47 @@ fn add_menu_keybindings(keybindings: &mut Keybindings) {
         KeyCode::Char('q'),
         ReedlineEvent::SearchHistory,
     );
+
+    // Accept the history hint one word at a time (like fish), in addition to the
+    // ctrl+right binding reedline provides by default
+    keybindings.add_binding(
+        KeyModifiers::ALT,
+        KeyCode::Right,
+        ReedlineEvent::UntilFound(vec![
+            ReedlineEvent::HistoryHintWordComplete,
+            ReedlineEvent::Edit(vec![EditCommand::MoveWordRight { select: false }]),
+        ]),
+    );
+
+    // Round-trip the current buffer through $env.config.buffer_editor (or $VISUAL/$EDITOR),
+    // like fish's alt-e, in addition to reedline's default ctrl+o
+    keybindings.add_binding(
+        KeyModifiers::ALT,
+        KeyCode::Char('e'),
+        ReedlineEvent::OpenEditor,
+    );
+
+    // Continue the entry on the next line, keeping the current line's indentation and adding
+    // a level after a trailing opener. This upgrades the plain-newline alt+enter; the bare
+    // Enter key stays with reedline's validator, which doesn't indent.
+    keybindings.add_binding(
+        KeyModifiers::ALT,
+        KeyCode::Enter,
+        ReedlineEvent::ExecuteHostCommand(SMART_NEWLINE_COMMAND.into()),
+    );
 }
 
+/// Insert a newline into the commandline at the cursor, copying the current line's leading
+/// whitespace and indenting one level deeper when the line ends in `{`, `[`, `(`, or `|`.
+const SMART_NEWLINE_COMMAND: &str = r#"
+    let cursor = commandline get-cursor
+    let line = commandline | str substring 0..<$cursor | lines | last | default ""
+    let indent = $line | parse --regex '^(?<ws>\s*)' | get 0.ws
+    let deeper = ["{" "[" "(" "|"] | any {|it| $line | str trim | str ends-with $it }
+    let extra = if $deeper { "    " } else { "" }
+    commandline edit --insert $"\n($indent)($extra)"
+"#;
+
 pub enum KeybindingsMode {
     Emacs(Keybindings),
     Vi {
@@ -7,6 +7,7 @@ use crate::prompt_update::{
 };
 use crate::{
     completions::NuCompleter,
+    hinter::NuHinter,
     nu_highlight::NoOpHighlighter,
     prompt_update,
     reedline_config::{add_menus, create_keybindings, KeybindingsMode},
@@ -21,21 +22,22 @@ use nu_color_config::StyleComputer;
 #[allow(deprecated)]
 use nu_engine::env_to_strings;
 use nu_engine::exit::cleanup_exit;
+use nu_engine::ClosureEvalOnce;
 use nu_parser::{lex, parse, trim_quotes_str};
 use nu_protocol::shell_error::io::IoError;
 use nu_protocol::{
     config::NuCursorShape,
     engine::{EngineState, Stack, StateWorkingSet},
-    report_shell_error, HistoryConfig, HistoryFileFormat, PipelineData, ShellError, Span, Spanned,
-    Value,
+    record, report_shell_error, HistoryConfig, HistoryFileFormat, PipelineData, ShellError, Span,
+    Spanned, Value,
 };
 use nu_utils::{
     filesystem::{have_permission, PermissionResult},
     perf,
 };
 use reedline::{
-    CursorConfig, CwdAwareHinter, DefaultCompleter, EditCommand, Emacs, FileBackedHistory,
-    HistorySessionId, Reedline, SqliteBackedHistory, Vi,
+    CursorConfig, DefaultCompleter, EditCommand, Emacs, FileBackedHistory, HistorySessionId,
+    Reedline, SqliteBackedHistory, Vi,
 };
 use std::sync::atomic::Ordering;
 use std::{
@@ -149,7 +151,7 @@ pub fn evaluate_repl(
                 eval_source(
                     engine_state,
                     &mut unique_stack,
-                    r#"banner --short"#.as_bytes(),
+                    r#"use std/prelude *; banner --short"#.as_bytes(),
                     "show short banner",
                     PipelineData::empty(),
                     false,
@@ -159,7 +161,7 @@ pub fn evaluate_repl(
                 eval_source(
                     engine_state,
                     &mut unique_stack,
-                    r#"banner"#.as_bytes(),
+                    r#"use std/prelude *; banner"#.as_bytes(),
                     "show_banner",
                     PipelineData::empty(),
                     false,
@@ -320,7 +322,22 @@ fn loop_iteration(ctx: LoopContext) -> (bool, Stack, Reedline) {
     perf!("merge env", start_time, use_color);
 
     start_time = std::time::Instant::now();
-    engine_state.reset_signals();
+    // If the previous command was cut short by ctrl-c, give the configured `interrupt` hooks a
+    // chance to clean up (temp files, child processes, ...) before the signal state is reset
+    if engine_state.signals().interrupted() {
+        engine_state.reset_signals();
+        if let Err(err) = hook::eval_hooks(
+            engine_state,
+            &mut stack,
+            vec![],
+            &engine_state.get_config().hooks.interrupt.clone(),
+            "interrupt",
+        ) {
+            report_shell_error(engine_state, &err);
+        }
+    } else {
+        engine_state.reset_signals();
+    }
     perf!("reset signals", start_time, use_color);
 
     start_time = std::time::Instant::now();
@@ -410,7 +427,7 @@ fn loop_iteration(ctx: LoopContext) -> (bool, Stack, Reedline) {
         line_editor.with_hinter(Box::new({
             // As of Nov 2022, "hints" color_config closures only get `null` passed in.
             let style = style_computer.compute("hints", &Value::nothing(Span::unknown()));
-            CwdAwareHinter::default().with_style(style)
+            NuHinter::default().with_style(style)
         }))
     } else {
         line_editor.disable_hints()
@@ -527,7 +544,10 @@ fn loop_iteration(ctx: LoopContext) -> (bool, Stack, Reedline) {
             start_time = Instant::now();
 
             // Right before we start running the code the user gave us, fire the `pre_execution`
-            // hook
+            // hook. A hook vetoes execution explicitly by setting $env.REPL_VETO_EXECUTION to
+            // true; a hook that merely errors has the error shown, and the command still runs,
+            // so a buggy hook can't block the whole session.
+            let execution_vetoed;
             {
                 // Set the REPL buffer to the current command for the "pre_execution" hook
                 let mut repl = engine_state.repl_state.lock().expect("repl state mutex");
@@ -537,12 +557,20 @@ fn loop_iteration(ctx: LoopContext) -> (bool, Stack, Reedline) {
                 if let Err(err) = hook::eval_hooks(
                     engine_state,
                     &mut stack,
-                    vec![],
+                    vec![(
+                        "cmd_text".into(),
+                        Value::string(repl_cmd_line_text.clone(), Span::unknown()),
+                    )],
                     &engine_state.get_config().hooks.pre_execution.clone(),
                     "pre_execution",
                 ) {
                     report_shell_error(engine_state, &err);
                 }
+
+                execution_vetoed = stack
+                    .get_env_var(engine_state, "REPL_VETO_EXECUTION")
+                    .is_some_and(|veto| veto.as_bool().unwrap_or(false));
+                stack.remove_env_var(engine_state, "REPL_VETO_EXECUTION");
             }
 
             perf!("pre_execution_hook", start_time, use_color);
@@ -593,18 +621,15 @@ fn loop_iteration(ctx: LoopContext) -> (bool, Stack, Reedline) {
             // Actual command execution logic starts from here
             let cmd_execution_start_time = Instant::now();
 
-            match parse_operation(repl_cmd_line_text.clone(), engine_state, &stack) {
+            let parse_result = if execution_vetoed {
+                Ok(ReplOperation::DoNothing)
+            } else {
+                parse_operation(repl_cmd_line_text.clone(), engine_state, &stack)
+            };
+            match parse_result {
                 Ok(operation) => match operation {
                     ReplOperation::AutoCd { cwd, target, span } => {
                         do_auto_cd(target, cwd, &mut stack, engine_state, span);
-
-                        run_finaliziation_ansi_sequence(
-                            &stack,
-                            engine_state,
-                            use_color,
-                            shell_integration_osc633,
-                            shell_integration_osc133,
-                        );
                     }
                     ReplOperation::RunCommand(cmd) => {
                         line_editor = do_run_cmd(
@@ -616,20 +641,23 @@ fn loop_iteration(ctx: LoopContext) -> (bool, Stack, Reedline) {
                             *entry_num,
                             use_color,
                         );
-
-                        run_finaliziation_ansi_sequence(
-                            &stack,
-                            engine_state,
-                            use_color,
-                            shell_integration_osc633,
-                            shell_integration_osc133,
-                        );
                     }
                     // as the name implies, we do nothing in this case
                     ReplOperation::DoNothing => {}
                 },
                 Err(ref e) => error!("Error parsing operation: {e}"),
             }
+
+            // The pre-execution marker (133;C / 633;C) was already emitted, so always close the
+            // command with the finished marker, even when nothing was run (empty input, a vetoed
+            // or unparsable commandline), so terminals never see an unbalanced command mark.
+            run_finaliziation_ansi_sequence(
+                &stack,
+                engine_state,
+                use_color,
+                shell_integration_osc633,
+                shell_integration_osc133,
+            );
             let cmd_duration = cmd_execution_start_time.elapsed();
 
             stack.add_env_var(
@@ -649,6 +677,44 @@ fn loop_iteration(ctx: LoopContext) -> (bool, Stack, Reedline) {
                 }
             }
 
+            // Fire the `post_execution` hook with metadata about the command that just ran
+            {
+                let post_hooks = engine_state.get_config().hooks.post_execution.clone();
+                if !post_hooks.is_empty() {
+                    let span = Span::unknown();
+                    let metadata = Value::record(
+                        record! {
+                            "command" => Value::string(repl_cmd_line_text.clone(), span),
+                            "duration" => Value::duration(
+                                cmd_duration.as_nanos().try_into().unwrap_or(i64::MAX),
+                                span,
+                            ),
+                            // A vetoed command never ran, so don't report the previous
+                            // command's stale exit code for it
+                            "exit_code" => if execution_vetoed {
+                                Value::nothing(span)
+                            } else {
+                                stack
+                                    .get_env_var(engine_state, "LAST_EXIT_CODE")
+                                    .cloned()
+                                    .unwrap_or_else(|| Value::nothing(span))
+                            },
+                            "vetoed" => Value::bool(execution_vetoed, span),
+                        },
+                        span,
+                    );
+                    if let Err(err) = hook::eval_hooks(
+                        engine_state,
+                        &mut stack,
+                        vec![("cmd_meta".into(), metadata)],
+                        &post_hooks,
+                        "post_execution",
+                    ) {
+                        report_shell_error(engine_state, &err);
+                    }
+                }
+            }
+
             if shell_integration_osc2 {
                 run_shell_integration_osc2(None, engine_state, &mut stack, use_color);
             }
@@ -1019,6 +1085,32 @@ fn run_shell_integration_osc2(
             None => maybe_abbrev_path.to_string(),
         };
 
+        // Let a configured title closure override the default
+        let title = match engine_state.get_config().shell_integration.title.clone() {
+            Some(closure) => {
+                let span = Span::unknown();
+                let context = Value::record(
+                    record! {
+                        "cwd" => Value::string(&maybe_abbrev_path, span),
+                        "command" => match command_name {
+                            Some(command) => Value::string(command, span),
+                            None => Value::nothing(span),
+                        },
+                    },
+                    span,
+                );
+                ClosureEvalOnce::new(engine_state, stack, closure)
+                    .add_arg(context)
+                    .run_with_input(PipelineData::empty())
+                    .and_then(|data| data.collect_string("", engine_state.get_config()))
+                    .unwrap_or_else(|err| {
+                        warn!("shell_integration.title closure failed: {err}");
+                        title
+                    })
+            }
+            None => title,
+        };
+
         // Set window title too
         // https://tldp.org/HOWTO/Xterm-Title-3.html
         // ESC]0;stringBEL -- Set icon name and window title to string
@@ -1213,6 +1305,64 @@ fn store_history_id_in_engine(engine_state: &mut EngineState, line_editor: &Reed
     engine_state.history_session_id = session_id;
 }
 
+/// A history backend that drops commands matching `$env.config.history.ignore` instead of
+/// recording them, so secrets typed on the command line never reach the history file. Works
+/// with both the plaintext and sqlite backends; everything else is delegated.
+struct IgnoringHistory {
+    backend: Box<dyn reedline::History>,
+    patterns: Vec<fancy_regex::Regex>,
+}
+
+impl reedline::History for IgnoringHistory {
+    fn save(&mut self, h: reedline::HistoryItem) -> reedline::Result<reedline::HistoryItem> {
+        if self
+            .patterns
+            .iter()
+            .any(|pattern| pattern.is_match(&h.command_line).unwrap_or(false))
+        {
+            // pretend it was saved; without an id, later context updates are no-ops
+            return Ok(h);
+        }
+        self.backend.save(h)
+    }
+
+    fn load(&self, id: reedline::HistoryItemId) -> reedline::Result<reedline::HistoryItem> {
+        self.backend.load(id)
+    }
+
+    fn count(&self, query: reedline::SearchQuery) -> reedline::Result<i64> {
+        self.backend.count(query)
+    }
+
+    fn search(&self, query: reedline::SearchQuery) -> reedline::Result<Vec<reedline::HistoryItem>> {
+        self.backend.search(query)
+    }
+
+    fn update(
+        &mut self,
+        id: reedline::HistoryItemId,
+        updater: &dyn Fn(reedline::HistoryItem) -> reedline::HistoryItem,
+    ) -> reedline::Result<()> {
+        self.backend.update(id, updater)
+    }
+
+    fn clear(&mut self) -> reedline::Result<()> {
+        self.backend.clear()
+    }
+
+    fn delete(&mut self, h: reedline::HistoryItemId) -> reedline::Result<()> {
+        self.backend.delete(h)
+    }
+
+    fn sync(&mut self) -> std::io::Result<()> {
+        self.backend.sync()
+    }
+
+    fn session(&self) -> Option<HistorySessionId> {
+        self.backend.session()
+    }
+}
+
 fn update_line_editor_history(
     engine_state: &mut EngineState,
     history_path: PathBuf,
@@ -1220,7 +1370,7 @@ fn update_line_editor_history(
     line_editor: Reedline,
     history_session_id: Option<HistorySessionId>,
 ) -> Result<Reedline, ErrReport> {
-    let history: Box<dyn reedline::History> = match history.file_format {
+    let mut backend: Box<dyn reedline::History> = match history.file_format {
         HistoryFileFormat::Plaintext => Box::new(
             FileBackedHistory::with_file(history.max_size as usize, history_path)
                 .into_diagnostic()?,
@@ -1234,10 +1384,31 @@ fn update_line_editor_history(
             .into_diagnostic()?,
         ),
     };
+    // Commands matching `$env.config.history.ignore` are kept out of history altogether,
+    // like a leading space but configurable. Invalid patterns are reported once here.
+    if !history.ignore.is_empty() {
+        let mut patterns = Vec::with_capacity(history.ignore.len());
+        for pattern in &history.ignore {
+            match fancy_regex::Regex::new(pattern) {
+                Ok(pattern) => patterns.push(pattern),
+                Err(err) => report_shell_error(
+                    engine_state,
+                    &ShellError::GenericError {
+                        error: "Invalid $env.config.history.ignore pattern".into(),
+                        msg: err.to_string(),
+                        span: None,
+                        help: Some(format!("the pattern `{pattern}` is not a valid regex")),
+                        inner: vec![],
+                    },
+                ),
+            }
+        }
+        backend = Box::new(IgnoringHistory { backend, patterns });
+    }
     let line_editor = line_editor
         .with_history_session_id(history_session_id)
         .with_history_exclusion_prefix(Some(" ".into()))
-        .with_history(history);
+        .with_history(backend);
 
     store_history_id_in_engine(engine_state, &line_editor);
 
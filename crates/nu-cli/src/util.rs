@@ -13,7 +13,10 @@ use nu_protocol::{
 #[cfg(windows)]
 use nu_utils::enable_vt_processing;
 use nu_utils::{escape_quote_string, perf};
-use std::path::Path;
+use std::{
+    path::Path,
+    sync::{Arc, Mutex},
+};
 
 // This will collect environment variables from std::env and adds them to a stack.
 //
@@ -203,6 +206,24 @@ fn gather_env_vars(
     }
 }
 
+/// If evaluation was cut short by ctrl-c, give the configured `interrupt` hooks a chance to
+/// clean up (temp files, child processes, ...) before the process exits, mirroring what the
+/// REPL does between prompts. Resets the signal state so the hooks themselves can run.
+pub fn run_interrupt_hooks(engine_state: &mut EngineState, stack: &mut Stack) {
+    if !engine_state.signals().interrupted() {
+        return;
+    }
+    engine_state.reset_signals();
+    // Read the config through the stack, so hooks registered by the interrupted script
+    // itself ($env.config.hooks.interrupt = ...) are seen
+    let hooks = stack.get_config(engine_state).hooks.interrupt.clone();
+    if let Err(err) =
+        nu_cmd_base::hook::eval_hooks(engine_state, stack, vec![], &hooks, "interrupt")
+    {
+        report_shell_error(engine_state, &err);
+    }
+}
+
 /// Print a pipeline with formatting applied based on display_output hook.
 ///
 /// This function should be preferred when printing values resulting from a completed evaluation.
@@ -217,6 +238,33 @@ pub fn print_pipeline(
     no_newline: bool,
 ) -> Result<(), ShellError> {
     if let Some(hook) = engine_state.get_config().hooks.display_output.clone() {
+        // A record without a `code` key dispatches on the type of the output: keys are type
+        // names ("table", "binary", "datetime", ...), with `_` as the fallback
+        let hook = match &hook {
+            Value::Record { val, .. } if val.get("code").is_none() => {
+                let type_name = match &pipeline {
+                    PipelineData::Value(value, ..) => value.get_type().to_string(),
+                    PipelineData::ListStream(..) => "list".to_string(),
+                    PipelineData::ByteStream(stream, ..) => {
+                        nu_protocol::Type::from(stream.type_()).to_string()
+                    }
+                    PipelineData::Empty => "nothing".to_string(),
+                };
+                let base_name = type_name.split('<').next().unwrap_or("").to_string();
+                match val
+                    .get(&type_name)
+                    .or_else(|| val.get(&base_name))
+                    .or_else(|| val.get("_"))
+                {
+                    Some(hook) => hook.clone(),
+                    // No entry for this type: print with the default formatting
+                    None => {
+                        return pipeline.print_table(engine_state, stack, no_newline, false);
+                    }
+                }
+            }
+            _ => hook,
+        };
         let pipeline = eval_hook(
             engine_state,
             stack,
@@ -274,6 +322,66 @@ pub fn eval_source(
     exit_code
 }
 
+/// The parser-visible state a cached line was compiled against. If any of this changed, the
+/// same source text could parse differently and the cached block can't be reused.
+#[derive(Clone, PartialEq, Eq)]
+struct ParseFingerprint {
+    num_decls: usize,
+    num_vars: usize,
+    num_modules: usize,
+    num_virtual_paths: usize,
+    active_overlays: Vec<Vec<u8>>,
+    // `hide` and re-imports change what a name resolves to without touching any of the
+    // counts above, so the sizes of the active overlays' name maps and their visibility
+    // state have to be part of the fingerprint too
+    scope_entries: usize,
+    hidden_decls: usize,
+    pwd: Option<String>,
+}
+
+impl ParseFingerprint {
+    fn of(engine_state: &EngineState, stack: &Stack) -> Self {
+        ParseFingerprint {
+            num_decls: engine_state.num_decls(),
+            num_vars: engine_state.num_vars(),
+            num_modules: engine_state.num_modules(),
+            num_virtual_paths: engine_state.num_virtual_paths(),
+            active_overlays: engine_state
+                .active_overlay_names(&[])
+                .map(|name| name.to_vec())
+                .collect(),
+            scope_entries: engine_state
+                .active_overlays(&[])
+                .map(|overlay| {
+                    overlay.decls.len()
+                        + overlay.vars.len()
+                        + overlay.modules.len()
+                        + overlay.visibility.len()
+                })
+                .sum(),
+            hidden_decls: engine_state
+                .active_overlays(&[])
+                .map(|overlay| overlay.visibility.num_hidden())
+                .sum(),
+            pwd: stack
+                .get_env_var(engine_state, "PWD")
+                .and_then(|pwd| pwd.as_str().ok())
+                .map(String::from),
+        }
+    }
+}
+
+/// Compiled blocks for lines previously evaluated in this process, so re-running an identical
+/// line (arrow-up is the common case) skips the parse and IR compilation entirely.
+///
+/// A line is only cached when parsing it had no side effects beyond registering the entry's
+/// own source text — no new declarations, modules, overlays, or files read from disk — and it
+/// is only reused while the parser-visible state it was compiled against is unchanged, so
+/// anything that could make the same text parse differently falls back to a fresh parse.
+type CompiledLine = (Vec<u8>, ParseFingerprint, Arc<nu_protocol::ast::Block>);
+static COMPILED_LINE_CACHE: Mutex<Vec<CompiledLine>> = Mutex::new(Vec::new());
+const COMPILED_LINE_CACHE_MAX: usize = 64;
+
 fn evaluate_source(
     engine_state: &mut EngineState,
     stack: &mut Stack,
@@ -282,33 +390,62 @@ fn evaluate_source(
     input: PipelineData,
     allow_return: bool,
 ) -> Result<bool, ShellError> {
-    let (block, delta) = {
-        let mut working_set = StateWorkingSet::new(engine_state);
-        let output = parse(
-            &mut working_set,
-            Some(fname), // format!("entry #{}", entry_num)
-            source,
-            false,
-        );
-        if let Some(warning) = working_set.parse_warnings.first() {
-            report_parse_warning(&working_set, warning);
-        }
+    let fingerprint = ParseFingerprint::of(engine_state, stack);
+    let cached_block = COMPILED_LINE_CACHE.lock().ok().and_then(|cache| {
+        cache
+            .iter()
+            .find(|(text, cached_fingerprint, _)| {
+                text == source && *cached_fingerprint == fingerprint
+            })
+            .map(|(_, _, block)| block.clone())
+    });
+
+    let block = if let Some(block) = cached_block {
+        block
+    } else {
+        let num_files_before = engine_state.num_files();
+        let (block, delta) = {
+            let mut working_set = StateWorkingSet::new(engine_state);
+            let output = parse(
+                &mut working_set,
+                Some(fname), // format!("entry #{}", entry_num)
+                source,
+                false,
+            );
+            if let Some(warning) = working_set.parse_warnings.first() {
+                report_parse_warning(&working_set, warning);
+            }
 
-        if let Some(err) = working_set.parse_errors.first() {
-            report_parse_error(&working_set, err);
-            return Ok(true);
-        }
+            if let Some(err) = working_set.parse_errors.first() {
+                report_parse_error(&working_set, err);
+                return Ok(true);
+            }
 
-        if let Some(err) = working_set.compile_errors.first() {
-            report_compile_error(&working_set, err);
-            return Ok(true);
+            if let Some(err) = working_set.compile_errors.first() {
+                report_compile_error(&working_set, err);
+                return Ok(true);
+            }
+
+            (output, working_set.render())
+        };
+
+        engine_state.merge_delta(delta)?;
+
+        // Only cache side-effect-free parses (see COMPILED_LINE_CACHE)
+        if engine_state.num_files() == num_files_before + 1
+            && ParseFingerprint::of(engine_state, stack) == fingerprint
+        {
+            if let Ok(mut cache) = COMPILED_LINE_CACHE.lock() {
+                if cache.len() >= COMPILED_LINE_CACHE_MAX {
+                    cache.remove(0);
+                }
+                cache.push((source.to_vec(), fingerprint, block.clone()));
+            }
         }
 
-        (output, working_set.render())
+        block
     };
 
-    engine_state.merge_delta(delta)?;
-
     let pipeline = if allow_return {
         eval_block_with_early_return::<WithoutDebug>(engine_state, stack, &block, input)
     } else {
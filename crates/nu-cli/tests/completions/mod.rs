@@ -222,6 +222,83 @@ fn variables_customcompletion_subcommands_with_customcompletion_2(
     match_suggestions(&expected, &suggestions);
 }
 
+/// Parameters restricted with the `@enum` attribute complete their declared values
+#[test]
+fn enum_attribute_completions() {
+    let (_, _, mut engine, mut stack) = new_engine();
+    let command = r#"@enum {level: [low medium high]}
+def set-level [level: string] { $level }"#;
+    assert!(support::merge_input(command.as_bytes(), &mut engine, &mut stack).is_ok());
+    let mut completer = NuCompleter::new(Arc::new(engine), Arc::new(stack));
+
+    let suggestions = completer.complete("set-level ", 10);
+    let expected: Vec<_> = vec!["high", "low", "medium"];
+    match_suggestions(&expected, &suggestions);
+
+    let suggestions = completer.complete("set-level m", 11);
+    let expected: Vec<_> = vec!["medium"];
+    match_suggestions(&expected, &suggestions);
+}
+
+/// `$env.config.completions.show_kind` includes the suggestion kind in descriptions
+#[test]
+fn show_kind_in_menu_descriptions() {
+    let (_, _, mut engine, mut stack) = new_engine();
+    let config = r#"$env.config.completions.show_kind = true"#;
+    assert!(support::merge_input(config.as_bytes(), &mut engine, &mut stack).is_ok());
+    let mut completer = NuCompleter::new(Arc::new(engine), Arc::new(stack));
+
+    let completion_str = "ls --al";
+    let suggestions = completer.complete(completion_str, completion_str.len());
+    let description = suggestions
+        .first()
+        .and_then(|suggestion| suggestion.description.clone())
+        .unwrap_or_default();
+    assert!(
+        description.starts_with("(flag)"),
+        "expected description to start with '(flag)', got: {description}"
+    );
+}
+
+/// The optional `$env.config.completions.sorter` closure reorders the final suggestions
+#[test]
+fn customcompletions_custom_sorter() {
+    let mut completer = custom_completer_with_options(
+        r#"$env.config.completions.sorter = {|line, pos| sort-by value --reverse}"#,
+        "",
+        &["apple", "mango", "zebra"],
+    );
+    let expected: Vec<_> = vec!["zebra", "mango", "apple"];
+    let suggestions = completer.complete("my-command ", 11);
+    match_suggestions(&expected, &suggestions);
+}
+
+/// A sorter that only mentions some candidates moves them to the front and keeps the rest
+#[test]
+fn customcompletions_partial_sorter() {
+    let mut completer = custom_completer_with_options(
+        r#"$env.config.completions.sorter = {|line, pos| where value == "mango"}"#,
+        "",
+        &["apple", "mango", "zebra"],
+    );
+    let expected: Vec<_> = vec!["mango", "apple", "zebra"];
+    let suggestions = completer.complete("my-command ", 11);
+    match_suggestions(&expected, &suggestions);
+}
+
+/// A sorter that errors should leave the original order alone
+#[test]
+fn customcompletions_broken_sorter_is_ignored() {
+    let mut completer = custom_completer_with_options(
+        r#"$env.config.completions.sorter = {|line, pos| error make {msg: "nope"}}"#,
+        "",
+        &["apple", "mango", "zebra"],
+    );
+    let expected: Vec<_> = vec!["apple", "mango", "zebra"];
+    let suggestions = completer.complete("my-command ", 11);
+    match_suggestions(&expected, &suggestions);
+}
+
 /// $env.config should be overridden by the custom completer's options
 #[test]
 fn customcompletions_override_options() {
@@ -1587,7 +1664,7 @@ fn attribute_completions() {
     let suggestions = completer.complete("@", 1);
 
     // Only checking for the builtins and not the std attributes
-    let expected: Vec<_> = vec!["category", "example", "search-terms"];
+    let expected: Vec<_> = vec!["category", "enum", "example", "search-terms", "validate"];
 
     // Match results
     match_suggestions(&expected, &suggestions);
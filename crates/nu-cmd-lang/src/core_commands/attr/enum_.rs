@@ -0,0 +1,68 @@
+use nu_engine::command_prelude::*;
+
+#[derive(Clone)]
+pub struct AttrEnum;
+
+impl Command for AttrEnum {
+    fn name(&self) -> &str {
+        "attr enum"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("attr enum")
+            .input_output_types(vec![(Type::Nothing, Type::record())])
+            .allow_variants_without_examples(true)
+            .required(
+                "values",
+                SyntaxShape::Record(vec![]),
+                "A record mapping parameter names to the list of values they accept.",
+            )
+            .category(Category::Core)
+    }
+
+    fn description(&self) -> &str {
+        "Attribute for restricting parameters of custom commands to a fixed set of values."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"The declared values are enforced when the command is called, and drive completions
+for the parameter:
+
+    @enum {format: [json yaml toml]}
+    def serialize [data, format: string] { ... }"#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let values: Value = call.req(engine_state, stack, 0)?;
+        Ok(values.into_pipeline_data())
+    }
+
+    fn run_const(
+        &self,
+        working_set: &StateWorkingSet,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let values: Value = call.req_const(working_set, 0)?;
+        Ok(values.into_pipeline_data())
+    }
+
+    fn is_const(&self) -> bool {
+        true
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Restrict a parameter to a fixed set of values",
+            example: r#"@enum {level: [low medium high]}
+def set-level [level: string] { $level }"#,
+            result: None,
+        }]
+    }
+}
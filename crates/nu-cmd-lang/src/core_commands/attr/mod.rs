@@ -1,7 +1,11 @@
 mod category;
+mod enum_;
 mod example;
 mod search_terms;
+mod validate;
 
 pub use category::AttrCategory;
+pub use enum_::AttrEnum;
 pub use example::AttrExample;
 pub use search_terms::AttrSearchTerms;
+pub use validate::AttrValidate;
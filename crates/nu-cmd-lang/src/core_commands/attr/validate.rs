@@ -0,0 +1,100 @@
+use nu_engine::command_prelude::*;
+use nu_protocol::ast::Expr;
+
+#[derive(Clone)]
+pub struct AttrValidate;
+
+impl Command for AttrValidate {
+    fn name(&self) -> &str {
+        "attr validate"
+    }
+
+    // TODO: When const closures are available, switch to using them for the `condition` argument
+    // rather than a block. That should remove the need for `requires_ast_for_arguments` to be true
+    fn signature(&self) -> Signature {
+        Signature::build("attr validate")
+            .input_output_types(vec![(Type::Nothing, Type::Int)])
+            .allow_variants_without_examples(true)
+            .required(
+                "condition",
+                SyntaxShape::Block,
+                "Validation block, run with the parameters as a record on $in before each call.",
+            )
+            .category(Category::Core)
+    }
+
+    fn description(&self) -> &str {
+        "Attribute for validating the parameters of custom commands."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"The block receives the command's parameters as a record on $in, right before the
+body runs. Returning false rejects the call with a generic error; for a better
+message, raise one with `error make` instead:
+
+    @validate {
+        if $in.scale not-in 1..10 {
+            error make {msg: "scale must be between 1 and 10"}
+        }
+    }
+    def resize [scale: int] { ... }
+
+Note that the block is not a closure: it can only rely on the $in record, not on
+variables from the surrounding scope."#
+    }
+
+    fn requires_ast_for_arguments(&self) -> bool {
+        true
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let block_id = extract_block_id(call.positional_nth(stack, 0), call.head)?;
+        Ok(Value::int(block_id, call.head).into_pipeline_data())
+    }
+
+    fn run_const(
+        &self,
+        _working_set: &StateWorkingSet,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let block_id = extract_block_id(call.assert_ast_call()?.positional_nth(0), call.head)?;
+        Ok(Value::int(block_id, call.head).into_pipeline_data())
+    }
+
+    fn is_const(&self) -> bool {
+        true
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Reject out-of-range arguments before the body runs",
+            example: r#"@validate { $in.scale in 1..10 }
+def resize [scale: int] { $scale * 100 }"#,
+            result: None,
+        }]
+    }
+}
+
+fn extract_block_id(
+    expr: Option<&nu_protocol::ast::Expression>,
+    head: Span,
+) -> Result<i64, ShellError> {
+    let expr = expr.ok_or(ShellError::MissingParameter {
+        param_name: "condition".into(),
+        span: head,
+    })?;
+    match expr.expr {
+        Expr::Block(block_id) | Expr::Closure(block_id) => Ok(block_id.get() as i64),
+        _ => Err(ShellError::TypeMismatch {
+            err_message: "expected a block".into(),
+            span: expr.span,
+        }),
+    }
+}
@@ -26,6 +26,11 @@ impl Command for Describe {
                 "show detailed information about the value",
                 Some('d'),
             )
+            .switch(
+                "schema",
+                "return a recursive structural schema, merging list elements into one element schema with nullability",
+                Some('s'),
+            )
             .category(Category::Core)
     }
 
@@ -44,6 +49,11 @@ impl Command for Describe {
             no_collect: call.has_flag(engine_state, stack, "no-collect")?,
             detailed: call.has_flag(engine_state, stack, "detailed")?,
         };
+        if call.has_flag(engine_state, stack, "schema")? {
+            let head = call.head;
+            let value = input.into_value(head)?;
+            return Ok(schema_of(&value, head).into_pipeline_data());
+        }
         run(Some(engine_state), call, input, options)
     }
 
@@ -57,6 +67,11 @@ impl Command for Describe {
             no_collect: call.has_flag_const(working_set, "no-collect")?,
             detailed: call.has_flag_const(working_set, "detailed")?,
         };
+        if call.has_flag_const(working_set, "schema")? {
+            let head = call.head;
+            let value = input.into_value(head)?;
+            return Ok(schema_of(&value, head).into_pipeline_data());
+        }
         run(None, call, input, options)
     }
 
@@ -146,6 +161,149 @@ impl Command for Describe {
     }
 }
 
+/// A recursive structural schema of a value: scalars become their type name, records become
+/// `{type: record, fields: {...}}` (fields carry `required: false` when they are missing or
+/// null in some rows), and lists become `{type: list, items: <merged element schema>}`.
+fn schema_of(value: &Value, head: Span) -> Value {
+    match value {
+        Value::Record { val, .. } => {
+            let mut fields = Record::new();
+            for (column, value) in val.iter() {
+                fields.push(column.clone(), schema_of(value, head));
+            }
+            Value::record(
+                record! {
+                    "type" => Value::string("record", head),
+                    "fields" => Value::record(fields, head),
+                },
+                head,
+            )
+        }
+        Value::List { vals, .. } => {
+            let items = vals
+                .iter()
+                .map(|item| schema_of(item, head))
+                .reduce(|a, b| merge_schemas(a, b, head))
+                .unwrap_or_else(|| Value::string("any", head));
+            Value::record(
+                record! {
+                    "type" => Value::string("list", head),
+                    "items" => items,
+                },
+                head,
+            )
+        }
+        other => Value::string(other.get_type().to_string(), head),
+    }
+}
+
+/// Merge two element schemas into one that describes both: identical schemas stay as-is,
+/// records merge field-wise (fields absent on one side become optional), a `nothing` merged
+/// with anything marks it nullable, and otherwise the result degrades to `any`.
+fn merge_schemas(a: Value, b: Value, head: Span) -> Value {
+    if a == b {
+        return a;
+    }
+
+    let nothing = Value::string("nothing", head);
+    if a == nothing || b == nothing {
+        let other = if a == nothing { b } else { a };
+        if let Value::Record { val, .. } = &other {
+            let mut merged = val.clone().into_owned();
+            merged.insert("nullable", Value::bool(true, head));
+            return Value::record(merged, head);
+        }
+        return Value::record(
+            record! {
+                "type" => other,
+                "nullable" => Value::bool(true, head),
+            },
+            head,
+        );
+    }
+
+    match (&a, &b) {
+        (Value::Record { val: a_rec, .. }, Value::Record { val: b_rec, .. }) => {
+            let a_type = a_rec.get("type").cloned();
+            if a_type != b_rec.get("type").cloned() {
+                return Value::string("any", head);
+            }
+            match a_type.as_ref().and_then(|ty| ty.as_str().ok()) {
+                Some("record") => {
+                    let empty = Record::new();
+                    let a_fields = match a_rec.get("fields") {
+                        Some(Value::Record { val, .. }) => val.clone().into_owned(),
+                        _ => empty.clone(),
+                    };
+                    let b_fields = match b_rec.get("fields") {
+                        Some(Value::Record { val, .. }) => val.clone().into_owned(),
+                        _ => empty,
+                    };
+                    let mut merged = Record::new();
+                    for (column, a_schema) in a_fields.iter() {
+                        match b_fields.get(column) {
+                            Some(b_schema) => merged.push(
+                                column.clone(),
+                                merge_schemas(a_schema.clone(), b_schema.clone(), head),
+                            ),
+                            None => merged.push(column.clone(), optional(a_schema.clone(), head)),
+                        }
+                    }
+                    for (column, b_schema) in b_fields.iter() {
+                        if a_fields.get(column).is_none() {
+                            merged.push(column.clone(), optional(b_schema.clone(), head));
+                        }
+                    }
+                    Value::record(
+                        record! {
+                            "type" => Value::string("record", head),
+                            "fields" => Value::record(merged, head),
+                        },
+                        head,
+                    )
+                }
+                Some("list") => {
+                    let a_items = a_rec
+                        .get("items")
+                        .cloned()
+                        .unwrap_or_else(|| Value::string("any", head));
+                    let b_items = b_rec
+                        .get("items")
+                        .cloned()
+                        .unwrap_or_else(|| Value::string("any", head));
+                    Value::record(
+                        record! {
+                            "type" => Value::string("list", head),
+                            "items" => merge_schemas(a_items, b_items, head),
+                        },
+                        head,
+                    )
+                }
+                _ => Value::string("any", head),
+            }
+        }
+        _ => Value::string("any", head),
+    }
+}
+
+/// Mark a field schema as not required (it's missing in some rows).
+fn optional(schema: Value, head: Span) -> Value {
+    match schema {
+        Value::Record { val, .. } => {
+            let mut record = val.into_owned();
+            record.insert("required", Value::bool(false, head));
+            Value::record(record, head)
+        }
+        other => Value::record(
+            record! {
+                "type" => other,
+                "required" => Value::bool(false, head),
+            },
+            head,
+        ),
+    }
+}
+
 #[derive(Clone, Copy)]
 struct Options {
     no_collect: bool,
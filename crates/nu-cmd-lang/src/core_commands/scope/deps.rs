@@ -0,0 +1,141 @@
+use nu_engine::command_prelude::*;
+use nu_protocol::{
+    ast::{Expr, Expression, Traverse},
+    engine::StateWorkingSet,
+    DeclId,
+};
+
+#[derive(Clone)]
+pub struct ScopeDeps;
+
+impl Command for ScopeDeps {
+    fn name(&self) -> &str {
+        "scope deps"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("scope deps")
+            .input_output_types(vec![
+                (Type::Nothing, Type::List(Box::new(Type::Any))),
+                (Type::Nothing, Type::String),
+            ])
+            .switch(
+                "all",
+                "Also include calls to built-in commands, not just custom ones",
+                Some('a'),
+            )
+            .switch(
+                "dot",
+                "Output the dependency graph in graphviz dot format",
+                None,
+            )
+            .allow_variants_without_examples(true)
+            .category(Category::Core)
+    }
+
+    fn description(&self) -> &str {
+        "Output which custom commands call which other commands."
+    }
+
+    fn extra_description(&self) -> &str {
+        "This is built from the parsed bodies of the custom commands in scope, so it can help
+understand and prune large configs and module trees. By default only calls to other custom
+commands are listed; use --all to include built-ins."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show which custom commands each custom command calls",
+                example: "scope deps",
+                result: None,
+            },
+            Example {
+                description: "Render the dependency graph with graphviz",
+                example: "scope deps --dot | save deps.dot",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let all = call.has_flag(engine_state, stack, "all")?;
+        let dot = call.has_flag(engine_state, stack, "dot")?;
+
+        let working_set = StateWorkingSet::new(engine_state);
+        let mut deps: Vec<(String, Vec<String>)> = Vec::new();
+
+        for (name_bytes, decl_id) in engine_state.get_decls_sorted(false) {
+            let decl = engine_state.get_decl(decl_id);
+            let Some(block_id) = decl.block_id() else {
+                // not a custom command
+                continue;
+            };
+            let name = String::from_utf8_lossy(&name_bytes).to_string();
+            let block = engine_state.get_block(block_id);
+
+            let mut calls: Vec<String> = block
+                .flat_map(&working_set, &|expr| collect_calls(expr, &working_set))
+                .into_iter()
+                .filter(|&callee| all || engine_state.get_decl(callee).block_id().is_some())
+                .map(|callee| engine_state.get_decl(callee).name().to_string())
+                .collect();
+            calls.sort();
+            calls.dedup();
+            deps.push((name, calls));
+        }
+
+        if dot {
+            let mut graph = String::from("digraph deps {\n");
+            for (name, calls) in &deps {
+                for callee in calls {
+                    graph.push_str(&format!("    \"{name}\" -> \"{callee}\";\n"));
+                }
+            }
+            graph.push_str("}\n");
+            Ok(Value::string(graph, head).into_pipeline_data())
+        } else {
+            let table = deps
+                .into_iter()
+                .map(|(name, calls)| {
+                    Value::record(
+                        record! {
+                            "command" => Value::string(name, head),
+                            "calls" => Value::list(
+                                calls.into_iter().map(|callee| Value::string(callee, head)).collect(),
+                                head,
+                            ),
+                        },
+                        head,
+                    )
+                })
+                .collect();
+            Ok(Value::list(table, head).into_pipeline_data())
+        }
+    }
+}
+
+/// Collect the decl ids of every call in an expression, including calls nested in arguments.
+fn collect_calls(expr: &Expression, working_set: &StateWorkingSet) -> Option<Vec<DeclId>> {
+    let closure = |expr: &Expression| collect_calls(expr, working_set);
+    match &expr.expr {
+        Expr::Call(call) => {
+            let mut ids = vec![call.decl_id];
+            ids.extend(
+                call.arguments
+                    .iter()
+                    .filter_map(|arg| arg.expr())
+                    .flat_map(|expr| expr.flat_map(working_set, &closure)),
+            );
+            Some(ids)
+        }
+        _ => None,
+    }
+}
@@ -1,6 +1,7 @@
 mod aliases;
 mod command;
 mod commands;
+mod deps;
 mod engine_stats;
 mod externs;
 mod modules;
@@ -9,6 +10,7 @@ mod variables;
 pub use aliases::*;
 pub use command::*;
 pub use commands::*;
+pub use deps::*;
 pub use engine_stats::*;
 pub use externs::*;
 pub use modules::*;
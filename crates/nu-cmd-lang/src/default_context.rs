@@ -17,7 +17,9 @@ pub fn create_default_context() -> EngineState {
         bind_command! {
             Alias,
             AttrCategory,
+            AttrEnum,
             AttrExample,
+            AttrValidate,
             AttrSearchTerms,
             Break,
             Collect,
@@ -55,6 +57,7 @@ pub fn create_default_context() -> EngineState {
             Scope,
             ScopeAliases,
             ScopeCommands,
+            ScopeDeps,
             ScopeEngineStats,
             ScopeExterns,
             ScopeModules,
@@ -0,0 +1,156 @@
+//! A small façade for embedding nushell as a scripting or configuration language.
+//!
+//! This wraps the usual `create_default_context` + `nu-parser` + `nu-engine` dance into a couple
+//! of functions with stable signatures, so applications don't have to depend on parser and
+//! evaluator internals:
+//!
+//! ```no_run
+//! use nu_cmd_lang::embedding::Engine;
+//!
+//! let mut engine = Engine::new();
+//! let value = engine.eval("def double [x] { $x * 2 }; double 21").unwrap();
+//! # let _ = value;
+//! ```
+//!
+//! The engine only contains the core language commands; use
+//! [`Engine::add_commands`] to register additional command sets (e.g.
+//! `nu_command::add_shell_command_context`) or custom [`Command`]s.
+
+use nu_engine::eval_block;
+use nu_parser::parse;
+use nu_protocol::{
+    debugger::WithoutDebug,
+    engine::{Command, EngineState, Stack, StateWorkingSet},
+    PipelineData, ShellError, Value,
+};
+
+/// An embedded nushell engine: an [`EngineState`] and [`Stack`] that persist across
+/// evaluations, so definitions and variables from one snippet are visible to the next.
+pub struct Engine {
+    engine_state: EngineState,
+    stack: Stack,
+}
+
+impl Engine {
+    /// Create an engine with the core language commands in scope.
+    pub fn new() -> Self {
+        Self::from_engine_state(crate::create_default_context())
+    }
+
+    /// Create an engine from a custom [`EngineState`], e.g. one with additional command
+    /// contexts merged in.
+    pub fn from_engine_state(engine_state: EngineState) -> Self {
+        Self {
+            engine_state,
+            stack: Stack::new(),
+        }
+    }
+
+    /// Register additional commands.
+    pub fn add_commands(
+        &mut self,
+        commands: impl IntoIterator<Item = Box<dyn Command>>,
+    ) -> Result<(), ShellError> {
+        let delta = {
+            let mut working_set = StateWorkingSet::new(&self.engine_state);
+            for command in commands {
+                working_set.add_decl(command);
+            }
+            working_set.render()
+        };
+        self.engine_state.merge_delta(delta)
+    }
+
+    /// Set a variable that snippets can read with `$env.<name>`.
+    pub fn set_env_var(&mut self, name: impl Into<String>, value: Value) {
+        self.stack.add_env_var(name.into(), value);
+    }
+
+    /// Evaluate a snippet of nushell source and collect its output into a [`Value`].
+    pub fn eval(&mut self, source: &str) -> Result<Value, ShellError> {
+        self.eval_with_input(source, PipelineData::empty())
+    }
+
+    /// Evaluate a snippet with the given pipeline input.
+    pub fn eval_with_input(
+        &mut self,
+        source: &str,
+        input: PipelineData,
+    ) -> Result<Value, ShellError> {
+        let (block, delta) = {
+            let mut working_set = StateWorkingSet::new(&self.engine_state);
+            let block = parse(&mut working_set, None, source.as_bytes(), false);
+            if let Some(error) = working_set.parse_errors.first() {
+                return Err(ShellError::GenericError {
+                    error: "Failed to parse source".into(),
+                    msg: error.to_string(),
+                    span: Some(error.span()),
+                    help: None,
+                    inner: vec![],
+                });
+            }
+            if let Some(error) = working_set.compile_errors.first() {
+                return Err(ShellError::GenericError {
+                    error: "Failed to compile source".into(),
+                    msg: error.to_string(),
+                    span: None,
+                    help: Some(
+                        "the source may use commands that aren't registered in this engine".into(),
+                    ),
+                    inner: vec![],
+                });
+            }
+            (block, working_set.render())
+        };
+        self.engine_state.merge_delta(delta)?;
+
+        let span = block.span.unwrap_or_else(nu_protocol::Span::unknown);
+        eval_block::<WithoutDebug>(&self.engine_state, &mut self.stack, &block, input)?
+            .into_value(span)
+    }
+
+    /// The underlying [`EngineState`], for anything the façade doesn't cover.
+    pub fn engine_state(&mut self) -> &mut EngineState {
+        &mut self.engine_state
+    }
+}
+
+impl Default for Engine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn eval_simple_expression() {
+        let mut engine = Engine::new();
+        let value = engine.eval("1 + 2").unwrap();
+        assert_eq!(value, Value::test_int(3));
+    }
+
+    #[test]
+    fn definitions_persist_between_evals() {
+        let mut engine = Engine::new();
+        engine.eval("def double [x: int] { $x * 2 }").unwrap();
+        let value = engine.eval("double 21").unwrap();
+        assert_eq!(value, Value::test_int(42));
+    }
+
+    #[test]
+    fn env_vars_are_visible() {
+        let mut engine = Engine::new();
+        engine.set_env_var("EMBEDDED", Value::test_string("yes"));
+        let value = engine.eval("$env.EMBEDDED").unwrap();
+        assert_eq!(value, Value::test_string("yes"));
+    }
+
+    #[test]
+    fn parse_errors_are_reported() {
+        let mut engine = Engine::new();
+        assert!(engine.eval("let let let").is_err());
+    }
+}
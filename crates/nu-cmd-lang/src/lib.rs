@@ -2,6 +2,7 @@
 #![doc = include_str!("../README.md")]
 mod core_commands;
 mod default_context;
+pub mod embedding;
 pub mod example_support;
 mod example_test;
 #[cfg(test)]
@@ -25,6 +25,8 @@ impl Command for PluginList {
                         ("filename".into(), Type::String),
                         ("shell".into(), Type::String),
                         ("commands".into(), Type::List(Type::String.into())),
+                        ("capabilities".into(), Type::List(Type::String.into())),
+                        ("trusted".into(), Type::Bool),
                     ]
                     .into(),
                 ),
@@ -100,6 +102,8 @@ See also: `plugin use`
                     },
                     "shell" => Value::test_nothing(),
                     "commands" => Value::test_list(vec![Value::test_string("inc")]),
+                    "capabilities" => Value::test_list(vec![]),
+                    "trusted" => Value::test_bool(true),
                 })])),
             },
             Example {
@@ -151,6 +155,8 @@ struct PluginInfo {
     filename: String,
     shell: Option<String>,
     commands: Vec<String>,
+    capabilities: Vec<String>,
+    trusted: bool,
 }
 
 #[derive(Debug, Clone, Copy, IntoValue, PartialOrd, Ord, PartialEq, Eq)]
@@ -184,9 +190,11 @@ fn get_plugins_in_engine(engine_state: &EngineState) -> Vec<PluginInfo> {
                 .sorted()
                 .collect();
 
+            let metadata = plugin.metadata();
+
             PluginInfo {
                 name: plugin.identity().name().into(),
-                version: plugin.metadata().and_then(|m| m.version),
+                version: metadata.as_ref().and_then(|m| m.version.clone()),
                 status: if plugin.pid().is_some() {
                     PluginStatus::Running
                 } else {
@@ -199,6 +207,19 @@ fn get_plugins_in_engine(engine_state: &EngineState) -> Vec<PluginInfo> {
                     .shell()
                     .map(|path| path.to_string_lossy().into_owned()),
                 commands,
+                capabilities: metadata
+                    .map(|m| {
+                        m.capabilities
+                            .iter()
+                            .map(|capability| capability.name().to_owned())
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                trusted: plugin
+                    .clone()
+                    .as_any()
+                    .downcast::<nu_plugin_engine::PersistentPlugin>()
+                    .is_ok_and(|plugin| plugin.is_trusted()),
             }
         })
         .sorted()
@@ -225,10 +246,17 @@ fn get_plugins_in_registry(
                 filename: plugin.filename.to_string_lossy().into_owned(),
                 shell: plugin.shell.map(|path| path.to_string_lossy().into_owned()),
                 commands: vec![],
+                capabilities: vec![],
+                trusted: plugin.trusted,
             };
 
             if let PluginRegistryItemData::Valid { metadata, commands } = plugin.data {
                 info.version = metadata.version;
+                info.capabilities = metadata
+                    .capabilities
+                    .iter()
+                    .map(|capability| capability.name().to_owned())
+                    .collect();
                 info.commands = commands
                     .into_iter()
                     .map(|command| command.sig.name)
@@ -269,8 +297,9 @@ fn merge_plugin_info(
             },
             // Exists in the registry file, but not in the engine
             EitherOrBoth::Right(info) => info,
-            // Exists in both
+            // Exists in both; the registry file is the source of truth for trust
             EitherOrBoth::Both(info_engine, info_registry) => PluginInfo {
+                trusted: info_registry.trusted,
                 status: match (info_engine.status, info_registry.status) {
                     // Above all, `running` should be displayed if the plugin is running
                     (PluginStatus::Running, _) => PluginStatus::Running,
@@ -2,14 +2,20 @@ use nu_engine::{command_prelude::*, get_full_help};
 
 mod add;
 mod list;
+mod new;
+mod reload;
 mod rm;
 mod stop;
+mod trust;
 mod use_;
 
 pub use add::PluginAdd;
 pub use list::PluginList;
+pub use new::PluginNew;
+pub use reload::PluginReload;
 pub use rm::PluginRm;
 pub use stop::PluginStop;
+pub use trust::{PluginTrust, PluginUntrust};
 pub use use_::PluginUse;
 
 #[derive(Clone)]
@@ -0,0 +1,259 @@
+use nu_engine::command_prelude::*;
+use nu_protocol::shell_error::io::IoError;
+use std::path::PathBuf;
+
+#[derive(Clone)]
+pub struct PluginNew;
+
+impl Command for PluginNew {
+    fn name(&self) -> &str {
+        "plugin new"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_type(Type::Nothing, Type::String)
+            .required(
+                "name",
+                SyntaxShape::String,
+                "The plugin name, e.g. `my-thing` to create `nu_plugin_my_thing`.",
+            )
+            .named(
+                "path",
+                SyntaxShape::Directory,
+                "Directory to create the crate in (defaults to the current directory)",
+                Some('p'),
+            )
+            .category(Category::Plugin)
+    }
+
+    fn description(&self) -> &str {
+        "Generate a working Rust plugin crate wired to the current nu-plugin version."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"The generated crate contains a simple value-based command, a streaming command, and
+an example test using nu-plugin-test-support. Build it with `cargo build`, then register
+it with `plugin add target/debug/nu_plugin_<name>`."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["scaffold", "template", "generate"]
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            example: "plugin new my-thing",
+            description: "Create a `nu_plugin_my_thing` crate in the current directory.",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let name: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let base: Option<Spanned<String>> = call.get_flag(engine_state, stack, "path")?;
+        let cwd = engine_state.cwd(Some(stack))?;
+
+        let snake = name.item.replace('-', "_").to_lowercase();
+        if snake.is_empty() || !snake.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return Err(ShellError::IncorrectValue {
+                msg: "plugin names may only contain letters, digits, `-`, and `_`".into(),
+                val_span: name.span,
+                call_span: head,
+            });
+        }
+        let crate_name = format!("nu_plugin_{snake}");
+
+        let base = match base {
+            Some(path) => nu_path::expand_path_with(&path.item, &cwd, true),
+            None => cwd.into(),
+        };
+        let root: PathBuf = base.join(&crate_name);
+        if root.exists() {
+            return Err(ShellError::GenericError {
+                error: format!("`{}` already exists", root.display()),
+                msg: "won't overwrite an existing directory".into(),
+                span: Some(name.span),
+                help: None,
+                inner: vec![],
+            });
+        }
+
+        let from_io_error =
+            |err: std::io::Error, path: PathBuf| IoError::new(err.kind(), head, path);
+        std::fs::create_dir_all(root.join("src"))
+            .map_err(|err| from_io_error(err, root.clone()))?;
+
+        let version = env!("CARGO_PKG_VERSION");
+        let cargo_toml = format!(
+            r#"[package]
+name = "{crate_name}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+nu-plugin = "{version}"
+nu-protocol = "{version}"
+
+[dev-dependencies]
+nu-plugin-test-support = "{version}"
+"#
+        );
+        std::fs::write(root.join("Cargo.toml"), cargo_toml)
+            .map_err(|err| from_io_error(err, root.join("Cargo.toml")))?;
+
+        let main_rs = main_rs_template(&snake);
+        std::fs::write(root.join("src/main.rs"), main_rs)
+            .map_err(|err| from_io_error(err, root.join("src/main.rs")))?;
+
+        Ok(Value::string(
+            format!(
+                "created {}\nbuild it with `cargo build`, then `plugin add target/debug/{crate_name}`",
+                root.display()
+            ),
+            head,
+        )
+        .into_pipeline_data())
+    }
+}
+
+/// The template for the generated plugin's `src/main.rs`.
+fn main_rs_template(snake: &str) -> String {
+    format!(
+        r##"use nu_plugin::{{
+    serve_plugin, EngineInterface, EvaluatedCall, MsgPackSerializer, Plugin, PluginCommand,
+    SimplePluginCommand,
+}};
+use nu_protocol::{{
+    Category, Example, IntoInterruptiblePipelineData, LabeledError, PipelineData, Signature,
+    Signals, Type, Value,
+}};
+
+pub struct {type_name}Plugin;
+
+impl Plugin for {type_name}Plugin {{
+    fn version(&self) -> String {{
+        env!("CARGO_PKG_VERSION").into()
+    }}
+
+    fn commands(&self) -> Vec<Box<dyn PluginCommand<Plugin = Self>>> {{
+        vec![Box::new(Greet), Box::new(Countdown)]
+    }}
+}}
+
+/// A simple value-in, value-out command.
+pub struct Greet;
+
+impl SimplePluginCommand for Greet {{
+    type Plugin = {type_name}Plugin;
+
+    fn name(&self) -> &str {{
+        "{snake} greet"
+    }}
+
+    fn description(&self) -> &str {{
+        "Greet somebody"
+    }}
+
+    fn signature(&self) -> Signature {{
+        Signature::build(PluginCommand::name(self))
+            .input_output_type(Type::String, Type::String)
+            .category(Category::Experimental)
+    }}
+
+    fn examples(&self) -> Vec<Example> {{
+        vec![Example {{
+            example: r#""world" | {snake} greet"#,
+            description: "Greet the world",
+            result: Some(Value::test_string("Hello, world!")),
+        }}]
+    }}
+
+    fn run(
+        &self,
+        _plugin: &{type_name}Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: &Value,
+    ) -> Result<Value, LabeledError> {{
+        let name = input.coerce_str()?;
+        Ok(Value::string(format!("Hello, {{name}}!"), call.head))
+    }}
+}}
+
+/// A streaming command: produces values incrementally instead of all at once.
+pub struct Countdown;
+
+impl PluginCommand for Countdown {{
+    type Plugin = {type_name}Plugin;
+
+    fn name(&self) -> &str {{
+        "{snake} countdown"
+    }}
+
+    fn description(&self) -> &str {{
+        "Count down from a number, streaming each value"
+    }}
+
+    fn signature(&self) -> Signature {{
+        Signature::build(PluginCommand::name(self))
+            .input_output_type(Type::Int, Type::List(Type::Int.into()))
+            .category(Category::Experimental)
+    }}
+
+    fn run(
+        &self,
+        _plugin: &{type_name}Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {{
+        let head = call.head;
+        let from = input.into_value(head)?.as_int()?;
+        Ok((0..=from)
+            .rev()
+            .map(move |n| Value::int(n, head))
+            .into_pipeline_data(head, Signals::empty()))
+    }}
+}}
+
+fn main() {{
+    serve_plugin(&{type_name}Plugin, MsgPackSerializer)
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+    use nu_plugin_test_support::PluginTest;
+
+    #[test]
+    fn test_examples() -> Result<(), nu_protocol::ShellError> {{
+        PluginTest::new("{snake}", {type_name}Plugin.into())?.test_command_examples(&Greet)
+    }}
+}}
+"##,
+        type_name = heck_upper_camel(snake),
+        snake = snake,
+    )
+}
+
+/// `my_thing` -> `MyThing`, enough for generated type names.
+fn heck_upper_camel(snake: &str) -> String {
+    snake
+        .split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
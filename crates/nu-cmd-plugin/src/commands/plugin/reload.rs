@@ -0,0 +1,133 @@
+use crate::util::{canonicalize_possible_filename_arg, modify_plugin_file};
+use nu_engine::command_prelude::*;
+use nu_plugin_engine::{GetPlugin, PersistentPlugin};
+use nu_protocol::{PluginRegistryItem, RegisteredPlugin};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct PluginReload;
+
+impl Command for PluginReload {
+    fn name(&self) -> &str {
+        "plugin reload"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_type(Type::Nothing, Type::Nothing)
+            .named(
+                "plugin-config",
+                SyntaxShape::Filepath,
+                "Use a plugin registry file other than the one set in `$nu.plugin-path`",
+                None,
+            )
+            .required(
+                "name",
+                SyntaxShape::String,
+                "The name, or filename, of the plugin to reload.",
+            )
+            .category(Category::Plugin)
+    }
+
+    fn description(&self) -> &str {
+        "Restart a plugin and refresh its signatures."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"
+This stops the plugin process if it was running, starts it again, and fetches
+fresh metadata and command signatures from it. The plugin registry file (by
+default, `$nu.plugin-path`) is updated with the new signatures.
+
+Commands already in scope keep their old signatures until the plugin is next
+loaded with `plugin use`, or `nu` is restarted.
+"#
+        .trim()
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["restart", "refresh", "signature"]
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                example: "plugin reload inc",
+                description: "Restart the plugin named `inc` and refresh its signatures.",
+                result: None,
+            },
+            Example {
+                example: "plugin reload ~/.cargo/bin/nu_plugin_inc",
+                description: "Restart the plugin with the filename `~/.cargo/bin/nu_plugin_inc`.",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let name: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let custom_path = call.get_flag(engine_state, stack, "plugin-config")?;
+
+        let filename = canonicalize_possible_filename_arg(engine_state, stack, &name.item);
+
+        let plugin = engine_state
+            .plugins()
+            .iter()
+            .find(|plugin| {
+                let id = &plugin.identity();
+                id.name() == name.item || id.filename() == filename
+            })
+            .cloned()
+            .ok_or_else(|| ShellError::GenericError {
+                error: format!("Failed to reload the `{}` plugin", name.item),
+                msg: "couldn't find a plugin with this name".into(),
+                span: Some(name.span),
+                help: Some("you may need to `plugin add` the plugin first".into()),
+                inner: vec![],
+            })?;
+
+        let plugin: Arc<PersistentPlugin> =
+            plugin
+                .as_any()
+                .downcast()
+                .map_err(|_| ShellError::GenericError {
+                    error: format!("Unable to reload the `{}` plugin", name.item),
+                    msg: "the plugin is not managed by the plugin system".into(),
+                    span: Some(name.span),
+                    help: None,
+                    inner: vec![],
+                })?;
+
+        // Stop the plugin and clear cached state, so the relaunch picks up a new executable and
+        // fresh signatures.
+        plugin.reset()?;
+
+        let interface = plugin.clone().get_plugin(Some((engine_state, stack)))?;
+        let metadata = interface.get_metadata()?;
+        let commands = interface.get_signature()?;
+        plugin.set_metadata(Some(metadata.clone()));
+
+        // Update the registry file if one is available, so the refreshed signatures are used the
+        // next time the plugin is loaded at parse time.
+        if custom_path.is_some() || engine_state.plugin_path.is_some() {
+            modify_plugin_file(engine_state, stack, call.head, &custom_path, |contents| {
+                let mut item = PluginRegistryItem::new(plugin.identity(), metadata, commands);
+                // Reloading refreshes signatures; it isn't a consent step, so keep the
+                // existing trust grant as-is
+                if let Some(existing) = contents.plugins.iter().find(|p| p.name == item.name) {
+                    item.trusted = existing.trusted;
+                }
+                contents.upsert_plugin(item);
+                Ok(())
+            })?;
+        }
+
+        Ok(Value::nothing(call.head).into_pipeline_data())
+    }
+}
@@ -0,0 +1,171 @@
+use nu_engine::command_prelude::*;
+
+use crate::util::{canonicalize_possible_filename_arg, modify_plugin_file};
+
+#[derive(Clone)]
+pub struct PluginTrust;
+
+impl Command for PluginTrust {
+    fn name(&self) -> &str {
+        "plugin trust"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_type(Type::Nothing, Type::Nothing)
+            // This matches the option to `nu`
+            .named(
+                "plugin-config",
+                SyntaxShape::Filepath,
+                "Use a plugin registry file other than the one set in `$nu.plugin-path`",
+                None,
+            )
+            .required(
+                "name",
+                SyntaxShape::String,
+                "The name, or filename, of the plugin to trust.",
+            )
+            .category(Category::Plugin)
+    }
+
+    fn description(&self) -> &str {
+        "Grant a plugin trust in the plugin registry file."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"
+With `$env.config.plugin_trust_required` set, commands from plugins that
+haven't been granted trust refuse to run. `plugin add` grants trust as part of
+adding a plugin; this command grants it to a plugin already in the registry
+file (e.g. one added before trust was being tracked, or revoked with
+`plugin untrust`).
+
+Like `plugin add`, this only modifies the registry file. Run `plugin use` (on
+a later line - it reads the registry file at parse time) or restart `nu` for
+the change to take effect.
+"#
+        .trim()
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["permission", "allow", "signature"]
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            example: "plugin trust inc",
+            description: "Grant the `inc` plugin trust; a later `plugin use inc` picks it up.",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        set_plugin_trust(self, engine_state, stack, call, true)?;
+        Ok(Value::nothing(call.head).into_pipeline_data())
+    }
+}
+
+#[derive(Clone)]
+pub struct PluginUntrust;
+
+impl Command for PluginUntrust {
+    fn name(&self) -> &str {
+        "plugin untrust"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_type(Type::Nothing, Type::Nothing)
+            // This matches the option to `nu`
+            .named(
+                "plugin-config",
+                SyntaxShape::Filepath,
+                "Use a plugin registry file other than the one set in `$nu.plugin-path`",
+                None,
+            )
+            .required(
+                "name",
+                SyntaxShape::String,
+                "The name, or filename, of the plugin to revoke trust from.",
+            )
+            .category(Category::Plugin)
+    }
+
+    fn description(&self) -> &str {
+        "Revoke a plugin's trust in the plugin registry file."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"
+The plugin stays in the registry file and its commands stay in scope, but with
+`$env.config.plugin_trust_required` set they refuse to run after the next
+`plugin use` or restart. Use `plugin rm` to remove the plugin entirely.
+"#
+        .trim()
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["permission", "deny", "revoke"]
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            example: "plugin untrust inc",
+            description: "Revoke trust from the `inc` plugin.",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        set_plugin_trust(self, engine_state, stack, call, false)?;
+        Ok(Value::nothing(call.head).into_pipeline_data())
+    }
+}
+
+fn set_plugin_trust(
+    command: &impl Command,
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    trusted: bool,
+) -> Result<(), ShellError> {
+    let name: Spanned<String> = call.req(engine_state, stack, 0)?;
+    let custom_path = call.get_flag(engine_state, stack, "plugin-config")?;
+
+    let filename = canonicalize_possible_filename_arg(engine_state, stack, &name.item);
+
+    modify_plugin_file(engine_state, stack, call.head, &custom_path, |contents| {
+        if let Some(plugin) = contents
+            .plugins
+            .iter_mut()
+            .find(|p| p.name == name.item || p.filename == filename)
+        {
+            plugin.trusted = trusted;
+            Ok(())
+        } else {
+            Err(ShellError::GenericError {
+                error: format!(
+                    "Failed to run `{}` for the `{}` plugin",
+                    command.name(),
+                    name.item
+                ),
+                msg: "couldn't find a plugin with this name in the registry file".into(),
+                span: Some(name.span),
+                help: Some("add the plugin with `plugin add` first".into()),
+                inner: vec![],
+            })
+        }
+    })
+}
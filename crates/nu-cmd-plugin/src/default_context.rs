@@ -15,7 +15,11 @@ pub fn add_plugin_command_context(mut engine_state: EngineState) -> EngineState
             PluginAdd,
             PluginCommand,
             PluginList,
+            PluginNew,
+            PluginReload,
             PluginRm,
+            PluginTrust,
+            PluginUntrust,
             PluginStop,
             PluginUse,
         );
@@ -23,6 +23,8 @@ impl Command for Histogram {
             .optional("column-name", SyntaxShape::String, "Column name to calc frequency, no need to provide if input is a list.")
             .optional("frequency-column-name", SyntaxShape::String, "Histogram's frequency column, default to be frequency column output.")
             .named("percentage-type", SyntaxShape::String, "percentage calculate method, can be 'normalize' or 'relative', in 'normalize', defaults to be 'normalize'", Some('t'))
+            .named("bins", SyntaxShape::Int, "for numeric input, group the values into this many equal-width bins", Some('b'))
+            .named("bin-width", SyntaxShape::Number, "for numeric input, group the values into bins of this width", None)
             .category(Category::Chart)
     }
 
@@ -120,20 +122,147 @@ impl Command for Histogram {
             },
         };
 
+        let bins: Option<Spanned<i64>> = call.get_flag(engine_state, stack, "bins")?;
+        let bin_width: Option<Spanned<f64>> = call
+            .get_flag::<Value>(engine_state, stack, "bin-width")?
+            .map(|value| {
+                let span = value.span();
+                value.coerce_float().map(|width| width.into_spanned(span))
+            })
+            .transpose()?;
+        if bins.is_some() && bin_width.is_some() {
+            return Err(ShellError::IncompatibleParametersSingle {
+                msg: "use either `--bins` or `--bin-width`, not both".into(),
+                span: call.head,
+            });
+        }
+        let binning = match (bins, bin_width) {
+            (Some(bins), _) if bins.item < 1 => {
+                return Err(ShellError::IncorrectValue {
+                    msg: "there must be at least one bin".into(),
+                    val_span: bins.span,
+                    call_span: call.head,
+                });
+            }
+            (Some(bins), _) => Some(Binning::Count(bins.item as usize)),
+            (_, Some(width)) if width.item <= 0.0 => {
+                return Err(ShellError::IncorrectValue {
+                    msg: "the bin width must be positive".into(),
+                    val_span: width.span,
+                    call_span: call.head,
+                });
+            }
+            (_, Some(width)) => Some(Binning::Width(width.item)),
+            _ => None,
+        };
+
         let span = call.head;
         let data_as_value = input.into_value(span)?;
         let value_span = data_as_value.span();
+        let value_column_name = column_name
+            .as_ref()
+            .map(|name| name.item.clone())
+            .unwrap_or_else(|| "value".to_string());
         // `input` is not a list, here we can return an error.
-        run_histogram(
+        let result = run_histogram(
             data_as_value.into_list()?,
             column_name,
             frequency_column_name,
             calc_method,
+            binning,
             span,
             // Note that as_list() filters out Value::Error here.
             value_span,
-        )
+        )?;
+
+        // Binned output reads left to right over the numeric range, not by frequency
+        if binning.is_some() {
+            let mut rows = result.into_value(span)?.into_list()?;
+            rows.sort_by(|a, b| {
+                let low = |row: &Value| {
+                    row.get_data_by_key(&value_column_name)
+                        .and_then(|value| value.coerce_str().ok().map(|s| s.into_owned()))
+                        .and_then(|label| {
+                            label
+                                .split_whitespace()
+                                .next()
+                                .and_then(|low| low.parse::<f64>().ok())
+                        })
+                };
+                low(a)
+                    .partial_cmp(&low(b))
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+            return Ok(Value::list(rows, span).into_pipeline_data());
+        }
+
+        Ok(result)
+    }
+}
+
+/// How numeric inputs are grouped into bins.
+#[derive(Clone, Copy)]
+enum Binning {
+    /// A fixed number of equal-width bins over the input range
+    Count(usize),
+    /// Bins of a fixed width, aligned to multiples of the width
+    Width(f64),
+}
+
+/// Replace numeric inputs with the range they fall in, so equal counting buckets them.
+fn bin_inputs(
+    inputs: Vec<HashableValue>,
+    binning: Binning,
+    span: Span,
+) -> Result<Vec<HashableValue>, ShellError> {
+    let mut numbers = Vec::with_capacity(inputs.len());
+    for input in &inputs {
+        match input {
+            HashableValue::Int { val, .. } => numbers.push(*val as f64),
+            HashableValue::Float { val, .. } => numbers.push(f64::from_ne_bytes(*val)),
+            _ => {
+                return Err(ShellError::UnsupportedInput {
+                    msg: "binning only works on numeric input".into(),
+                    input: "a non-numeric value".into(),
+                    msg_span: span,
+                    input_span: span,
+                });
+            }
+        }
     }
+
+    let (start, width) = match binning {
+        Binning::Width(width) => (0.0, width),
+        Binning::Count(bins) => {
+            let min = numbers.iter().copied().fold(f64::INFINITY, f64::min);
+            let max = numbers.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+            if !min.is_finite() || !max.is_finite() || min == max {
+                // Everything in one bin; fall back to plain value counting
+                return Ok(inputs);
+            }
+            (min, (max - min) / bins as f64)
+        }
+    };
+
+    let max_bin = match binning {
+        // The maximum value itself belongs to the last bin, not one past it
+        Binning::Count(bins) => Some((bins - 1) as f64),
+        Binning::Width(_) => None,
+    };
+    Ok(numbers
+        .into_iter()
+        .map(|number| {
+            let mut bin = ((number - start) / width).floor();
+            if let Some(max_bin) = max_bin {
+                bin = bin.min(max_bin);
+            }
+            let low = start + bin * width;
+            HashableValue::String {
+                val: format!("{low:.2} ..< {:.2}", low + width),
+                span,
+            }
+        })
+        .collect())
 }
 
 fn run_histogram(
@@ -141,6 +270,7 @@ fn run_histogram(
     column_name: Option<Spanned<String>>,
     freq_column: String,
     calc_method: PercentageCalcMethod,
+    binning: Option<Binning>,
     head_span: Span,
     list_span: Span,
 ) -> Result<PipelineData, ShellError> {
@@ -199,6 +329,11 @@ fn run_histogram(
         }
     }
 
+    let inputs = match binning {
+        Some(binning) => bin_inputs(inputs, binning, head_span)?,
+        None => inputs,
+    };
+
     let value_column_name = column_name
         .map(|x| x.item)
         .unwrap_or_else(|| "value".to_string());
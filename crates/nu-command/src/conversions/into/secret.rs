@@ -0,0 +1,115 @@
+use nu_engine::command_prelude::*;
+use nu_protocol::SecretValue;
+
+#[derive(Clone)]
+pub struct IntoSecret;
+
+impl Command for IntoSecret {
+    fn name(&self) -> &str {
+        "into secret"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("into secret")
+            .input_output_types(vec![(Type::String, Type::custom("secret"))])
+            .category(Category::Conversions)
+    }
+
+    fn description(&self) -> &str {
+        "Wrap a string in a secret value that displays as <redacted>."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Tables, `to json`, `to nuon`, and other output paths only ever see `<redacted>`, so
+tokens can flow through pipelines without leaking into output by accident. The content is
+only accessible deliberately, with `secret reveal`."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["redact", "sensitive", "password", "token"]
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Keep a token out of any rendered output",
+            example: r#""hunter2" | into secret"#,
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let value = input.into_value(head)?;
+        let span = value.span();
+        let secret = value.coerce_into_string()?;
+        Ok(SecretValue::new(secret)
+            .into_value(span)
+            .into_pipeline_data())
+    }
+}
+
+#[derive(Clone)]
+pub struct SecretReveal;
+
+impl Command for SecretReveal {
+    fn name(&self) -> &str {
+        "secret reveal"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("secret reveal")
+            .input_output_types(vec![(Type::custom("secret"), Type::String)])
+            .category(Category::Conversions)
+    }
+
+    fn description(&self) -> &str {
+        "Deliberately unwrap a secret value back into a plain string."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["redact", "unwrap", "expose"]
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Use a secret where the real value is needed",
+            example: r#"$env.TOKEN | secret reveal | str length"#,
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let value = input.into_value(head)?;
+        let span = value.span();
+        match value {
+            Value::Custom { val, .. } => match val.as_any().downcast_ref::<SecretValue>() {
+                Some(secret) => Ok(Value::string(secret.reveal(), span).into_pipeline_data()),
+                None => Err(ShellError::OnlySupportsThisInputType {
+                    exp_input_type: "secret".into(),
+                    wrong_type: val.type_name(),
+                    dst_span: head,
+                    src_span: span,
+                }),
+            },
+            other => Err(ShellError::OnlySupportsThisInputType {
+                exp_input_type: "secret".into(),
+                wrong_type: other.get_type().to_string(),
+                dst_span: head,
+                src_span: other.span(),
+            }),
+        }
+    }
+}
@@ -34,6 +34,12 @@ impl Command for IntoSqliteDb {
                 "Specify table name to store the data in",
                 Some('t'),
             )
+            .named(
+                "schema",
+                SyntaxShape::Record(vec![]),
+                "Specify the column types explicitly, as a record of column -> type (nu type names or SQL types), or the record produced by `describe --schema`",
+                Some('s'),
+            )
     }
 
     fn run(
@@ -83,12 +89,14 @@ impl Command for IntoSqliteDb {
 struct Table {
     conn: rusqlite::Connection,
     table_name: String,
+    schema: Option<Record>,
 }
 
 impl Table {
     pub fn new(
         db_path: &Spanned<String>,
         table_name: Option<Spanned<String>>,
+        schema: Option<Record>,
     ) -> Result<Self, nu_protocol::ShellError> {
         let table_name = if let Some(table_name) = table_name {
             table_name.item
@@ -99,7 +107,11 @@ impl Table {
         // create the sqlite database table
         let conn = open_sqlite_db(Path::new(&db_path.item), db_path.span)?;
 
-        Ok(Self { conn, table_name })
+        Ok(Self {
+            conn,
+            table_name,
+            schema,
+        })
     }
 
     pub fn name(&self) -> &String {
@@ -110,8 +122,11 @@ impl Table {
         &mut self,
         record: &Record,
     ) -> Result<rusqlite::Transaction, nu_protocol::ShellError> {
-        let first_row_null = record.values().any(Value::is_nothing);
-        let columns = get_columns_with_sqlite_types(record)?;
+        let first_row_null = self.schema.is_none() && record.values().any(Value::is_nothing);
+        let columns = match &self.schema {
+            Some(schema) => columns_from_schema(schema)?,
+            None => get_columns_with_sqlite_types(record)?,
+        };
 
         let table_exists_query = format!(
             "SELECT count(*) FROM sqlite_master WHERE type='table' AND name='{}';",
@@ -182,7 +197,8 @@ fn operate(
     let span = call.head;
     let file_name: Spanned<String> = call.req(engine_state, stack, 0)?;
     let table_name: Option<Spanned<String>> = call.get_flag(engine_state, stack, "table-name")?;
-    let table = Table::new(&file_name, table_name)?;
+    let schema: Option<Record> = call.get_flag(engine_state, stack, "schema")?;
+    let table = Table::new(&file_name, table_name, schema)?;
     Ok(action(input, table, span, engine_state.signals())?.into_pipeline_data())
 }
 
@@ -388,6 +404,61 @@ fn get_columns_with_sqlite_types(
     Ok(columns)
 }
 
+/// Build the column list from an explicit schema: either a plain record of column -> type, or
+/// the `{type: record, fields: {...}}` / `{type: list, items: {...}}` form that
+/// `describe --schema` produces.
+fn columns_from_schema(schema: &Record) -> Result<Vec<(String, &'static str)>, ShellError> {
+    // Unwrap `describe --schema` wrappers down to the fields record
+    let mut fields = schema;
+    loop {
+        match fields.get("type").and_then(|ty| ty.as_str().ok()) {
+            Some("list") => match fields.get("items") {
+                Some(Value::Record { val, .. }) => fields = val,
+                _ => break,
+            },
+            Some("record") => match fields.get("fields") {
+                Some(Value::Record { val, .. }) => {
+                    fields = val;
+                    break;
+                }
+                _ => break,
+            },
+            _ => break,
+        }
+    }
+
+    let mut columns = Vec::new();
+    for (column, spec) in fields.iter() {
+        // A field schema may itself be a record carrying `type`
+        let type_name = match spec {
+            Value::Record { val, .. } => val
+                .get("type")
+                .and_then(|ty| ty.as_str().ok().map(String::from))
+                .unwrap_or_else(|| "any".into()),
+            other => other.coerce_string()?,
+        };
+        let sql_type = match type_name.to_ascii_lowercase().as_str() {
+            "string" | "text" => "TEXT",
+            "int" | "integer" | "filesize" => "INTEGER",
+            "float" | "number" | "real" => "REAL",
+            "bool" | "boolean" => "BOOLEAN",
+            "date" | "datetime" => "DATETIME",
+            "duration" | "bigint" => "BIGINT",
+            "binary" | "blob" => "BLOB",
+            "any" | "nothing" => "TEXT",
+            other => {
+                return Err(ShellError::IncorrectValue {
+                    msg: format!("unknown column type `{other}` for column `{column}`"),
+                    val_span: spec.span(),
+                    call_span: spec.span(),
+                });
+            }
+        };
+        columns.push((format!("`{column}`"), sql_type));
+    }
+    Ok(columns)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -33,6 +33,22 @@ impl Command for ViewSource {
         let arg_span = arg.span();
 
         let source = match arg {
+            Value::Closure { ref val, .. } => {
+                let block = engine_state.get_block(val.block_id);
+                if let Some(span) = block.span {
+                    let contents = engine_state.get_span_contents(span);
+                    Ok(Value::string(String::from_utf8_lossy(contents), call.head)
+                        .into_pipeline_data())
+                } else {
+                    Err(ShellError::GenericError {
+                        error: "Cannot view closure".to_string(),
+                        msg: "the closure does not have a viewable span".to_string(),
+                        span: Some(arg_span),
+                        help: None,
+                        inner: vec![],
+                    })
+                }
+            }
             Value::Int { val, .. } => {
                 if let Some(block) =
                     engine_state.try_get_block(nu_protocol::BlockId::new(val as usize))
@@ -21,6 +21,12 @@ impl Command for ViewSpan {
             .input_output_types(vec![(Type::Nothing, Type::String)])
             .required("start", SyntaxShape::Int, "Start of the span.")
             .required("end", SyntaxShape::Int, "End of the span.")
+            .named(
+                "context",
+                SyntaxShape::Int,
+                "Also show this many lines of surrounding source",
+                Some('c'),
+            )
             .category(Category::Debug)
     }
 
@@ -33,14 +39,53 @@ impl Command for ViewSpan {
     ) -> Result<PipelineData, ShellError> {
         let start_span: Spanned<usize> = call.req(engine_state, stack, 0)?;
         let end_span: Spanned<usize> = call.req(engine_state, stack, 1)?;
+        let context: Option<usize> = call.get_flag(engine_state, stack, "context")?;
 
         if start_span.item < end_span.item {
-            let bin_contents =
-                engine_state.get_span_contents(Span::new(start_span.item, end_span.item));
-            Ok(
-                Value::string(String::from_utf8_lossy(bin_contents), call.head)
-                    .into_pipeline_data(),
-            )
+            let span = Span::new(start_span.item, end_span.item);
+            let Some(context) = context else {
+                let bin_contents = engine_state.get_span_contents(span);
+                return Ok(
+                    Value::string(String::from_utf8_lossy(bin_contents), call.head)
+                        .into_pipeline_data(),
+                );
+            };
+
+            // Widen the span to whole lines, plus `context` lines on each side, within the file
+            // (or other contiguous source block) the span belongs to
+            let block_span = engine_state
+                .files()
+                .find(|file| {
+                    file.covered_span.start <= start_span.item
+                        && end_span.item <= file.covered_span.end
+                })
+                .map(|file| file.covered_span)
+                .unwrap_or(span);
+            let contents = engine_state.get_span_contents(block_span);
+            let text = String::from_utf8_lossy(contents);
+            let rel_start = span.start - block_span.start;
+            let rel_end = span.end - block_span.start;
+
+            let mut line_starts: Vec<usize> = Some(0)
+                .into_iter()
+                .chain(text.match_indices('\n').map(|(idx, _)| idx + 1))
+                .collect();
+            line_starts.push(text.len());
+
+            let span_first_line = line_starts
+                .iter()
+                .rposition(|&start| start <= rel_start)
+                .unwrap_or(0);
+            let span_last_line = line_starts
+                .iter()
+                .rposition(|&start| start < rel_end.max(1))
+                .unwrap_or(0);
+            let first_line = span_first_line.saturating_sub(context);
+            let last_line = (span_last_line + context).min(line_starts.len().saturating_sub(2));
+
+            let snippet =
+                text[line_starts[first_line]..line_starts[last_line + 1]].trim_end_matches('\n');
+            Ok(Value::string(snippet, call.head).into_pipeline_data())
         } else {
             Err(ShellError::GenericError {
                 error: "Cannot view span".to_string(),
@@ -84,6 +84,7 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
             SplitList,
             Tee,
             Transpose,
+            Unflatten,
             Uniq,
             UniqBy,
             Upsert,
@@ -97,7 +98,12 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
 
         // Misc
         bind_command! {
+            Diff,
             Panic,
+            Patch,
+            PatchApply,
+            ToJsonPatch,
+            Validate,
             Source,
             Tutor,
         };
@@ -192,10 +198,14 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
             Encode,
             DecodeHex,
             EncodeHex,
+            DecodeAscii85,
+            EncodeAscii85,
             DecodeBase32,
             EncodeBase32,
             DecodeBase32Hex,
             EncodeBase32Hex,
+            DecodeBrotli,
+            EncodeBrotli,
             DecodeBase64,
             EncodeBase64,
             DetectColumns,
@@ -224,6 +234,7 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
             StrUpcase,
             Format,
             FormatDate,
+            FormatNu,
             FormatDuration,
             FormatFilesize,
         };
@@ -238,9 +249,15 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
             UMv,
             UCp,
             Open,
+            Pwd,
             Start,
             Rm,
             Save,
+            Link,
+            LinkCreate,
+            LinkRead,
+            UChmod,
+            UChown,
             UTouch,
             Glob,
             Watch,
@@ -250,6 +267,7 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
         #[cfg(feature = "os")]
         bind_command! {
             Ansi,
+            AnsiApply,
             AnsiLink,
             AnsiStrip,
             Clear,
@@ -335,6 +353,8 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
             IntoFilesize,
             IntoInt,
             IntoRecord,
+            IntoSecret,
+            SecretReveal,
             IntoString,
             IntoGlob,
             IntoValue,
@@ -413,6 +433,7 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
             UrlDecode,
             UrlEncode,
             UrlJoin,
+            UrlResolve,
             UrlParse,
         }
 
@@ -67,6 +67,34 @@ impl Command for Cd {
                     } else {
                         cwd
                     }
+                } else if let Some(back) = {
+                    // An unquoted `+N` is a directory literal and has already been expanded to
+                    // `<cwd>/+N` by now, so look at the original argument text as well
+                    let arg_text = String::from_utf8_lossy(engine_state.get_span_contents(v.span));
+                    [v.item.as_str(), arg_text.as_ref()].iter().find_map(|arg| {
+                        arg.strip_prefix('+')
+                            .or_else(|| arg.strip_prefix('-'))
+                            .and_then(|n| n.parse::<usize>().ok())
+                    })
+                } {
+                    // `cd +N` (or `cd "-N"`) goes N entries back in the directory history; the
+                    // unquoted `-N` form is taken by flag parsing
+                    let history = stack
+                        .get_env_var(engine_state, "DIRS_HISTORY")
+                        .cloned()
+                        .and_then(|history| history.into_list().ok())
+                        .unwrap_or_default();
+                    if back == 0 || back > history.len() {
+                        return Err(ShellError::IncorrectValue {
+                            msg: format!(
+                                "the directory history currently holds {} entries",
+                                history.len()
+                            ),
+                            val_span: v.span,
+                            call_span: call.head,
+                        });
+                    }
+                    history[history.len() - back].to_path()?
                 } else {
                     // Trim whitespace from the end of path.
                     let path_no_whitespace =
@@ -119,10 +147,23 @@ impl Command for Cd {
             None => nu_path::expand_tilde("~"),
         };
 
-        // Set OLDPWD.
+        // Set OLDPWD and append it to the directory history.
         // We're using `Stack::get_env_var()` instead of `EngineState::cwd()` to avoid a conversion roundtrip.
         if let Some(oldpwd) = stack.get_env_var(engine_state, "PWD") {
-            stack.add_env_var("OLDPWD".into(), oldpwd.clone())
+            let oldpwd = oldpwd.clone();
+            let mut history = stack
+                .get_env_var(engine_state, "DIRS_HISTORY")
+                .cloned()
+                .and_then(|history| history.into_list().ok())
+                .unwrap_or_default();
+            history.push(oldpwd.clone());
+            // Keep the history bounded; nobody jumps back a thousand directories
+            const DIRS_HISTORY_MAX: usize = 100;
+            if history.len() > DIRS_HISTORY_MAX {
+                history.drain(..history.len() - DIRS_HISTORY_MAX);
+            }
+            stack.add_env_var("DIRS_HISTORY".into(), Value::list(history, Span::unknown()));
+            stack.add_env_var("OLDPWD".into(), oldpwd)
         }
 
         match have_permission(&path) {
@@ -150,6 +191,11 @@ impl Command for Cd {
                 example: r#"cd -"#,
                 result: None,
             },
+            Example {
+                description: "Change to the directory you were in two `cd`s ago (see $env.DIRS_HISTORY). A real directory named `+2` is still reachable as `cd ./+2`",
+                example: r#"cd +2"#,
+                result: None,
+            },
             Example {
                 description: "Changing directory with a custom command requires 'def --env'",
                 example: r#"def --env gohome [] { cd ~ }"#,
@@ -0,0 +1,227 @@
+use nu_engine::{command_prelude::*, get_full_help};
+use nu_path::expand_path_with;
+use nu_protocol::shell_error::io::IoError;
+use std::path::{Component, Path, PathBuf};
+
+#[derive(Clone)]
+pub struct Link;
+
+impl Command for Link {
+    fn name(&self) -> &str {
+        "link"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("link")
+            .input_output_types(vec![(Type::Nothing, Type::String)])
+            .category(Category::FileSystem)
+    }
+
+    fn description(&self) -> &str {
+        "Commands for working with file links."
+    }
+
+    fn extra_description(&self) -> &str {
+        "You must use one of the following subcommands. Using this command as-is will only produce this help message."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        Ok(Value::string(get_full_help(self, engine_state, stack), call.head).into_pipeline_data())
+    }
+}
+
+#[derive(Clone)]
+pub struct LinkCreate;
+
+impl Command for LinkCreate {
+    fn name(&self) -> &str {
+        "link create"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("link create")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .required("target", SyntaxShape::String, "What the link points at.")
+            .required("link", SyntaxShape::Filepath, "The link to create.")
+            .switch("symbolic", "create a symbolic link", Some('s'))
+            .switch("hard", "create a hard link", None)
+            .switch(
+                "relative",
+                "store the target as a path relative to the link's directory",
+                Some('r'),
+            )
+            .category(Category::FileSystem)
+    }
+
+    fn description(&self) -> &str {
+        "Create a symbolic or hard link to a file or directory."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Exactly one of `--symbolic` or `--hard` must be given. Hard links can only point at
+files on the same filesystem; symbolic links can point anywhere, including at paths that
+don't exist yet."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["ln", "symlink", "hardlink", "junction"]
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Link the current configuration into place",
+                example: "link create --symbolic ~/dotfiles/config.nu config.nu",
+                result: None,
+            },
+            Example {
+                description: "Create a relative symbolic link",
+                example: "link create --symbolic --relative ./data/input.csv latest.csv",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let target: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let link: Spanned<String> = call.req(engine_state, stack, 1)?;
+        let symbolic = call.has_flag(engine_state, stack, "symbolic")?;
+        let hard = call.has_flag(engine_state, stack, "hard")?;
+        let relative = call.has_flag(engine_state, stack, "relative")?;
+
+        if symbolic == hard {
+            return Err(ShellError::IncompatibleParametersSingle {
+                msg: "use exactly one of `--symbolic` and `--hard`".into(),
+                span: head,
+            });
+        }
+        if relative && hard {
+            return Err(ShellError::IncompatibleParametersSingle {
+                msg: "`--relative` only applies to symbolic links".into(),
+                span: head,
+            });
+        }
+
+        let cwd = engine_state.cwd(Some(stack))?;
+        let link_path = expand_path_with(&link.item, &cwd, true);
+        let target_path = expand_path_with(&target.item, &cwd, true);
+
+        let stored_target: PathBuf = if hard || relative {
+            // Hard links need a real path; --relative stores the path relative to the link
+            if relative {
+                let base = link_path.parent().unwrap_or(Path::new("."));
+                relative_to(&target_path, base)
+            } else {
+                target_path.clone()
+            }
+        } else if Path::new(&target.item).is_absolute() {
+            target_path.clone()
+        } else {
+            // Keep a relative target spelling as given, like ln(1)
+            PathBuf::from(&target.item)
+        };
+
+        let result = if hard {
+            std::fs::hard_link(&target_path, &link_path)
+        } else {
+            #[cfg(unix)]
+            {
+                std::os::unix::fs::symlink(&stored_target, &link_path)
+            }
+            #[cfg(windows)]
+            {
+                if target_path.is_dir() {
+                    std::os::windows::fs::symlink_dir(&stored_target, &link_path)
+                } else {
+                    std::os::windows::fs::symlink_file(&stored_target, &link_path)
+                }
+            }
+        };
+        result.map_err(|err| IoError::new(err.kind(), link.span, link_path))?;
+
+        Ok(PipelineData::empty())
+    }
+}
+
+#[derive(Clone)]
+pub struct LinkRead;
+
+impl Command for LinkRead {
+    fn name(&self) -> &str {
+        "link read"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("link read")
+            .input_output_types(vec![(Type::Nothing, Type::String)])
+            .required("link", SyntaxShape::Filepath, "The link to read.")
+            .category(Category::FileSystem)
+    }
+
+    fn description(&self) -> &str {
+        "Read what a symbolic link points at, one level deep."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["readlink", "resolve"]
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "See where a link points",
+            example: "link read latest.csv",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let link: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let cwd = engine_state.cwd(Some(stack))?;
+        let link_path = expand_path_with(&link.item, cwd, true);
+        let target = std::fs::read_link(&link_path)
+            .map_err(|err| IoError::new(err.kind(), link.span, link_path))?;
+        Ok(Value::string(target.to_string_lossy(), call.head).into_pipeline_data())
+    }
+}
+
+/// Compute `path` relative to `base`, walking up with `..` where necessary. Both paths should
+/// be absolute.
+fn relative_to(path: &Path, base: &Path) -> PathBuf {
+    let path_components: Vec<Component> = path.components().collect();
+    let base_components: Vec<Component> = base.components().collect();
+    let common = path_components
+        .iter()
+        .zip(&base_components)
+        .take_while(|(a, b)| a == b)
+        .count();
+    let mut result = PathBuf::new();
+    for _ in common..base_components.len() {
+        result.push("..");
+    }
+    for component in &path_components[common..] {
+        result.push(component);
+    }
+    if result.as_os_str().is_empty() {
+        result.push(".");
+    }
+    result
+}
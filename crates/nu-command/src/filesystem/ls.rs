@@ -111,6 +111,32 @@ impl Command for Ls {
         };
 
         let pattern_arg = call.rest::<Spanned<NuGlob>>(engine_state, stack, 0)?;
+
+        // URL-style patterns route to an `ls-<scheme>` handler, mirroring how `open` and
+        // `save` dispatch URL-style paths
+        if let Some(url) = pattern_arg.first() {
+            let url_str = url.item.as_ref().to_string();
+            if let Some(scheme) = crate::filesystem::util::url_scheme(&url_str) {
+                if let Some(extra) = pattern_arg.get(1) {
+                    return Err(ShellError::IncompatibleParametersSingle {
+                        msg: format!("`{scheme}://` listings take a single URL"),
+                        span: extra.span,
+                    });
+                }
+                return crate::filesystem::util::eval_scheme_handler(
+                    engine_state,
+                    stack,
+                    &format!("ls-{scheme}"),
+                    "list",
+                    scheme,
+                    &url_str,
+                    call_span,
+                    url.span,
+                    PipelineData::empty(),
+                );
+            }
+        }
+
         let input_pattern_arg = if !call.has_positional_args(stack, 0) {
             None
         } else {
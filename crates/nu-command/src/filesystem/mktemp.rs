@@ -1,5 +1,6 @@
 #[allow(deprecated)]
-use nu_engine::{command_prelude::*, env::current_dir};
+use nu_engine::{command_prelude::*, env::current_dir, ClosureEvalOnce};
+use nu_protocol::engine::Closure;
 use std::path::PathBuf;
 
 #[derive(Clone)]
@@ -37,6 +38,12 @@ impl Command for Mktemp {
             .named("tmpdir-path", SyntaxShape::Filepath, "Interpret TEMPLATE relative to tmpdir-path. If tmpdir-path is not set use $TMPDIR", Some('p'))
             .switch("tmpdir", "Interpret TEMPLATE relative to the system temporary directory.", Some('t'))
             .switch("directory", "Create a directory instead of a file.", Some('d'))
+            .named(
+                "scope",
+                SyntaxShape::Closure(Some(vec![SyntaxShape::String])),
+                "Run the closure with the temporary path as its argument and remove the path afterwards, even if the closure errors.",
+                None,
+            )
             .category(Category::FileSystem)
     }
 
@@ -62,6 +69,11 @@ impl Command for Mktemp {
                 example: "mktemp -d",
                 result: Some(Value::test_string("/tmp/tmp.NMw9fJr8K0")),
             },
+            Example {
+                description: "Work in a temporary directory that is cleaned up afterwards, even on error.",
+                example: "mktemp -d --scope {|dir| cd $dir; make-artifacts }",
+                result: None,
+            },
         ]
     }
 
@@ -120,6 +132,31 @@ impl Command for Mktemp {
                 });
             }
         };
+        let scope: Option<Closure> = call.get_flag(engine_state, stack, "scope")?;
+        if let Some(closure) = scope {
+            // Run the closure with the temp path, then clean up no matter how it went
+            let result = ClosureEvalOnce::new(engine_state, stack, closure)
+                .add_arg(Value::string(&res, span))
+                .run_with_input(PipelineData::empty())
+                .and_then(|data| data.into_value(span));
+            let path = PathBuf::from(&res);
+            let cleanup = if path.is_dir() {
+                std::fs::remove_dir_all(&path)
+            } else {
+                std::fs::remove_file(&path)
+            };
+            // Surface the closure's error only after the cleanup ran
+            let value = result?;
+            cleanup.map_err(|err| {
+                ShellError::Io(nu_protocol::shell_error::io::IoError::new(
+                    err.kind(),
+                    span,
+                    path,
+                ))
+            })?;
+            return Ok(value.into_pipeline_data());
+        }
+
         Ok(PipelineData::Value(Value::string(res, span), None))
     }
 }
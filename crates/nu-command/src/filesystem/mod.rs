@@ -1,12 +1,16 @@
 mod cd;
 mod du;
 mod glob;
+mod link;
 mod ls;
 mod mktemp;
 mod open;
+mod pwd;
 mod rm;
 mod save;
 mod start;
+mod uchmod;
+mod uchown;
 mod ucp;
 mod umkdir;
 mod umv;
@@ -15,14 +19,18 @@ mod utouch;
 mod watch;
 
 pub use self::open::Open;
+pub use self::pwd::Pwd;
 pub use cd::Cd;
 pub use du::Du;
 pub use glob::Glob;
+pub use link::{Link, LinkCreate, LinkRead};
 pub use ls::Ls;
 pub use mktemp::Mktemp;
 pub use rm::Rm;
 pub use save::Save;
 pub use start::Start;
+pub use uchmod::UChmod;
+pub use uchown::UChown;
 pub use ucp::UCp;
 pub use umkdir::UMkdir;
 pub use umv::UMv;
@@ -1,7 +1,7 @@
 #[allow(deprecated)]
 use nu_engine::{command_prelude::*, current_dir, eval_call};
 use nu_protocol::{
-    ast,
+    ast::{self, Expression},
     debugger::{WithDebug, WithoutDebug},
     shell_error::{self, io::IoError},
     DataSource, NuGlob, PipelineMetadata,
@@ -13,6 +13,7 @@ use std::{
 
 #[cfg(feature = "sqlite")]
 use crate::database::SQLiteDatabase;
+use crate::filesystem::util::url_scheme;
 
 #[cfg(unix)]
 use std::os::unix::fs::PermissionsExt;
@@ -30,7 +31,13 @@ impl Command for Open {
     }
 
     fn extra_description(&self) -> &str {
-        "Support to automatically parse files with an extension `.xyz` can be provided by a `from xyz` command in scope."
+        "Support to automatically parse files with an extension `.xyz` can be provided by a `from xyz` command in scope.
+
+`http://` and `https://` URLs are fetched with `http get`. Other `scheme://` paths are routed
+to an `open-<scheme>` command in scope; `ls` and `save` route the same way through
+`ls-<scheme>` and `save-<scheme>`, so custom commands and plugins can register storage
+backends (`use std-rfc/cloud *` provides s3/gs handlers). `cd` has no scheme routing: the
+current directory always refers to the real filesystem."
     }
 
     fn search_terms(&self) -> Vec<&str> {
@@ -96,6 +103,64 @@ impl Command for Open {
             path.item = path.item.strip_ansi_string_unlikely();
 
             let arg_span = path.span;
+
+            // Delegate remote URLs to `http get`, so `open` works on them too. Other schemes
+            // (e.g. `s3://`) are routed to an `open-<scheme>` command if one is in scope, which
+            // lets custom commands and plugins register handlers for additional storage schemes.
+            let path_str: &str = path.item.as_ref();
+            if let Some(scheme) = url_scheme(path_str) {
+                let handler = if scheme == "http" || scheme == "https" {
+                    engine_state.find_decl(b"http get", &[])
+                } else {
+                    engine_state.find_decl(format!("open-{scheme}").as_bytes(), &[])
+                };
+                let Some(http_get) = handler else {
+                    return Err(ShellError::GenericError {
+                        error: format!("Cannot open a `{scheme}://` URL"),
+                        msg: "no handler for this scheme is available".into(),
+                        span: Some(arg_span),
+                        help: Some(format!(
+                            "`http` and `https` URLs need the `http get` command; other schemes \
+                            are handled by a command or plugin defining `open-{scheme}`"
+                        )),
+                        inner: vec![],
+                    });
+                };
+                let mut arguments = vec![ast::Argument::Positional(Expression::new_unknown(
+                    ast::Expr::String(path_str.to_string()),
+                    arg_span,
+                    Type::String,
+                ))];
+                if raw {
+                    arguments.push(ast::Argument::Named((
+                        Spanned {
+                            item: "raw".into(),
+                            span: arg_span,
+                        },
+                        None,
+                        None,
+                    )));
+                }
+                let http_call = ast::Call {
+                    decl_id: http_get,
+                    head: call_span,
+                    arguments,
+                    parser_info: HashMap::new(),
+                };
+                let command_output = if engine_state.is_debugging() {
+                    eval_call::<WithDebug>(engine_state, stack, &http_call, PipelineData::empty())
+                } else {
+                    eval_call::<WithoutDebug>(
+                        engine_state,
+                        stack,
+                        &http_call,
+                        PipelineData::empty(),
+                    )
+                };
+                output.push(command_output?);
+                continue;
+            }
+
             // let path_no_whitespace = &path.item.trim_end_matches(|x| matches!(x, '\x09'..='\x0d'));
 
             for path in
@@ -254,6 +319,11 @@ impl Command for Open {
                 example: "open myfile.json",
                 result: None,
             },
+            Example {
+                description: "Open a remote URL (fetched with `http get`)",
+                example: "open https://api.github.com/repos/nushell/nushell",
+                result: None,
+            },
             Example {
                 description: "Open a file, as raw bytes",
                 example: "open myfile.json --raw",
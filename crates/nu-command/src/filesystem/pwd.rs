@@ -0,0 +1,61 @@
+use nu_engine::command_prelude::*;
+use nu_protocol::shell_error::io::IoError;
+
+#[derive(Clone)]
+pub struct Pwd;
+
+impl Command for Pwd {
+    fn name(&self) -> &str {
+        "pwd"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("pwd")
+            .input_output_types(vec![(Type::Nothing, Type::String)])
+            .switch("physical", "Resolve symbolic links", Some('P'))
+            .category(Category::FileSystem)
+    }
+
+    fn description(&self) -> &str {
+        "Return the current working directory."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["cwd", "current", "directory"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let physical = call.has_flag(engine_state, stack, "physical")?;
+        let cwd = engine_state.cwd(Some(stack))?;
+        let cwd = if physical {
+            cwd.canonicalize()
+                .map_err(|err| IoError::new(err.kind(), call.head, cwd.into_std_path_buf()))?
+                .to_string_lossy()
+                .into_owned()
+        } else {
+            cwd.to_string_lossy().into_owned()
+        };
+        Ok(Value::string(cwd, call.head).into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Return the current working directory",
+                example: "pwd",
+                result: None,
+            },
+            Example {
+                description: "Return the current working directory with symlinks resolved",
+                example: "pwd --physical",
+                result: None,
+            },
+        ]
+    }
+}
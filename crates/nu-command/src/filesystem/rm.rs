@@ -54,6 +54,11 @@ impl Command for Rm {
             .switch("recursive", "delete subdirectories recursively", Some('r'))
             .switch("force", "suppress error when no file", Some('f'))
             .switch("verbose", "print names of deleted files", Some('v'))
+            .switch(
+                "dry-run",
+                "report what would be deleted as a table, without deleting anything",
+                None,
+            )
             .switch("interactive", "ask user to confirm action", Some('i'))
             .switch(
                 "interactive-once",
@@ -119,7 +124,10 @@ fn rm(
     let recursive = call.has_flag(engine_state, stack, "recursive")?;
     let force = call.has_flag(engine_state, stack, "force")?;
     let verbose = call.has_flag(engine_state, stack, "verbose")?;
-    let interactive = call.has_flag(engine_state, stack, "interactive")?;
+    let dry_run = call.has_flag(engine_state, stack, "dry-run")?;
+    let interactive = !dry_run
+        && (call.has_flag(engine_state, stack, "interactive")?
+            || super::util::confirm_destructive_configured(engine_state, stack, "rm"));
     let interactive_once = call.has_flag(engine_state, stack, "interactive-once")? && !interactive;
 
     let mut paths = call.rest::<Spanned<NuGlob>>(engine_state, stack, 0)?;
@@ -331,6 +339,32 @@ fn rm(
         });
     }
 
+    if dry_run {
+        // Report what would be deleted, without touching anything
+        let head = call.head;
+        let mut targets: Vec<_> = all_targets.into_keys().collect();
+        targets.sort();
+        let rows = targets
+            .into_iter()
+            .map(|path| {
+                let kind = match path.symlink_metadata() {
+                    Ok(metadata) if metadata.file_type().is_symlink() => "symlink",
+                    Ok(metadata) if metadata.is_dir() => "directory",
+                    Ok(_) => "file",
+                    Err(_) => "unknown",
+                };
+                Value::record(
+                    record! {
+                        "path" => Value::string(path.to_string_lossy(), head),
+                        "type" => Value::string(kind, head),
+                    },
+                    head,
+                )
+            })
+            .collect();
+        return Ok(Value::list(rows, head).into_pipeline_data());
+    }
+
     if interactive_once {
         let (interaction, confirmed) = try_interaction(
             interactive_once,
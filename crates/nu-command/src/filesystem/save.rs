@@ -53,6 +53,17 @@ impl Command for Save {
             .switch("raw", "save file as raw binary", Some('r'))
             .switch("append", "append input to the end of the file", Some('a'))
             .switch("force", "overwrite the destination", Some('f'))
+            .switch(
+                "atomic",
+                "write to a temporary file and rename it into place once complete (keeps an existing destination's permissions, but not its ownership)",
+                None,
+            )
+            .named(
+                "backup",
+                SyntaxShape::String,
+                "keep the previous version of the file with this suffix (implies --atomic)",
+                None,
+            )
             .switch("progress", "enable progress bar", Some('p'))
             .category(Category::FileSystem)
     }
@@ -68,12 +79,45 @@ impl Command for Save {
         let append = call.has_flag(engine_state, stack, "append")?;
         let force = call.has_flag(engine_state, stack, "force")?;
         let progress = call.has_flag(engine_state, stack, "progress")?;
+        let backup: Option<String> = call.get_flag(engine_state, stack, "backup")?;
+        let atomic = call.has_flag(engine_state, stack, "atomic")? || backup.is_some();
 
         let span = call.head;
+
+        if atomic && append {
+            return Err(ShellError::IncompatibleParametersSingle {
+                msg: "--atomic can't be used together with --append".into(),
+                span,
+            });
+        }
         #[allow(deprecated)]
         let cwd = current_dir(engine_state, stack)?;
 
         let path_arg = call.req::<Spanned<PathBuf>>(engine_state, stack, 0)?;
+
+        // Remote URLs route to a `save-<scheme>` handler (or `http put`), mirroring how `open`
+        // dispatches URL-style paths
+        if let Some(path_str) = path_arg.item.to_str() {
+            if let Some(scheme) = crate::filesystem::util::url_scheme(path_str) {
+                let handler = if scheme == "http" || scheme == "https" {
+                    "http put".to_string()
+                } else {
+                    format!("save-{scheme}")
+                };
+                return crate::filesystem::util::eval_scheme_handler(
+                    engine_state,
+                    stack,
+                    &handler,
+                    "save to",
+                    scheme,
+                    path_str,
+                    span,
+                    path_arg.span,
+                    input,
+                );
+            }
+        }
+
         let path = Spanned {
             item: expand_path_with(path_arg.item, &cwd, true),
             span: path_arg.span,
@@ -91,7 +135,8 @@ impl Command for Save {
             PipelineData::ByteStream(stream, metadata) => {
                 check_saving_to_source_file(metadata.as_ref(), &path, stderr_path.as_ref())?;
 
-                let (file, stderr_file) = get_files(&path, stderr_path.as_ref(), append, force)?;
+                let (file, stderr_file, pending_atomic) =
+                    get_files(&path, stderr_path.as_ref(), append, force, atomic)?;
 
                 let size = stream.known_size();
                 let signals = engine_state.signals();
@@ -190,6 +235,8 @@ impl Command for Save {
                     }
                 }
 
+                finish_atomic_save(pending_atomic, &path, backup.as_deref())?;
+
                 Ok(PipelineData::Empty)
             }
             PipelineData::ListStream(ls, pipeline_metadata)
@@ -201,13 +248,15 @@ impl Command for Save {
                     stderr_path.as_ref(),
                 )?;
 
-                let (mut file, _) = get_files(&path, stderr_path.as_ref(), append, force)?;
+                let (mut file, _, pending_atomic) =
+                    get_files(&path, stderr_path.as_ref(), append, force, atomic)?;
                 for val in ls {
                     file.write_all(&value_to_bytes(val)?)
                         .map_err(&from_io_error)?;
                     file.write_all("\n".as_bytes()).map_err(&from_io_error)?;
                 }
                 file.flush().map_err(&from_io_error)?;
+                finish_atomic_save(pending_atomic, &path, backup.as_deref())?;
 
                 Ok(PipelineData::empty())
             }
@@ -226,10 +275,12 @@ impl Command for Save {
                     input_to_bytes(input, Path::new(&path.item), raw, engine_state, stack, span)?;
 
                 // Only open file after successful conversion
-                let (mut file, _) = get_files(&path, stderr_path.as_ref(), append, force)?;
+                let (mut file, _, pending_atomic) =
+                    get_files(&path, stderr_path.as_ref(), append, force, atomic)?;
 
                 file.write_all(&bytes).map_err(&from_io_error)?;
                 file.flush().map_err(&from_io_error)?;
+                finish_atomic_save(pending_atomic, &path, backup.as_deref())?;
 
                 Ok(PipelineData::empty())
             }
@@ -386,6 +437,29 @@ fn value_to_bytes(value: Value) -> Result<Vec<u8>, ShellError> {
     }
 }
 
+/// Complete an atomic save: optionally keep the previous version of the destination with the
+/// backup suffix, then rename the temporary file into place.
+fn finish_atomic_save(
+    pending_atomic: Option<tempfile::NamedTempFile>,
+    path: &Spanned<PathBuf>,
+    backup: Option<&str>,
+) -> Result<(), ShellError> {
+    let Some(temp) = pending_atomic else {
+        return Ok(());
+    };
+    if let Some(suffix) = backup {
+        if path.item.exists() {
+            let mut backup_name = path.item.clone().into_os_string();
+            backup_name.push(suffix);
+            std::fs::rename(&path.item, &backup_name)
+                .map_err(|err| IoError::new(err.kind(), path.span, path.item.clone()))?;
+        }
+    }
+    temp.persist(&path.item)
+        .map_err(|err| IoError::new(err.error.kind(), path.span, path.item.clone()))?;
+    Ok(())
+}
+
 /// Convert string path to [`Path`] and [`Span`] and check if this path
 /// can be used with given flags
 fn prepare_path(
@@ -444,7 +518,8 @@ fn get_files(
     stderr_path: Option<&Spanned<PathBuf>>,
     append: bool,
     force: bool,
-) -> Result<(File, Option<File>), ShellError> {
+    atomic: bool,
+) -> Result<(File, Option<File>, Option<tempfile::NamedTempFile>), ShellError> {
     // First check both paths
     let (path, path_span) = prepare_path(path, append, force)?;
     let stderr_path_and_span = stderr_path
@@ -452,8 +527,35 @@ fn get_files(
         .map(|stderr_path| prepare_path(stderr_path, append, force))
         .transpose()?;
 
+    // With `--atomic`, write to a temporary file next to the destination; it is renamed over
+    // the destination by `finish_atomic_save` once writing completed successfully
+    let pending_atomic = if atomic {
+        let parent = path.parent().unwrap_or_else(|| Path::new("."));
+        let temp = tempfile::Builder::new()
+            .prefix(".nu-save-")
+            .tempfile_in(parent)
+            .map_err(|err| IoError::new(err.kind(), path_span, PathBuf::from(path)))?;
+        // The temporary file is created with restrictive permissions; carry over an existing
+        // destination's permissions so the rename doesn't silently reset the file's mode.
+        // Ownership still ends up as the saving user's.
+        if let Ok(metadata) = std::fs::metadata(path) {
+            temp.as_file()
+                .set_permissions(metadata.permissions())
+                .map_err(|err| IoError::new(err.kind(), path_span, PathBuf::from(path)))?;
+        }
+        Some(temp)
+    } else {
+        None
+    };
+
     // Only if both files can be used open and possibly truncate them
-    let file = open_file(path, path_span, append)?;
+    let file = match &pending_atomic {
+        Some(temp) => temp
+            .as_file()
+            .try_clone()
+            .map_err(|err| IoError::new(err.kind(), path_span, PathBuf::from(path)))?,
+        None => open_file(path, path_span, append)?,
+    };
 
     let stderr_file = stderr_path_and_span
         .map(|(stderr_path, stderr_path_span)| {
@@ -471,7 +573,7 @@ fn get_files(
         })
         .transpose()?;
 
-    Ok((file, stderr_file))
+    Ok((file, stderr_file, pending_atomic))
 }
 
 fn stream_to_file(
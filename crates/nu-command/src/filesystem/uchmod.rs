@@ -0,0 +1,170 @@
+use nu_engine::command_prelude::*;
+use nu_protocol::{shell_error::io::IoError, NuGlob};
+use std::path::PathBuf;
+
+#[derive(Clone)]
+pub struct UChmod;
+
+impl Command for UChmod {
+    fn name(&self) -> &str {
+        "chmod"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("chmod")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .required(
+                "mode",
+                SyntaxShape::String,
+                "The mode to apply: octal (e.g. 644) or symbolic (e.g. u+x,go-w).",
+            )
+            .rest(
+                "paths",
+                SyntaxShape::OneOf(vec![SyntaxShape::GlobPattern, SyntaxShape::Filepath]),
+                "The files to change the mode of.",
+            )
+            .switch("recursive", "change modes recursively", Some('R'))
+            .switch("verbose", "print a line for every changed file", Some('v'))
+            .category(Category::FileSystem)
+    }
+
+    fn description(&self) -> &str {
+        "Change the permissions of files and directories."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Symbolic modes are applied relative to each file's current permissions, like chmod(1)."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["permissions", "mode", "attrib"]
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Make a script executable",
+                example: "chmod u+x script.sh",
+                result: None,
+            },
+            Example {
+                description: "Set an exact octal mode on every config file",
+                example: "chmod 600 *.conf",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        #[cfg(unix)]
+        {
+            run_chmod(engine_state, stack, call)
+        }
+        #[cfg(not(unix))]
+        {
+            let _ = (engine_state, stack);
+            Err(ShellError::DisabledOsSupport {
+                msg: "chmod is only supported on Unix".into(),
+                span: Some(call.head),
+            })
+        }
+    }
+}
+
+#[cfg(unix)]
+fn run_chmod(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<PipelineData, ShellError> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let head = call.head;
+    let mode_arg: Spanned<String> = call.req(engine_state, stack, 0)?;
+    let paths: Vec<Spanned<NuGlob>> = call.rest(engine_state, stack, 1)?;
+    let recursive = call.has_flag(engine_state, stack, "recursive")?;
+    let verbose = call.has_flag(engine_state, stack, "verbose")?;
+    let cwd = engine_state.cwd(Some(stack))?;
+
+    if paths.is_empty() {
+        return Err(ShellError::MissingParameter {
+            param_name: "paths".into(),
+            span: head,
+        });
+    }
+
+    let mut targets: Vec<PathBuf> = Vec::new();
+    for path in &paths {
+        for expanded in nu_engine::glob_from(
+            path,
+            cwd.as_ref(),
+            head,
+            None,
+            engine_state.signals().clone(),
+        )?
+        .1
+        {
+            targets.push(expanded?);
+        }
+    }
+    if recursive {
+        let mut stack_paths = targets.clone();
+        while let Some(dir) = stack_paths.pop() {
+            if dir.is_dir() && !dir.is_symlink() {
+                for entry in std::fs::read_dir(&dir)
+                    .map_err(|err| IoError::new(err.kind(), head, dir.clone()))?
+                {
+                    let entry = entry.map_err(|err| IoError::new(err.kind(), head, dir.clone()))?;
+                    stack_paths.push(entry.path());
+                    targets.push(entry.path());
+                }
+            }
+        }
+    }
+
+    let mut output = Vec::new();
+    for target in targets {
+        engine_state.signals().check(head)?;
+        let metadata = target
+            .metadata()
+            .map_err(|err| IoError::new(err.kind(), head, target.clone()))?;
+        let current = metadata.permissions().mode() & 0o7777;
+        let new_mode = parse_mode(&mode_arg.item, current, metadata.is_dir()).map_err(|msg| {
+            ShellError::IncorrectValue {
+                msg,
+                val_span: mode_arg.span,
+                call_span: head,
+            }
+        })?;
+        std::fs::set_permissions(&target, std::fs::Permissions::from_mode(new_mode))
+            .map_err(|err| IoError::new(err.kind(), head, target.clone()))?;
+        if verbose {
+            output.push(Value::string(
+                format!("mode of '{}' changed to {:04o}", target.display(), new_mode),
+                head,
+            ));
+        }
+    }
+
+    if verbose {
+        Ok(Value::list(output, head).into_pipeline_data())
+    } else {
+        Ok(PipelineData::empty())
+    }
+}
+
+/// Parse an octal or symbolic mode string against the current permissions.
+#[cfg(unix)]
+fn parse_mode(mode: &str, current: u32, is_dir: bool) -> Result<u32, String> {
+    if mode.chars().all(|c| c.is_ascii_digit()) {
+        uucore::mode::parse_numeric(current, mode, is_dir)
+    } else {
+        uucore::mode::parse_symbolic(current, mode, uucore::mode::get_umask(), is_dir)
+    }
+}
@@ -0,0 +1,162 @@
+use nu_engine::command_prelude::*;
+use nu_protocol::NuGlob;
+
+#[derive(Clone)]
+pub struct UChown;
+
+impl Command for UChown {
+    fn name(&self) -> &str {
+        "chown"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("chown")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .required(
+                "owner",
+                SyntaxShape::String,
+                "The new owner, as `user`, `user:group`, or `:group`.",
+            )
+            .rest(
+                "paths",
+                SyntaxShape::OneOf(vec![SyntaxShape::GlobPattern, SyntaxShape::Filepath]),
+                "The files to change the owner of.",
+            )
+            .switch("recursive", "change owners recursively", Some('R'))
+            .category(Category::FileSystem)
+    }
+
+    fn description(&self) -> &str {
+        "Change the owner and group of files and directories."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["owner", "group", "chgrp"]
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Give a file to another user",
+                example: "chown alice notes.txt",
+                result: None,
+            },
+            Example {
+                description: "Change owner and group recursively",
+                example: "chown -R alice:staff project/",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        #[cfg(unix)]
+        {
+            run_chown(engine_state, stack, call)
+        }
+        #[cfg(not(unix))]
+        {
+            let _ = (engine_state, stack);
+            Err(ShellError::DisabledOsSupport {
+                msg: "chown is only supported on Unix".into(),
+                span: Some(call.head),
+            })
+        }
+    }
+}
+
+#[cfg(unix)]
+fn run_chown(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<PipelineData, ShellError> {
+    use nix::unistd::{Group, User};
+    use nu_protocol::shell_error::io::IoError;
+    use std::path::PathBuf;
+
+    let head = call.head;
+    let owner: Spanned<String> = call.req(engine_state, stack, 0)?;
+    let paths: Vec<Spanned<NuGlob>> = call.rest(engine_state, stack, 1)?;
+    let recursive = call.has_flag(engine_state, stack, "recursive")?;
+    let cwd = engine_state.cwd(Some(stack))?;
+
+    if paths.is_empty() {
+        return Err(ShellError::MissingParameter {
+            param_name: "paths".into(),
+            span: head,
+        });
+    }
+
+    // Resolve `user`, `user:group`, or `:group` to ids
+    let (user_part, group_part) = match owner.item.split_once(':') {
+        Some((user, group)) => (user, Some(group)),
+        None => (owner.item.as_str(), None),
+    };
+    let incorrect_value = |msg: String| ShellError::IncorrectValue {
+        msg,
+        val_span: owner.span,
+        call_span: head,
+    };
+    let uid = if user_part.is_empty() {
+        None
+    } else {
+        Some(
+            User::from_name(user_part)
+                .map_err(|err| incorrect_value(err.to_string()))?
+                .ok_or_else(|| incorrect_value(format!("unknown user '{user_part}'")))?
+                .uid,
+        )
+    };
+    let gid = match group_part {
+        None | Some("") => None,
+        Some(group) => Some(
+            Group::from_name(group)
+                .map_err(|err| incorrect_value(err.to_string()))?
+                .ok_or_else(|| incorrect_value(format!("unknown group '{group}'")))?
+                .gid,
+        ),
+    };
+    let mut targets: Vec<PathBuf> = Vec::new();
+    for path in &paths {
+        for expanded in nu_engine::glob_from(
+            path,
+            cwd.as_ref(),
+            head,
+            None,
+            engine_state.signals().clone(),
+        )?
+        .1
+        {
+            targets.push(expanded?);
+        }
+    }
+    if recursive {
+        let mut stack_paths = targets.clone();
+        while let Some(dir) = stack_paths.pop() {
+            if dir.is_dir() && !dir.is_symlink() {
+                for entry in std::fs::read_dir(&dir)
+                    .map_err(|err| IoError::new(err.kind(), head, dir.clone()))?
+                {
+                    let entry = entry.map_err(|err| IoError::new(err.kind(), head, dir.clone()))?;
+                    stack_paths.push(entry.path());
+                    targets.push(entry.path());
+                }
+            }
+        }
+    }
+
+    for target in targets {
+        engine_state.signals().check(head)?;
+        nix::unistd::chown(&target, uid, gid)
+            .map_err(|err| IoError::new(std::io::Error::from(err).kind(), head, target.clone()))?;
+    }
+
+    Ok(PipelineData::empty())
+}
@@ -41,6 +41,11 @@ impl Command for UCp {
                 Some('f'),
             )
             .switch("interactive", "ask before overwriting files", Some('i'))
+            .switch(
+                "dry-run",
+                "report what would be copied as a table, without copying anything",
+                None,
+            )
             .switch(
                 "update",
                 "copy only when the SOURCE file is newer than the destination file or when the destination file is missing",
@@ -114,7 +119,8 @@ impl Command for UCp {
         call: &Call,
         _input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
-        let interactive = call.has_flag(engine_state, stack, "interactive")?;
+        let interactive = call.has_flag(engine_state, stack, "interactive")?
+            || super::util::confirm_destructive_configured(engine_state, stack, "cp");
         let (update, copy_mode) = if call.has_flag(engine_state, stack, "update")? {
             (UpdateMode::ReplaceIfOlder, CopyMode::Update)
         } else {
@@ -237,6 +243,17 @@ impl Command for UCp {
         }
         let sources: Vec<PathBuf> = sources.into_iter().flat_map(|x| x.0).collect();
 
+        if call.has_flag(engine_state, stack, "dry-run")? {
+            let mut files = sources.clone();
+            files.push(target_path.clone());
+            return Ok(super::util::dry_run_plan(
+                &files,
+                &target_path,
+                "copy",
+                call.head,
+            ));
+        }
+
         let attributes = make_attributes(preserve)?;
 
         let options = uu_cp::Options {
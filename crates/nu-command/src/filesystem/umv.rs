@@ -58,6 +58,11 @@ impl Command for UMv {
             .switch("verbose", "explain what is being done.", Some('v'))
             .switch("progress", "display a progress bar", Some('p'))
             .switch("interactive", "prompt before overwriting", Some('i'))
+            .switch(
+                "dry-run",
+                "report what would be moved as a table, without moving anything",
+                None,
+            )
             .switch(
                 "update",
                 "move and overwrite only when the SOURCE file is newer than the destination file or when the destination file is missing",
@@ -80,7 +85,8 @@ impl Command for UMv {
         call: &Call,
         _input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
-        let interactive = call.has_flag(engine_state, stack, "interactive")?;
+        let interactive = call.has_flag(engine_state, stack, "interactive")?
+            || super::util::confirm_destructive_configured(engine_state, stack, "mv");
         let no_clobber = call.has_flag(engine_state, stack, "no-clobber")?;
         let progress = call.has_flag(engine_state, stack, "progress")?;
         let verbose = call.has_flag(engine_state, stack, "verbose")?;
@@ -174,6 +180,15 @@ impl Command for UMv {
             matches!(spanned_target.item, NuGlob::Expand(..)),
         );
         files.push(abs_target_path.clone());
+        if call.has_flag(engine_state, stack, "dry-run")? {
+            return Ok(super::util::dry_run_plan(
+                &files,
+                &abs_target_path,
+                "move",
+                call.head,
+            ));
+        }
+
         let files = files
             .into_iter()
             .map(|p| p.into_os_string())
@@ -12,6 +12,60 @@ pub struct Resource {
 
 impl Resource {}
 
+/// Build the table a filesystem command outputs in `--dry-run` mode: one row per planned
+/// operation. The last element of `files` is the target, matching the uutils argument layout.
+pub fn dry_run_plan(
+    files: &[std::path::PathBuf],
+    target: &std::path::Path,
+    operation: &str,
+    head: nu_protocol::Span,
+) -> nu_protocol::PipelineData {
+    use nu_protocol::{record, IntoPipelineData, Value};
+
+    let sources = &files[..files.len().saturating_sub(1)];
+    let target_is_dir = target.is_dir() || sources.len() > 1;
+    let rows = sources
+        .iter()
+        .map(|source| {
+            let destination = if target_is_dir {
+                source
+                    .file_name()
+                    .map(|name| target.join(name))
+                    .unwrap_or_else(|| target.to_path_buf())
+            } else {
+                target.to_path_buf()
+            };
+            Value::record(
+                record! {
+                    "operation" => Value::string(operation, head),
+                    "from" => Value::string(source.to_string_lossy(), head),
+                    "to" => Value::string(destination.to_string_lossy(), head),
+                    "exists" => Value::bool(destination.exists(), head),
+                },
+                head,
+            )
+        })
+        .collect();
+    Value::list(rows, head).into_pipeline_data()
+}
+
+/// True if `$env.config.always_confirm_destructive` lists the given command, meaning it should
+/// behave as if `--interactive` had been passed.
+///
+/// Only applies to interactive sessions; scripts would hang on a prompt with no tty behind it.
+pub fn confirm_destructive_configured(
+    engine_state: &nu_protocol::engine::EngineState,
+    stack: &mut nu_protocol::engine::Stack,
+    command: &str,
+) -> bool {
+    engine_state.is_interactive
+        && stack
+            .get_config(engine_state)
+            .always_confirm_destructive
+            .iter()
+            .any(|name| name == command)
+}
+
 pub fn try_interaction(
     interactive: bool,
     prompt: String,
@@ -87,3 +141,70 @@ pub fn is_older(src: &Path, dst: &Path) -> Option<bool> {
         Some(src_ctime <= dst_ctime)
     }
 }
+
+/// The scheme of a URL-style path (`scheme://...`), if it has one.
+///
+/// Single-character schemes are excluded so Windows drive paths like `C://` keep going through
+/// the filesystem.
+pub(crate) fn url_scheme(path: &str) -> Option<&str> {
+    let (scheme, _) = path.split_once("://")?;
+    (scheme.len() > 1
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.')))
+    .then_some(scheme)
+}
+
+/// Evaluate the handler command for a URL-style path (usually `<verb>-<scheme>`, e.g.
+/// `save-s3`) with the URL as its only positional argument and `input` piped in. This is how
+/// `open`, `ls`, and `save` route schemes they don't handle natively: a custom command or
+/// plugin in scope named after the verb and scheme takes over.
+///
+/// Returns an error naming the missing handler if none is in scope.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn eval_scheme_handler(
+    engine_state: &nu_protocol::engine::EngineState,
+    stack: &mut nu_protocol::engine::Stack,
+    handler: &str,
+    verb: &str,
+    scheme: &str,
+    url: &str,
+    head: nu_protocol::Span,
+    url_span: nu_protocol::Span,
+    input: nu_protocol::PipelineData,
+) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
+    use nu_engine::eval_call;
+    use nu_protocol::{
+        ast,
+        debugger::{WithDebug, WithoutDebug},
+        ShellError, Type,
+    };
+
+    let Some(decl_id) = engine_state.find_decl(handler.as_bytes(), &[]) else {
+        return Err(ShellError::GenericError {
+            error: format!("Cannot {verb} a `{scheme}://` URL"),
+            msg: "no handler for this scheme is available".into(),
+            span: Some(url_span),
+            help: Some(format!(
+                "this scheme is handled by a command or plugin defining `{handler}` \
+                (see `use std-rfc/cloud *` for s3/gs handlers)"
+            )),
+            inner: vec![],
+        });
+    };
+    let handler_call = ast::Call {
+        decl_id,
+        head,
+        arguments: vec![ast::Argument::Positional(ast::Expression::new_unknown(
+            ast::Expr::String(url.to_string()),
+            url_span,
+            Type::String,
+        ))],
+        parser_info: std::collections::HashMap::new(),
+    };
+    if engine_state.is_debugging() {
+        eval_call::<WithDebug>(engine_state, stack, &handler_call, input)
+    } else {
+        eval_call::<WithoutDebug>(engine_state, stack, &handler_call, input)
+    }
+}
@@ -86,6 +86,26 @@ impl Command for Default {
                     }),
                 ])),
             },
+            Example {
+                description: "Fill in missing or null columns from a record of defaults, including nested ones",
+                example: "[{a: 1} {a: null, b: {c: 2}}] | default {a: 0, b: {c: 0, d: 9}}",
+                result: Some(Value::test_list(vec![
+                    Value::test_record(record! {
+                        "a" => Value::test_int(1),
+                        "b" => Value::test_record(record! {
+                            "c" => Value::test_int(0),
+                            "d" => Value::test_int(9),
+                        }),
+                    }),
+                    Value::test_record(record! {
+                        "a" => Value::test_int(0),
+                        "b" => Value::test_record(record! {
+                            "c" => Value::test_int(2),
+                            "d" => Value::test_int(9),
+                        }),
+                    }),
+                ])),
+            },
             Example {
                 description: r#"Replace the empty string in the "a" column of a list"#,
                 example: "[{a:1 b:2} {a:'' b:1}] | default -e 'N/A' a",
@@ -146,11 +166,64 @@ fn default(
             && matches!(input, PipelineData::Value(ref value, _) if value.is_empty()))
     {
         Ok(value.into_pipeline_data())
+    } else if matches!(value, Value::Record { .. })
+        && matches!(
+            input,
+            PipelineData::Value(Value::List { .. }, ..) | PipelineData::ListStream(..)
+        )
+    {
+        // A record of defaults is applied column-wise to every row of table input, recursing
+        // into nested records. Non-table input keeps the plain replace-if-null semantics, so
+        // `{} | default {a: 5}` is still `{}`.
+        let Value::Record { val: defaults, .. } = value else {
+            unreachable!("just matched a record");
+        };
+        let defaults = defaults.into_owned();
+        input.map(
+            move |mut item: Value| {
+                if let Value::Record {
+                    val: ref mut record,
+                    ..
+                } = item
+                {
+                    apply_record_defaults(record.to_mut(), &defaults, default_when_empty);
+                }
+                item
+            },
+            engine_state.signals(),
+        )
     } else {
         Ok(input)
     }
 }
 
+/// Fill in missing, null, and (optionally) empty fields from a record of defaults, recursing
+/// into nested records.
+fn apply_record_defaults(record: &mut Record, defaults: &Record, default_when_empty: bool) {
+    for (column, default) in defaults.iter() {
+        match record.get_mut(column) {
+            None => record.push(column.clone(), default.clone()),
+            Some(val) => {
+                if matches!(val, Value::Nothing { .. }) || (default_when_empty && val.is_empty()) {
+                    *val = default.clone();
+                } else if let (
+                    Value::Record {
+                        val: ref mut nested,
+                        ..
+                    },
+                    Value::Record {
+                        val: nested_defaults,
+                        ..
+                    },
+                ) = (val, default)
+                {
+                    apply_record_defaults(nested.to_mut(), nested_defaults, default_when_empty);
+                }
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -25,6 +25,12 @@ impl Command for Flatten {
                 "Optionally flatten data by column.",
             )
             .switch("all", "flatten inner table one level out", Some('a'))
+            .named(
+                "separator",
+                SyntaxShape::String,
+                "Fully flatten nested records into one level, joining the key segments with this separator (e.g. '.')",
+                None,
+            )
             .category(Category::Filters)
     }
 
@@ -39,6 +45,41 @@ impl Command for Flatten {
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
+        if let Some(separator) = call.get_flag::<String>(engine_state, stack, "separator")? {
+            let head = call.head;
+            let metadata = input.metadata();
+            return match input.into_value(head)? {
+                Value::Record { val, .. } => {
+                    let mut flat = Record::new();
+                    flatten_record_deep(&val, &separator, &mut String::new(), &mut flat, head);
+                    Ok(Value::record(flat, head).into_pipeline_data_with_metadata(metadata))
+                }
+                Value::List { vals, .. } => {
+                    let rows = vals
+                        .into_iter()
+                        .map(|row| {
+                            let span = row.span();
+                            match row {
+                                Value::Record { val, .. } => {
+                                    let mut flat = Record::new();
+                                    flatten_record_deep(
+                                        &val,
+                                        &separator,
+                                        &mut String::new(),
+                                        &mut flat,
+                                        span,
+                                    );
+                                    Value::record(flat, span)
+                                }
+                                other => other,
+                            }
+                        })
+                        .collect();
+                    Ok(Value::list(rows, head).into_pipeline_data_with_metadata(metadata))
+                }
+                other => Ok(other.into_pipeline_data_with_metadata(metadata)),
+            };
+        }
         flatten(engine_state, stack, call, input)
     }
 
@@ -308,6 +349,33 @@ fn flat_value(columns: &[CellPath], item: Value, all: bool) -> Vec<Value> {
     }
 }
 
+/// Recursively flatten nested records into dotted keys, e.g. `{a: {b: 1}}` -> `{"a.b": 1}`.
+fn flatten_record_deep(
+    record: &Record,
+    separator: &str,
+    prefix: &mut String,
+    output: &mut Record,
+    head: Span,
+) {
+    let _ = head;
+    for (key, value) in record.iter() {
+        let previous_len = prefix.len();
+        if !prefix.is_empty() {
+            prefix.push_str(separator);
+        }
+        prefix.push_str(key);
+        match value {
+            Value::Record { val, .. } => {
+                flatten_record_deep(val, separator, prefix, output, head);
+            }
+            other => {
+                output.push(prefix.clone(), other.clone());
+            }
+        }
+        prefix.truncate(previous_len);
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
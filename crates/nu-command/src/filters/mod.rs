@@ -45,6 +45,7 @@ mod sort_by;
 mod take;
 mod tee;
 mod transpose;
+mod unflatten;
 mod uniq;
 mod uniq_by;
 mod update;
@@ -104,6 +105,7 @@ pub use sort_by::SortBy;
 pub use take::*;
 pub use tee::Tee;
 pub use transpose::Transpose;
+pub use unflatten::Unflatten;
 pub use uniq::*;
 pub use uniq_by::UniqBy;
 pub use update::Update;
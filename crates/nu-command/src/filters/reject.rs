@@ -22,6 +22,16 @@ impl Command for Reject {
                 "ignore missing data (make all cell path members optional)",
                 Some('i'),
             )
+            .switch(
+                "regex",
+                "treat the given column names as regular expressions matched against the input's columns",
+                Some('r'),
+            )
+            .switch(
+                "ignore-case",
+                "with --regex, match column names case-insensitively",
+                None,
+            )
             .rest(
                 "rest",
                 SyntaxShape::CellPath,
@@ -89,6 +99,14 @@ impl Command for Reject {
         }
         let span = call.head;
 
+        let (new_columns, input) = if call.has_flag(engine_state, stack, "regex")? {
+            let ignore_case = call.has_flag(engine_state, stack, "ignore-case")?;
+            super::select::expand_column_patterns(new_columns, input, ignore_case, span)?
+        } else {
+            (new_columns, input)
+        };
+        let mut new_columns = new_columns;
+
         let ignore_errors = call.has_flag(engine_state, stack, "ignore-errors")?;
         if ignore_errors {
             for cell_path in &mut new_columns {
@@ -23,6 +23,16 @@ impl Command for Select {
                 "ignore missing data (make all cell path members optional)",
                 Some('i'),
             )
+            .switch(
+                "regex",
+                "treat the given column names as regular expressions matched against the input's columns",
+                Some('r'),
+            )
+            .switch(
+                "ignore-case",
+                "with --regex, match column names case-insensitively",
+                None,
+            )
             .rest(
                 "rest",
                 SyntaxShape::CellPath,
@@ -102,6 +112,24 @@ produce a table, a list will produce a list, and a record will produce a record.
         let ignore_errors = call.has_flag(engine_state, stack, "ignore-errors")?;
         let span = call.head;
 
+        let (new_columns, input) = if call.has_flag(engine_state, stack, "regex")? {
+            let ignore_case = call.has_flag(engine_state, stack, "ignore-case")?;
+            let (expanded, input) = expand_column_patterns(new_columns, input, ignore_case, span)?;
+            if expanded.is_empty() {
+                // Bare `select` returns its input unchanged; an explicit pattern that matches
+                // nothing almost certainly means a typo, so don't silently return everything
+                return Err(ShellError::IncorrectValue {
+                    msg: "no columns match the given patterns".into(),
+                    val_span: span,
+                    call_span: span,
+                });
+            }
+            (expanded, input)
+        } else {
+            (new_columns, input)
+        };
+        let mut new_columns = new_columns;
+
         if ignore_errors {
             for cell_path in &mut new_columns {
                 cell_path.make_optional();
@@ -171,6 +199,89 @@ produce a table, a list will produce a list, and a record will produce a record.
     }
 }
 
+/// Expand regex column patterns against the input's columns, in the input's column order.
+///
+/// The input has to be collected to learn its columns, so this is meant for the interactive
+/// wide-table case, not for streaming pipelines.
+pub(crate) fn expand_column_patterns(
+    patterns: Vec<CellPath>,
+    input: PipelineData,
+    ignore_case: bool,
+    span: Span,
+) -> Result<(Vec<CellPath>, PipelineData), ShellError> {
+    let metadata = input.metadata();
+    let value = input.into_value(span)?;
+    let columns: Vec<String> = match &value {
+        Value::Record { val, .. } => val.columns().cloned().collect(),
+        Value::List { vals, .. } => {
+            let mut columns = Vec::new();
+            for row in vals {
+                if let Value::Record { val, .. } = row {
+                    for column in val.columns() {
+                        if !columns.contains(column) {
+                            columns.push(column.clone());
+                        }
+                    }
+                }
+            }
+            columns
+        }
+        other => {
+            return Err(ShellError::OnlySupportsThisInputType {
+                exp_input_type: "record or table".into(),
+                wrong_type: other.get_type().to_string(),
+                dst_span: span,
+                src_span: other.span(),
+            });
+        }
+    };
+
+    let mut expanded = Vec::new();
+    for pattern in patterns {
+        let [PathMember::String {
+            val: pattern,
+            span: pattern_span,
+            ..
+        }] = &pattern.members[..]
+        else {
+            return Err(ShellError::IncorrectValue {
+                msg: "only plain column patterns can be used with --regex".into(),
+                val_span: pattern.members.first().map(|m| m.span()).unwrap_or(span),
+                call_span: span,
+            });
+        };
+        let source = if ignore_case {
+            format!("(?i){pattern}")
+        } else {
+            pattern.clone()
+        };
+        let regex = fancy_regex::Regex::new(&source).map_err(|err| ShellError::IncorrectValue {
+            msg: format!("invalid column pattern: {err}"),
+            val_span: *pattern_span,
+            call_span: span,
+        })?;
+        for column in &columns {
+            if regex.is_match(column).unwrap_or(false) {
+                let member = PathMember::String {
+                    val: column.clone(),
+                    span: *pattern_span,
+                    optional: false,
+                };
+                let already_there = expanded.iter().any(|path: &CellPath| {
+                    matches!(path.members.first(), Some(PathMember::String { val, .. }) if val == column)
+                });
+                if !already_there {
+                    expanded.push(CellPath {
+                        members: vec![member],
+                    });
+                }
+            }
+        }
+    }
+
+    Ok((expanded, PipelineData::Value(value, metadata)))
+}
+
 fn select(
     engine_state: &EngineState,
     call_span: Span,
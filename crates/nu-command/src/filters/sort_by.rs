@@ -135,7 +135,7 @@ impl Command for SortBy {
         let natural = call.has_flag(engine_state, stack, "natural")?;
         let custom = call.has_flag(engine_state, stack, "custom")?;
         let metadata = input.metadata();
-        let mut vec: Vec<_> = input.into_iter_strict(head)?.collect();
+        let iter = input.into_iter_strict(head)?;
 
         if comparator_vals.is_empty() {
             return Err(ShellError::MissingParameter {
@@ -164,6 +164,24 @@ impl Command for SortBy {
             })
             .collect::<Result<_, _>>()?;
 
+        // With a sort_spill_threshold configured, oversized inputs get sorted through
+        // temporary run files instead of entirely in memory
+        let spill_threshold = stack.get_config(engine_state).sort_spill_threshold;
+        if spill_threshold > 0 {
+            let data = crate::sort_by_spilling(
+                iter,
+                comparators,
+                head,
+                insensitive,
+                natural,
+                reverse,
+                spill_threshold as usize,
+                engine_state.signals().clone(),
+            )?;
+            return Ok(data.set_metadata(metadata));
+        }
+
+        let mut vec: Vec<_> = iter.collect();
         crate::sort_by(&mut vec, comparators, head, insensitive, natural)?;
 
         if reverse {
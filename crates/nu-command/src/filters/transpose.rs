@@ -10,6 +10,7 @@ pub struct TransposeArgs {
     as_record: bool,
     keep_last: bool,
     keep_all: bool,
+    deduplicate: bool,
 }
 
 impl Command for Transpose {
@@ -48,6 +49,11 @@ impl Command for Transpose {
                 "on repetition of record fields due to `header-row`, keep all the values obtained",
                 Some('a'),
             )
+            .switch(
+                "deduplicate",
+                "on repetition of record fields due to `header-row`, rename them with a numeric suffix",
+                Some('u'),
+            )
             .allow_variants_without_examples(true)
             .rest(
                 "rest",
@@ -143,6 +149,7 @@ pub fn transpose(
         as_record: call.has_flag(engine_state, stack, "as-record")?,
         keep_last: call.has_flag(engine_state, stack, "keep-last")?,
         keep_all: call.has_flag(engine_state, stack, "keep-all")?,
+        deduplicate: call.has_flag(engine_state, stack, "deduplicate")?,
         rest: call.rest(engine_state, stack, 0)?,
     };
 
@@ -164,6 +171,18 @@ pub fn transpose(
             span: call.get_flag_span(stack, "keep-last").expect("has flag"),
         });
     }
+    if !args.header_row && args.deduplicate {
+        return Err(ShellError::IncompatibleParametersSingle {
+            msg: "Can only be used with `--header-row`(`-r`)".into(),
+            span: call.get_flag_span(stack, "deduplicate").expect("has flag"),
+        });
+    }
+    if args.deduplicate && (args.keep_all || args.keep_last) {
+        return Err(ShellError::IncompatibleParametersSingle {
+            msg: "Can't use `--deduplicate` together with `--keep-all` or `--keep-last`".into(),
+            span: call.get_flag_span(stack, "deduplicate").expect("has flag"),
+        });
+    }
     if args.keep_all && args.keep_last {
         return Err(ShellError::IncompatibleParameters {
             left_message: "can't use `--keep-last` at the same time".into(),
@@ -266,6 +285,18 @@ pub fn transpose(
                 let x = i
                     .get_data_by_key(&desc)
                     .unwrap_or_else(|| Value::nothing(name));
+                if args.deduplicate && record.get(&headers[column_num]).is_some() {
+                    // Rename repeated fields with a numeric suffix instead of merging them
+                    let mut suffix = 1;
+                    let mut new_name = format!("{}_{}", headers[column_num], suffix);
+                    while record.get(&new_name).is_some() {
+                        suffix += 1;
+                        new_name = format!("{}_{}", headers[column_num], suffix);
+                    }
+                    record.push(new_name, x);
+                    column_num += 1;
+                    continue;
+                }
                 match record.get_mut(&headers[column_num]) {
                     None => {
                         record.push(headers[column_num].clone(), x);
@@ -0,0 +1,130 @@
+use nu_engine::command_prelude::*;
+
+#[derive(Clone)]
+pub struct Unflatten;
+
+impl Command for Unflatten {
+    fn name(&self) -> &str {
+        "unflatten"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("unflatten")
+            .input_output_types(vec![
+                (Type::record(), Type::record()),
+                (Type::table(), Type::table()),
+            ])
+            .allow_variants_without_examples(true)
+            .named(
+                "separator",
+                SyntaxShape::String,
+                "The separator the keys are joined with (default '.')",
+                Some('s'),
+            )
+            .category(Category::Filters)
+    }
+
+    fn description(&self) -> &str {
+        "Rebuild nested records from keys flattened with a separator."
+    }
+
+    fn extra_description(&self) -> &str {
+        "The inverse of `flatten --separator`, so structure survives a round trip through
+flat formats like CSV, INI, or environment variables."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["nest", "expand"]
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Rebuild a nested record from dotted keys",
+            example: r#"{"server.host": "example.com", "server.port": 80} | unflatten"#,
+            result: Some(Value::test_record(record! {
+                "server" => Value::test_record(record! {
+                    "host" => Value::test_string("example.com"),
+                    "port" => Value::test_int(80),
+                }),
+            })),
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let separator = call
+            .get_flag::<String>(engine_state, stack, "separator")?
+            .unwrap_or_else(|| ".".into());
+        let metadata = input.metadata();
+        match input.into_value(head)? {
+            Value::Record { val, .. } => {
+                Ok(unflatten_record(&val, &separator, head)
+                    .into_pipeline_data_with_metadata(metadata))
+            }
+            Value::List { vals, .. } => {
+                let rows = vals
+                    .into_iter()
+                    .map(|row| {
+                        let span = row.span();
+                        match row {
+                            Value::Record { val, .. } => unflatten_record(&val, &separator, span),
+                            other => other,
+                        }
+                    })
+                    .collect();
+                Ok(Value::list(rows, head).into_pipeline_data_with_metadata(metadata))
+            }
+            other => Err(ShellError::OnlySupportsThisInputType {
+                exp_input_type: "record or table".into(),
+                wrong_type: other.get_type().to_string(),
+                dst_span: head,
+                src_span: other.span(),
+            }),
+        }
+    }
+}
+
+fn unflatten_record(record: &Record, separator: &str, head: Span) -> Value {
+    let mut output = Record::new();
+    for (key, value) in record.iter() {
+        let segments: Vec<&str> = key.split(separator).collect();
+        insert_nested(&mut output, &segments, value.clone(), head);
+    }
+    Value::record(output, head)
+}
+
+fn insert_nested(record: &mut Record, segments: &[&str], value: Value, head: Span) {
+    match segments {
+        [] => (),
+        [leaf] => {
+            record.insert(leaf.to_string(), value);
+        }
+        [first, rest @ ..] => {
+            // Reuse an existing nested record if the sibling keys built one already
+            if !matches!(record.get(*first), Some(Value::Record { .. })) {
+                record.insert(first.to_string(), Value::record(Record::new(), head));
+            }
+            if let Some(Value::Record { val, .. }) = record.get_mut(*first) {
+                insert_nested(val.to_mut(), rest, value, head);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Unflatten {})
+    }
+}
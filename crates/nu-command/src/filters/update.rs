@@ -1,5 +1,5 @@
 use nu_engine::{command_prelude::*, ClosureEval, ClosureEvalOnce};
-use nu_protocol::ast::PathMember;
+use nu_protocol::{ast::PathMember, FromValue};
 
 #[derive(Clone)]
 pub struct Update;
@@ -21,10 +21,10 @@ impl Command for Update {
             ])
             .required(
                 "field",
-                SyntaxShape::CellPath,
-                "The name of the column to update.",
+                SyntaxShape::OneOf(vec![SyntaxShape::CellPath, SyntaxShape::Record(vec![])]),
+                "The name of the column to update, or a record of columns to new values/closures.",
             )
-            .required(
+            .optional(
                 "replacement value",
                 SyntaxShape::Any,
                 "The new value to give the cell(s), or a closure to create the value.",
@@ -41,7 +41,10 @@ impl Command for Update {
         "When updating a column, the closure will be run for each row, and the current row will be passed as the first argument. \
 Referencing `$in` inside the closure will provide the value at the column for the current row.
 
-When updating a specific index, the closure will instead be run once. The first argument to the closure and the `$in` value will both be the current value at the index."
+When updating a specific index, the closure will instead be run once. The first argument to the closure and the `$in` value will both be the current value at the index.
+
+A record of columns to values or closures updates all of the columns in a single pass over
+the data, instead of chaining one `update` per column."
     }
 
     fn run(
@@ -109,8 +112,26 @@ fn update(
     input: PipelineData,
 ) -> Result<PipelineData, ShellError> {
     let head = call.head;
-    let cell_path: CellPath = call.req(engine_state, stack, 0)?;
-    let replacement: Value = call.req(engine_state, stack, 1)?;
+    let target: Value = call.req(engine_state, stack, 0)?;
+    let replacement: Option<Value> = call.opt(engine_state, stack, 1)?;
+
+    if let Value::Record { val: updates, .. } = target {
+        if let Some(replacement) = replacement {
+            return Err(ShellError::IncompatibleParametersSingle {
+                msg: "a replacement value can't be combined with a record of updates".into(),
+                span: replacement.span(),
+            });
+        }
+        return update_batch(engine_state, stack, head, updates.into_owned(), input);
+    }
+
+    let cell_path = CellPath::from_value(target)?;
+    let Some(replacement) = replacement else {
+        return Err(ShellError::MissingParameter {
+            param_name: "replacement value".into(),
+            span: head,
+        });
+    };
 
     match input {
         PipelineData::Value(mut value, metadata) => {
@@ -236,6 +257,65 @@ fn update(
     }
 }
 
+/// Apply a record of column -> value-or-closure updates in one pass over the input.
+fn update_batch(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    head: Span,
+    updates: Record,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    enum Op {
+        Closure(ClosureEval),
+        Value(Value),
+    }
+    let mut ops: Vec<(Vec<PathMember>, Op)> = updates
+        .into_iter()
+        .map(|(column, spec)| {
+            let members = vec![PathMember::String {
+                val: column,
+                span: head,
+                optional: false,
+            }];
+            let op = match spec {
+                Value::Closure { val, .. } => {
+                    Op::Closure(ClosureEval::new(engine_state, stack, *val))
+                }
+                other => Op::Value(other),
+            };
+            (members, op)
+        })
+        .collect();
+
+    let mut apply = move |mut row: Value| -> Result<Value, ShellError> {
+        for (members, op) in &mut ops {
+            match op {
+                Op::Closure(closure) => {
+                    update_value_by_closure(&mut row, closure, head, members, false)?;
+                }
+                Op::Value(new_value) => {
+                    row.update_data_at_cell_path(members, new_value.clone())?;
+                }
+            }
+        }
+        Ok(row)
+    };
+
+    match input {
+        PipelineData::Value(value @ Value::Record { .. }, metadata) => {
+            Ok(apply(value)?.into_pipeline_data_with_metadata(metadata))
+        }
+        input => {
+            let metadata = input.metadata();
+            let signals = engine_state.signals().clone();
+            Ok(input
+                .into_iter_strict(head)?
+                .map(move |row| apply(row).unwrap_or_else(|err| Value::error(err, head)))
+                .into_pipeline_data_with_metadata(head, signals, metadata))
+        }
+    }
+}
+
 fn update_value_by_closure(
     value: &mut Value,
     closure: &mut ClosureEval,
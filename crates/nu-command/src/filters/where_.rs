@@ -1,5 +1,8 @@
-use nu_engine::{command_prelude::*, ClosureEval};
-use nu_protocol::engine::{Closure, CommandType};
+use nu_engine::{command_prelude::*, ClosureEval, ClosureEvalOnce};
+use nu_protocol::{
+    ast::{Expr, Expression},
+    engine::{Closure, CommandType},
+};
 
 #[derive(Clone)]
 pub struct Where;
@@ -56,6 +59,22 @@ not supported."#
         let head = call.head;
         let closure: Closure = call.req(engine_state, stack, 0)?;
 
+        // `where 10..20` filters by row number instead of evaluating a condition per row
+        if let Some(range_expr) = row_range_expression(engine_state, &closure) {
+            let range_span = range_expr.span;
+            let range = ClosureEvalOnce::new(engine_state, stack, closure)
+                .run_with_input(PipelineData::empty())?
+                .into_value(range_span)?;
+            let metadata = input.metadata();
+            return Ok(input
+                .into_iter_strict(head)?
+                .enumerate()
+                .filter_map(move |(idx, value)| {
+                    range.contains_row_number(idx, range_span).then_some(value)
+                })
+                .into_pipeline_data_with_metadata(head, engine_state.signals().clone(), metadata));
+        }
+
         let mut closure = ClosureEval::new(engine_state, stack, closure);
 
         let metadata = input.metadata();
@@ -75,6 +94,14 @@ not supported."#
 
     fn examples(&self) -> Vec<Example> {
         vec![
+            Example {
+                description: "Keep rows 1 through 2 by row number",
+                example: "[{a: 1} {a: 2} {a: 3} {a: 4}] | where 1..2",
+                result: Some(Value::test_list(vec![
+                    Value::test_record(record! { "a" => Value::test_int(2) }),
+                    Value::test_record(record! { "a" => Value::test_int(3) }),
+                ])),
+            },
             Example {
                 description: "Filter rows of a table according to a condition",
                 example: "[{a: 1} {a: 2}] | where a > 1",
@@ -132,6 +159,34 @@ not supported."#
     }
 }
 
+/// If the row condition is a bare range literal (e.g. `where 10..20`), return its expression.
+fn row_range_expression<'a>(
+    engine_state: &'a EngineState,
+    closure: &Closure,
+) -> Option<&'a Expression> {
+    let block = engine_state.get_block(closure.block_id);
+    let [pipeline] = &block.pipelines[..] else {
+        return None;
+    };
+    let [element] = &pipeline.elements[..] else {
+        return None;
+    };
+    matches!(element.expr.expr, Expr::Range(..)).then_some(&element.expr)
+}
+
+trait ContainsRowNumber {
+    fn contains_row_number(&self, idx: usize, span: Span) -> bool;
+}
+
+impl ContainsRowNumber for Value {
+    fn contains_row_number(&self, idx: usize, span: Span) -> bool {
+        match self {
+            Value::Range { val, .. } => val.contains(&Value::int(idx as i64, span)),
+            _ => false,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -23,7 +23,7 @@ impl Command for Help {
             .named(
                 "find",
                 SyntaxShape::String,
-                "string to find in command names, descriptions, and search terms",
+                "string to find in command names, descriptions, examples, and search terms",
                 Some('f'),
             )
             .category(Category::Core)
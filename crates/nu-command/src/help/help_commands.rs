@@ -25,10 +25,15 @@ impl Command for HelpCommands {
             .named(
                 "find",
                 SyntaxShape::String,
-                "string to find in command names, descriptions, and search terms",
+                "string to find in command names, descriptions, examples, and search terms",
                 Some('f'),
             )
             .input_output_types(vec![(Type::Nothing, Type::table())])
+            .switch(
+                "examples",
+                "for a specific command, show only its examples as a table",
+                Some('e'),
+            )
             .allow_variants_without_examples(true)
     }
 
@@ -66,7 +71,13 @@ pub fn help_commands(
         let found_cmds_vec = highlight_search_in_table(
             all_cmds_vec,
             &f.item,
-            &["name", "description", "search_terms"],
+            &[
+                "name",
+                "description",
+                "extra_description",
+                "search_terms",
+                "examples",
+            ],
             &string_style,
             &highlight_style,
         )?;
@@ -87,6 +98,32 @@ pub fn help_commands(
             name.push_str(&r.item);
         }
 
+        if call.has_flag(engine_state, stack, "examples")? {
+            let Some(decl_id) = engine_state.find_decl(name.as_bytes(), &[]) else {
+                return Err(ShellError::CommandNotFound {
+                    span: Span::merge_many(rest.iter().map(|s| s.span)),
+                });
+            };
+            let decl = engine_state.get_decl(decl_id);
+            let examples = decl
+                .examples()
+                .into_iter()
+                .map(|example| {
+                    Value::record(
+                        record! {
+                            "description" => Value::string(example.description, head),
+                            "example" => Value::string(example.example, head),
+                            "result" => example
+                                .result
+                                .unwrap_or_else(|| Value::nothing(head)),
+                        },
+                        head,
+                    )
+                })
+                .collect();
+            return Ok(Value::list(examples, head).into_pipeline_data());
+        }
+
         if let Some(decl) = engine_state.find_decl(name.as_bytes(), &[]) {
             let cmd = engine_state.get_decl(decl);
             let help_text = get_full_help(cmd, engine_state, stack);
@@ -201,14 +238,23 @@ fn build_help_commands(engine_state: &EngineState, span: Span) -> Vec<Value> {
             Value::list(vals, span)
         };
 
+        let examples = decl
+            .examples()
+            .iter()
+            .flat_map(|example| [example.description, example.example])
+            .collect::<Vec<_>>()
+            .join("\n");
+
         let record = record! {
             "name" => Value::string(key, span),
             "category" => Value::string(sig.category.to_string(), span),
             "command_type" => Value::string(command_type, span),
             "description" => Value::string(description, span),
+            "extra_description" => Value::string(decl.extra_description(), span),
             "params" => param_table,
             "input_output" => input_output_table,
             "search_terms" => Value::string(search_terms.join(", "), span),
+            "examples" => Value::string(examples, span),
             "is_const" => Value::bool(decl.is_const(), span),
         };
 
@@ -27,6 +27,7 @@ mod progress_bar;
 mod random;
 mod removed;
 mod shells;
+mod sort_spill;
 mod sort_utils;
 #[cfg(feature = "sqlite")]
 mod stor;
@@ -62,6 +63,7 @@ pub use platform::*;
 pub use random::*;
 pub use removed::*;
 pub use shells::*;
+pub use sort_spill::*;
 pub use sort_utils::*;
 #[cfg(feature = "sqlite")]
 pub use stor::*;
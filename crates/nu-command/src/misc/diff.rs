@@ -0,0 +1,241 @@
+use nu_engine::command_prelude::*;
+
+#[derive(Clone)]
+pub struct Diff;
+
+impl Command for Diff {
+    fn name(&self) -> &str {
+        "diff"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("diff")
+            .input_output_types(vec![
+                (Type::Any, Type::table()),
+                (Type::String, Type::String),
+            ])
+            .allow_variants_without_examples(true)
+            .required("other", SyntaxShape::Any, "The value to compare against.")
+            .switch(
+                "text",
+                "Treat the values as text and produce a unified diff",
+                Some('t'),
+            )
+            .category(Category::Misc)
+    }
+
+    fn description(&self) -> &str {
+        "Compare the input value against another value, producing a structured patch."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Records, tables, and lists are compared structurally: the result is a table of
+added/removed/changed entries addressed by cell path, suitable for config-drift checks
+directly in pipelines. With `--text`, both values are treated as text and a unified
+diff is produced instead."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["compare", "patch", "drift", "delta"]
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Compare two records",
+                example: "{a: 1, b: 2} | diff {a: 1, b: 3, c: 4}",
+                result: Some(Value::test_list(vec![
+                    Value::test_record(record! {
+                        "op" => Value::test_string("change"),
+                        "path" => Value::test_string("b"),
+                        "old" => Value::test_int(2),
+                        "new" => Value::test_int(3),
+                    }),
+                    Value::test_record(record! {
+                        "op" => Value::test_string("add"),
+                        "path" => Value::test_string("c"),
+                        "old" => Value::test_nothing(),
+                        "new" => Value::test_int(4),
+                    }),
+                ])),
+            },
+            Example {
+                description: "Diff two files as text",
+                example: "open --raw before.toml | diff (open --raw after.toml) --text",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let other: Value = call.req(engine_state, stack, 0)?;
+        let text = call.has_flag(engine_state, stack, "text")?;
+        let value = input.into_value(head)?;
+
+        if text {
+            let old = value.coerce_into_string()?;
+            let new = other.coerce_into_string()?;
+            Ok(Value::string(unified_diff(&old, &new), head).into_pipeline_data())
+        } else {
+            let mut entries = Vec::new();
+            diff_values(&value, &other, &mut Vec::new(), &mut entries, head);
+            Ok(Value::list(entries, head).into_pipeline_data())
+        }
+    }
+}
+
+fn push_entry(
+    entries: &mut Vec<Value>,
+    op: &str,
+    path: &[String],
+    old: Option<&Value>,
+    new: Option<&Value>,
+    head: Span,
+) {
+    entries.push(Value::record(
+        record! {
+            "op" => Value::string(op, head),
+            "path" => Value::string(path.join("."), head),
+            "old" => old.cloned().unwrap_or_else(|| Value::nothing(head)),
+            "new" => new.cloned().unwrap_or_else(|| Value::nothing(head)),
+        },
+        head,
+    ));
+}
+
+/// Structurally compare two values, collecting add/remove/change entries addressed by cell path.
+fn diff_values(
+    old: &Value,
+    new: &Value,
+    path: &mut Vec<String>,
+    entries: &mut Vec<Value>,
+    head: Span,
+) {
+    match (old, new) {
+        (Value::Record { val: old_rec, .. }, Value::Record { val: new_rec, .. }) => {
+            for (key, old_val) in old_rec.iter() {
+                path.push(key.clone());
+                match new_rec.get(key) {
+                    Some(new_val) => diff_values(old_val, new_val, path, entries, head),
+                    None => push_entry(entries, "remove", path, Some(old_val), None, head),
+                }
+                path.pop();
+            }
+            for (key, new_val) in new_rec.iter() {
+                if old_rec.get(key).is_none() {
+                    path.push(key.clone());
+                    push_entry(entries, "add", path, None, Some(new_val), head);
+                    path.pop();
+                }
+            }
+        }
+        (Value::List { vals: old_vals, .. }, Value::List { vals: new_vals, .. }) => {
+            for (idx, (old_val, new_val)) in old_vals.iter().zip(new_vals).enumerate() {
+                path.push(idx.to_string());
+                diff_values(old_val, new_val, path, entries, head);
+                path.pop();
+            }
+            for (idx, old_val) in old_vals.iter().enumerate().skip(new_vals.len()) {
+                path.push(idx.to_string());
+                push_entry(entries, "remove", path, Some(old_val), None, head);
+                path.pop();
+            }
+            for (idx, new_val) in new_vals.iter().enumerate().skip(old_vals.len()) {
+                path.push(idx.to_string());
+                push_entry(entries, "add", path, None, Some(new_val), head);
+                path.pop();
+            }
+        }
+        (old, new) => {
+            if old != new {
+                push_entry(entries, "change", path, Some(old), Some(new), head);
+            }
+        }
+    }
+}
+
+/// A plain LCS-based line diff, good enough for config-sized inputs.
+fn unified_diff(old: &str, new: &str) -> String {
+    let mut old_lines: Vec<&str> = old.lines().collect();
+    let mut new_lines: Vec<&str> = new.lines().collect();
+
+    // The LCS table is O(n*m); trim the common prefix and suffix first so realistic inputs
+    // stay small, and bail out on pathological sizes rather than exhausting memory
+    let mut prefix = Vec::new();
+    while let (Some(old_line), Some(new_line)) = (old_lines.first(), new_lines.first()) {
+        if old_line != new_line {
+            break;
+        }
+        prefix.push(format!(" {old_line}\n"));
+        old_lines.remove(0);
+        new_lines.remove(0);
+    }
+    let mut suffix = Vec::new();
+    while let (Some(old_line), Some(new_line)) = (old_lines.last(), new_lines.last()) {
+        if old_line != new_line {
+            break;
+        }
+        suffix.push(format!(" {old_line}\n"));
+        old_lines.pop();
+        new_lines.pop();
+    }
+    const MAX_CELLS: usize = 25_000_000;
+    if old_lines.len().saturating_mul(new_lines.len()) > MAX_CELLS {
+        return format!(
+            "inputs differ (diff too large to render: {} vs {} differing lines)\n",
+            old_lines.len(),
+            new_lines.len()
+        );
+    }
+
+    // LCS lengths table
+    let mut lcs = vec![vec![0usize; new_lines.len() + 1]; old_lines.len() + 1];
+    for (i, old_line) in old_lines.iter().enumerate().rev() {
+        for (j, new_line) in new_lines.iter().enumerate().rev() {
+            lcs[i][j] = if old_line == new_line {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut output: String = prefix.concat();
+    let (mut i, mut j) = (0, 0);
+    while i < old_lines.len() || j < new_lines.len() {
+        if i < old_lines.len() && j < new_lines.len() && old_lines[i] == new_lines[j] {
+            output.push_str(&format!(" {}\n", old_lines[i]));
+            i += 1;
+            j += 1;
+        } else if j < new_lines.len() && (i == old_lines.len() || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            output.push_str(&format!("+{}\n", new_lines[j]));
+            j += 1;
+        } else {
+            output.push_str(&format!("-{}\n", old_lines[i]));
+            i += 1;
+        }
+    }
+    for line in suffix.into_iter().rev() {
+        output.push_str(&line);
+    }
+    output
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Diff {})
+    }
+}
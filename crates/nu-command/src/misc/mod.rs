@@ -1,7 +1,13 @@
+mod diff;
 mod panic;
+mod patch;
 mod source;
 mod tutor;
+mod validate;
 
+pub use diff::Diff;
 pub use panic::Panic;
+pub use patch::{Patch, PatchApply, ToJsonPatch};
 pub use source::Source;
 pub use tutor::Tutor;
+pub use validate::Validate;
@@ -0,0 +1,271 @@
+use nu_engine::command_prelude::*;
+use nu_protocol::ast::PathMember;
+
+#[derive(Clone)]
+pub struct Patch;
+
+impl Command for Patch {
+    fn name(&self) -> &str {
+        "patch"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("patch")
+            .input_output_types(vec![(Type::Nothing, Type::String)])
+            .category(Category::Misc)
+    }
+
+    fn description(&self) -> &str {
+        "Commands for applying structured patches."
+    }
+
+    fn extra_description(&self) -> &str {
+        "You must use one of the following subcommands. Using this command as-is will only produce this help message."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        Ok(Value::string(
+            nu_engine::get_full_help(self, engine_state, stack),
+            call.head,
+        )
+        .into_pipeline_data())
+    }
+}
+
+#[derive(Clone)]
+pub struct PatchApply;
+
+impl Command for PatchApply {
+    fn name(&self) -> &str {
+        "patch apply"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("patch apply")
+            .input_output_types(vec![(Type::Any, Type::Any)])
+            .required(
+                "patch",
+                SyntaxShape::Any,
+                "The patch to apply: the table produced by `diff`.",
+            )
+            .category(Category::Misc)
+    }
+
+    fn description(&self) -> &str {
+        "Apply a structured patch (as produced by `diff`) to the input value."
+    }
+
+    fn extra_description(&self) -> &str {
+        "`value | diff $other | ... ; $value | patch apply $the_diff` reproduces `$other`, so
+patches can be stored, reviewed, and replayed declaratively."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["diff", "merge", "mutate"]
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Apply a patch produced by `diff`",
+            example: "{a: 1, b: 2} | patch apply ({a: 1, b: 2} | diff {a: 1, b: 3})",
+            result: Some(Value::test_record(record! {
+                "a" => Value::test_int(1),
+                "b" => Value::test_int(3),
+            })),
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let patch: Value = call.req(engine_state, stack, 0)?;
+        let mut value = input.into_value(head)?;
+
+        // Removals are deferred and applied in reverse path order, so deleting several list
+        // elements doesn't shift the indices of the removals still to come
+        let mut removals: Vec<String> = Vec::new();
+        for entry in patch.into_list()? {
+            let entry = entry.into_record()?;
+            let op = entry
+                .get("op")
+                .map(|op| op.coerce_string())
+                .transpose()?
+                .unwrap_or_default();
+            let path = entry
+                .get("path")
+                .map(|path| path.coerce_string())
+                .transpose()?
+                .unwrap_or_default();
+            match op.as_str() {
+                "add" | "change" => {
+                    let new = entry.get("new").cloned().unwrap_or(Value::nothing(head));
+                    value.upsert_data_at_cell_path(&parse_path(&path, head), new)?;
+                }
+                "remove" => removals.push(path),
+                other => {
+                    return Err(ShellError::IncorrectValue {
+                        msg: format!("unknown patch op `{other}`"),
+                        val_span: head,
+                        call_span: head,
+                    });
+                }
+            }
+        }
+        removals.sort_by_key(|path| path_sort_key(path));
+        for path in removals.into_iter().rev() {
+            value.remove_data_at_cell_path(&parse_path(&path, head))?;
+        }
+
+        Ok(value.into_pipeline_data())
+    }
+}
+
+#[derive(Clone)]
+pub struct ToJsonPatch;
+
+impl Command for ToJsonPatch {
+    fn name(&self) -> &str {
+        "to json-patch"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to json-patch")
+            .input_output_types(vec![(Type::table(), Type::table())])
+            .category(Category::Formats)
+    }
+
+    fn description(&self) -> &str {
+        "Convert a structured patch (as produced by `diff`) to RFC 6902 JSON Patch operations."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Pipe the result through `to json` to hand a standard JSON Patch document to other tools."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["rfc6902", "diff"]
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Convert a diff to JSON Patch operations",
+            example: "{a: 1} | diff {a: 2} | to json-patch",
+            result: Some(Value::test_list(vec![Value::test_record(record! {
+                "op" => Value::test_string("replace"),
+                "path" => Value::test_string("/a"),
+                "value" => Value::test_int(2),
+            })])),
+        }]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let mut operations = Vec::new();
+        for entry in input.into_value(head)?.into_list()? {
+            let entry = entry.into_record()?;
+            let op = entry
+                .get("op")
+                .map(|op| op.coerce_string())
+                .transpose()?
+                .unwrap_or_default();
+            let path = entry
+                .get("path")
+                .map(|path| path.coerce_string())
+                .transpose()?
+                .unwrap_or_default();
+            // RFC 6901 pointer: `/` separated, with `~` and `/` escaped
+            let pointer = format!(
+                "/{}",
+                path.split('.')
+                    .map(|part| part.replace('~', "~0").replace('/', "~1"))
+                    .collect::<Vec<_>>()
+                    .join("/")
+            );
+            let record = match op.as_str() {
+                "add" => record! {
+                    "op" => Value::string("add", head),
+                    "path" => Value::string(pointer, head),
+                    "value" => entry.get("new").cloned().unwrap_or(Value::nothing(head)),
+                },
+                "change" => record! {
+                    "op" => Value::string("replace", head),
+                    "path" => Value::string(pointer, head),
+                    "value" => entry.get("new").cloned().unwrap_or(Value::nothing(head)),
+                },
+                "remove" => record! {
+                    "op" => Value::string("remove", head),
+                    "path" => Value::string(pointer, head),
+                },
+                other => {
+                    return Err(ShellError::IncorrectValue {
+                        msg: format!("unknown patch op `{other}`"),
+                        val_span: head,
+                        call_span: head,
+                    });
+                }
+            };
+            operations.push(Value::record(record, head));
+        }
+        Ok(Value::list(operations, head).into_pipeline_data())
+    }
+}
+
+/// A sort key that orders numeric path segments numerically, so list removals can be applied
+/// from the highest index down.
+fn path_sort_key(path: &str) -> Vec<(u8, u64, String)> {
+    path.split('.')
+        .map(|segment| match segment.parse::<u64>() {
+            Ok(index) => (0, index, String::new()),
+            Err(_) => (1, 0, segment.to_string()),
+        })
+        .collect()
+}
+
+/// Parse a dotted path from `diff` into cell path members; numeric segments index into lists.
+fn parse_path(path: &str, span: Span) -> Vec<PathMember> {
+    path.split('.')
+        .filter(|part| !part.is_empty())
+        .map(|part| match part.parse::<usize>() {
+            Ok(index) => PathMember::Int {
+                val: index,
+                span,
+                optional: false,
+            },
+            Err(_) => PathMember::String {
+                val: part.into(),
+                span,
+                optional: false,
+            },
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::{misc::Diff, test_examples_with_commands};
+
+        test_examples_with_commands(PatchApply {}, &[&Diff]);
+        test_examples_with_commands(ToJsonPatch {}, &[&Diff])
+    }
+}
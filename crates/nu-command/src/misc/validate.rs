@@ -0,0 +1,298 @@
+use nu_engine::command_prelude::*;
+
+#[derive(Clone)]
+pub struct Validate;
+
+impl Command for Validate {
+    fn name(&self) -> &str {
+        "validate"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("validate")
+            .input_output_types(vec![(Type::Any, Type::table()), (Type::Any, Type::Any)])
+            .allow_variants_without_examples(true)
+            .required(
+                "schema",
+                SyntaxShape::Any,
+                "The schema to check the input against.",
+            )
+            .switch(
+                "strict",
+                "Error on the first violation instead of returning a table",
+                Some('s'),
+            )
+            .category(Category::Misc)
+    }
+
+    fn description(&self) -> &str {
+        "Check the input value against a schema, returning the violations."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"A schema is either a type name ("string", "int", "bool", "float", "list",
+"record", "datetime", "duration", "filesize", "nothing", "any") or a record with any
+of these keys:
+
+    type       a type name as above
+    required   whether the field must be present (default true, fields only)
+    fields     record of field name -> schema, for records
+    items      schema for every element, for lists
+    one_of     list of allowed values
+    pattern    regex the value must match, for strings
+    min / max  inclusive bounds, for numbers
+
+The output is a table of violations with the cell path and a message; an empty table
+means the input is valid. With --strict, the first violation is raised as an error and
+the input passes through unchanged when valid, so `validate` can sit mid-pipeline."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["schema", "check", "conform", "assert"]
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Validate a record against a schema",
+                example: r#"{name: "nu", port: 99999} | validate {fields: {name: {type: string}, port: {type: int, max: 65535}}}"#,
+                result: Some(Value::test_list(vec![Value::test_record(record! {
+                    "path" => Value::test_string("port"),
+                    "message" => Value::test_string("99999 is greater than the maximum 65535"),
+                })])),
+            },
+            Example {
+                description: "Let valid data flow through, stopping the pipeline otherwise",
+                example: r#"open config.json | validate $schema --strict | get servers"#,
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let schema: Value = call.req(engine_state, stack, 0)?;
+        let strict = call.has_flag(engine_state, stack, "strict")?;
+        let value = input.into_value(head)?;
+
+        let mut violations = Vec::new();
+        check(&value, &schema, &mut Vec::new(), &mut violations, head)?;
+
+        if strict {
+            if let Some(first) = violations.first() {
+                let path = first
+                    .get_data_by_key("path")
+                    .map(|path| path.coerce_string())
+                    .transpose()?
+                    .unwrap_or_default();
+                let message = first
+                    .get_data_by_key("message")
+                    .map(|message| message.coerce_string())
+                    .transpose()?
+                    .unwrap_or_default();
+                return Err(ShellError::IncorrectValue {
+                    msg: if path.is_empty() {
+                        message
+                    } else {
+                        format!("{path}: {message}")
+                    },
+                    val_span: value.span(),
+                    call_span: head,
+                });
+            }
+            Ok(value.into_pipeline_data())
+        } else {
+            Ok(Value::list(violations, head).into_pipeline_data())
+        }
+    }
+}
+
+fn violation(path: &[String], message: String, head: Span) -> Value {
+    Value::record(
+        record! {
+            "path" => Value::string(path.join("."), head),
+            "message" => Value::string(message, head),
+        },
+        head,
+    )
+}
+
+/// The user-facing name of a value's type, matching the names accepted in schemas.
+fn type_name(value: &Value) -> String {
+    value.get_type().to_string()
+}
+
+fn type_matches(value: &Value, wanted: &str) -> bool {
+    match wanted {
+        "any" => true,
+        "number" => matches!(value, Value::Int { .. } | Value::Float { .. }),
+        "record" => matches!(value, Value::Record { .. }),
+        "list" | "table" => matches!(value, Value::List { .. }),
+        wanted => type_name(value) == wanted,
+    }
+}
+
+/// Recursively check `value` against `schema`, pushing violations with their cell paths.
+fn check(
+    value: &Value,
+    schema: &Value,
+    path: &mut Vec<String>,
+    violations: &mut Vec<Value>,
+    head: Span,
+) -> Result<(), ShellError> {
+    match schema {
+        Value::String { val: wanted, .. } => {
+            if !type_matches(value, wanted) {
+                violations.push(violation(
+                    path,
+                    format!("expected {wanted}, found {}", type_name(value)),
+                    head,
+                ));
+            }
+        }
+        Value::Record { val: schema, .. } => {
+            if let Some(wanted) = schema.get("type") {
+                let wanted = wanted.coerce_str()?;
+                if !type_matches(value, &wanted) {
+                    violations.push(violation(
+                        path,
+                        format!("expected {wanted}, found {}", type_name(value)),
+                        head,
+                    ));
+                    return Ok(());
+                }
+            }
+            if let Some(allowed) = schema.get("one_of") {
+                if let Value::List { vals, .. } = allowed {
+                    if !vals.contains(value) {
+                        violations.push(violation(
+                            path,
+                            format!(
+                                "{} is not one of the allowed values",
+                                value.to_abbreviated_string(&nu_protocol::Config::default())
+                            ),
+                            head,
+                        ));
+                        return Ok(());
+                    }
+                }
+            }
+            if let Some(pattern) = schema.get("pattern") {
+                let pattern = pattern.coerce_str()?;
+                if let Ok(text) = value.coerce_str() {
+                    let regex = fancy_regex::Regex::new(&pattern).map_err(|err| {
+                        ShellError::IncorrectValue {
+                            msg: format!("invalid schema pattern: {err}"),
+                            val_span: schema.get("pattern").expect("just read").span(),
+                            call_span: head,
+                        }
+                    })?;
+                    if !regex.is_match(&text).unwrap_or(false) {
+                        violations.push(violation(
+                            path,
+                            format!("'{text}' does not match pattern '{pattern}'"),
+                            head,
+                        ));
+                    }
+                }
+            }
+            if let (Some(min), Ok(actual)) = (schema.get("min"), value.coerce_float()) {
+                if actual < min.coerce_float()? {
+                    violations.push(violation(
+                        path,
+                        format!(
+                            "{} is less than the minimum {}",
+                            value.to_abbreviated_string(&nu_protocol::Config::default()),
+                            min.to_abbreviated_string(&nu_protocol::Config::default())
+                        ),
+                        head,
+                    ));
+                }
+            }
+            if let (Some(max), Ok(actual)) = (schema.get("max"), value.coerce_float()) {
+                if actual > max.coerce_float()? {
+                    violations.push(violation(
+                        path,
+                        format!(
+                            "{} is greater than the maximum {}",
+                            value.to_abbreviated_string(&nu_protocol::Config::default()),
+                            max.to_abbreviated_string(&nu_protocol::Config::default())
+                        ),
+                        head,
+                    ));
+                }
+            }
+            if let Some(Value::Record { val: fields, .. }) = schema.get("fields") {
+                if let Value::Record { val: record, .. } = value {
+                    for (field, field_schema) in fields.iter() {
+                        path.push(field.clone());
+                        match record.get(field) {
+                            Some(field_value) => {
+                                check(field_value, field_schema, path, violations, head)?;
+                            }
+                            None => {
+                                let required = field_schema
+                                    .get_data_by_key("required")
+                                    .map(|required| required.as_bool())
+                                    .transpose()?
+                                    .unwrap_or(true);
+                                if required {
+                                    violations.push(violation(
+                                        path,
+                                        "required field is missing".into(),
+                                        head,
+                                    ));
+                                }
+                            }
+                        }
+                        path.pop();
+                    }
+                } else {
+                    violations.push(violation(
+                        path,
+                        format!("expected record, found {}", type_name(value)),
+                        head,
+                    ));
+                }
+            }
+            if let Some(items) = schema.get("items") {
+                if let Value::List { vals, .. } = value {
+                    for (idx, item) in vals.iter().enumerate() {
+                        path.push(idx.to_string());
+                        check(item, items, path, violations, head)?;
+                        path.pop();
+                    }
+                }
+            }
+        }
+        other => {
+            return Err(ShellError::IncorrectValue {
+                msg: format!(
+                    "schemas are type names or records, found {}",
+                    type_name(other)
+                ),
+                val_span: other.span(),
+                call_span: head,
+            });
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Validate {})
+    }
+}
@@ -4,6 +4,7 @@ mod encode;
 mod join;
 mod parse;
 mod query;
+mod resolve;
 mod split_query;
 mod url_;
 
@@ -12,5 +13,6 @@ pub use build_query::UrlBuildQuery;
 pub use decode::UrlDecode;
 pub use encode::UrlEncode;
 pub use join::UrlJoin;
+pub use resolve::UrlResolve;
 pub use split_query::UrlSplitQuery;
 pub use url_::Url;
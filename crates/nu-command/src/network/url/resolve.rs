@@ -0,0 +1,91 @@
+use nu_engine::command_prelude::*;
+
+#[derive(Clone)]
+pub struct UrlResolve;
+
+impl Command for UrlResolve {
+    fn name(&self) -> &str {
+        "url resolve"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("url resolve")
+            .input_output_types(vec![(Type::String, Type::String)])
+            .required(
+                "reference",
+                SyntaxShape::String,
+                "The (possibly relative) URL reference to resolve against the input base URL.",
+            )
+            .category(Category::Network)
+    }
+
+    fn description(&self) -> &str {
+        "Resolve a URL reference against a base URL, per RFC 3986."
+    }
+
+    fn extra_description(&self) -> &str {
+        "This is what a browser does with a link on a page: relative paths, absolute paths,
+and full URLs are all handled, so API endpoints can be built without string surgery."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["relative", "base", "reference", "link"]
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Resolve a relative path",
+                example: r#""https://example.com/api/v1/jobs" | url resolve ../v2/users"#,
+                result: Some(Value::test_string("https://example.com/api/v2/users")),
+            },
+            Example {
+                description: "An absolute path replaces the whole path",
+                example: r#""https://example.com/api/v1" | url resolve /healthz"#,
+                result: Some(Value::test_string("https://example.com/healthz")),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let reference: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let metadata = input.metadata();
+        let value = input.into_value(head)?;
+        let base_span = value.span();
+        let base = value.coerce_into_string()?;
+
+        let base = url::Url::parse(&base).map_err(|err| ShellError::IncorrectValue {
+            msg: format!("invalid base URL: {err}"),
+            val_span: base_span,
+            call_span: head,
+        })?;
+        let resolved = base
+            .join(&reference.item)
+            .map_err(|err| ShellError::IncorrectValue {
+                msg: format!("invalid URL reference: {err}"),
+                val_span: reference.span,
+                call_span: head,
+            })?;
+
+        Ok(Value::string(resolved, head).into_pipeline_data_with_metadata(metadata))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(UrlResolve {})
+    }
+}
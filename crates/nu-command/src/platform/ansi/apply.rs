@@ -0,0 +1,91 @@
+use nu_color_config::lookup_ansi_color_style;
+use nu_engine::command_prelude::*;
+
+#[derive(Clone)]
+pub struct AnsiApply;
+
+impl Command for AnsiApply {
+    fn name(&self) -> &str {
+        "ansi apply"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("ansi apply")
+            .input_output_types(vec![(Type::String, Type::String)])
+            .required(
+                "style",
+                SyntaxShape::Any,
+                "The style to apply: a named style (e.g. `red_bold`), a hex color, or a record like `{fg: '#ff0000', attr: b}`.",
+            )
+            .category(Category::Platform)
+    }
+
+    fn description(&self) -> &str {
+        "Wrap the input text in the ANSI codes for a style, with a trailing reset."
+    }
+
+    fn extra_description(&self) -> &str {
+        "The style is expressed the same way as in `$env.config.color_config`, so themes and
+one-off styling share one vocabulary. Composition is a pipeline: apply one style, embed the
+result in a larger string, and apply another."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["color", "style", "highlight", "escape"]
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Style a string with a named style",
+                example: r#""error" | ansi apply red_bold"#,
+                result: Some(Value::test_string("\u{1b}[1;31merror\u{1b}[0m")),
+            },
+            Example {
+                description: "Style a string with a style record",
+                example: r##""warning" | ansi apply {fg: "#ffa500", attr: b}"##,
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let style: Value = call.req(engine_state, stack, 0)?;
+        let metadata = input.metadata();
+        let text = input.into_value(head)?.coerce_into_string()?;
+
+        let style = match style {
+            Value::String { ref val, .. } => lookup_ansi_color_style(val),
+            record @ Value::Record { .. } => nu_color_config::color_record_to_nustyle(&record),
+            other => {
+                return Err(ShellError::RuntimeTypeMismatch {
+                    expected: Type::custom("string or record"),
+                    actual: other.get_type(),
+                    span: other.span(),
+                });
+            }
+        };
+
+        Ok(Value::string(format!("{}", style.paint(text)), head)
+            .into_pipeline_data_with_metadata(metadata))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(AnsiApply {})
+    }
+}
@@ -1,7 +1,9 @@
 mod ansi_;
+mod apply;
 mod link;
 mod strip;
 
 pub use ansi_::Ansi;
+pub use apply::AnsiApply;
 pub use link::AnsiLink;
 pub use strip::AnsiStrip;
@@ -10,7 +10,7 @@ mod term;
 mod ulimit;
 mod whoami;
 
-pub use ansi::{Ansi, AnsiLink, AnsiStrip};
+pub use ansi::{Ansi, AnsiApply, AnsiLink, AnsiStrip};
 pub use clear::Clear;
 pub use dir_info::{DirBuilder, DirInfo, FileInfo};
 pub use input::Input;
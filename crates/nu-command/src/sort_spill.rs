@@ -0,0 +1,262 @@
+//! External sorting for `sort-by`: when `$env.config.sort_spill_threshold` is set and the
+//! input exceeds that many rows, sorted runs are spilled to temporary files and merged back
+//! as a stream, so sorting a huge input doesn't have to hold every row in memory at once.
+
+use std::{
+    cmp::Ordering,
+    fs::File,
+    io::{BufRead, BufReader, BufWriter, Seek, Write},
+};
+
+use nu_protocol::{IntoPipelineData, ListStream, PipelineData, ShellError, Signals, Span, Value};
+
+use crate::{compare_by, Comparator};
+
+/// Sort the rows of `input` while keeping at most `threshold` of them in memory.
+///
+/// Rows are collected into chunks of `threshold`, each chunk is sorted and written to an
+/// anonymous temporary file (one JSON value per line, deleted by the OS once dropped), and
+/// the runs are streamed back through a k-way merge. If the input never exceeds the budget,
+/// this is an ordinary in-memory sort.
+#[allow(clippy::too_many_arguments)]
+pub fn sort_by_spilling(
+    input: impl Iterator<Item = Value>,
+    mut comparators: Vec<Comparator>,
+    head: Span,
+    insensitive: bool,
+    natural: bool,
+    reverse: bool,
+    threshold: usize,
+    signals: Signals,
+) -> Result<PipelineData, ShellError> {
+    let threshold = threshold.max(1);
+    let mut chunk: Vec<Value> = Vec::new();
+    let mut runs: Vec<RunReader> = Vec::new();
+
+    for value in input {
+        chunk.push(value);
+        if chunk.len() >= threshold {
+            sort_chunk(
+                &mut chunk,
+                &mut comparators,
+                head,
+                insensitive,
+                natural,
+                reverse,
+            )?;
+            runs.push(RunReader::from_sorted(&chunk, head)?);
+            chunk.clear();
+        }
+    }
+
+    sort_chunk(
+        &mut chunk,
+        &mut comparators,
+        head,
+        insensitive,
+        natural,
+        reverse,
+    )?;
+
+    if runs.is_empty() {
+        // the input fit in the budget after all
+        return Ok(Value::list(chunk, head).into_pipeline_data());
+    }
+
+    let mut merge = MergeIterator {
+        sources: Vec::with_capacity(runs.len() + 1),
+        heads: Vec::with_capacity(runs.len() + 1),
+        comparators,
+        head,
+        insensitive,
+        natural,
+        reverse,
+        failed: false,
+    };
+    for run in runs {
+        merge.sources.push(MergeSource::Run(run));
+    }
+    merge.sources.push(MergeSource::Memory(chunk.into_iter()));
+    for source in &mut merge.sources {
+        let head = source.next_value(head)?;
+        merge.heads.push(head);
+    }
+
+    Ok(PipelineData::ListStream(
+        ListStream::new(merge, head, signals),
+        None,
+    ))
+}
+
+/// Sort one in-memory chunk. Reversal is done exactly like the in-memory `sort-by` path
+/// (stable ascending sort, then reverse), so tied rows come out in the same order whether or
+/// not the input spilled.
+fn sort_chunk(
+    chunk: &mut [Value],
+    comparators: &mut [Comparator],
+    head: Span,
+    insensitive: bool,
+    natural: bool,
+    reverse: bool,
+) -> Result<(), ShellError> {
+    let mut compare_err: Option<ShellError> = None;
+    chunk.sort_by(|a, b| {
+        compare_by(
+            a,
+            b,
+            comparators,
+            head,
+            insensitive,
+            natural,
+            &mut compare_err,
+        )
+    });
+    if reverse {
+        chunk.reverse();
+    }
+    compare_err.map_or(Ok(()), Err)
+}
+
+/// A sorted run on disk, read back one value per line.
+struct RunReader {
+    reader: BufReader<File>,
+}
+
+impl RunReader {
+    fn from_sorted(values: &[Value], span: Span) -> Result<Self, ShellError> {
+        let error = |msg: String| ShellError::GenericError {
+            error: "Sort spill failed".into(),
+            msg,
+            span: Some(span),
+            help: None,
+            inner: vec![],
+        };
+        let mut writer = BufWriter::new(
+            tempfile::tempfile().map_err(|err| error(format!("can't create run file: {err}")))?,
+        );
+        for value in values {
+            serde_json::to_writer(&mut writer, value)
+                .map_err(|err| error(format!("can't write run file: {err}")))?;
+            writer
+                .write_all(b"\n")
+                .map_err(|err| error(format!("can't write run file: {err}")))?;
+        }
+        let mut file = writer
+            .into_inner()
+            .map_err(|err| error(format!("can't write run file: {err}")))?;
+        file.rewind()
+            .map_err(|err| error(format!("can't rewind run file: {err}")))?;
+        Ok(RunReader {
+            reader: BufReader::new(file),
+        })
+    }
+
+    fn next_value(&mut self, span: Span) -> Result<Option<Value>, ShellError> {
+        let mut line = String::new();
+        let read = self
+            .reader
+            .read_line(&mut line)
+            .map_err(|err| ShellError::GenericError {
+                error: "Sort spill failed".into(),
+                msg: format!("can't read run file: {err}"),
+                span: Some(span),
+                help: None,
+                inner: vec![],
+            })?;
+        if read == 0 {
+            return Ok(None);
+        }
+        serde_json::from_str(&line).map_err(|err| ShellError::GenericError {
+            error: "Sort spill failed".into(),
+            msg: format!("can't decode run file: {err}"),
+            span: Some(span),
+            help: None,
+            inner: vec![],
+        })
+    }
+}
+
+enum MergeSource {
+    Run(RunReader),
+    Memory(std::vec::IntoIter<Value>),
+}
+
+impl MergeSource {
+    fn next_value(&mut self, span: Span) -> Result<Option<Value>, ShellError> {
+        match self {
+            MergeSource::Run(run) => run.next_value(span),
+            MergeSource::Memory(iter) => Ok(iter.next()),
+        }
+    }
+}
+
+/// K-way merge over the sorted runs (and the final in-memory chunk), smallest head first.
+struct MergeIterator {
+    sources: Vec<MergeSource>,
+    heads: Vec<Option<Value>>,
+    comparators: Vec<Comparator>,
+    head: Span,
+    insensitive: bool,
+    natural: bool,
+    reverse: bool,
+    failed: bool,
+}
+
+impl Iterator for MergeIterator {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Value> {
+        if self.failed {
+            return None;
+        }
+
+        // Pick the best head. Forward merges take the smallest, ties going to the earliest
+        // run; reverse merges take the largest, ties going to the latest run — matching the
+        // "stable ascending sort, then reverse" order of the in-memory path.
+        let mut compare_err: Option<ShellError> = None;
+        let mut best: Option<usize> = None;
+        for idx in 0..self.heads.len() {
+            let Some(candidate) = &self.heads[idx] else {
+                continue;
+            };
+            let better = match best {
+                None => true,
+                Some(best_idx) => {
+                    let current = self.heads[best_idx]
+                        .as_ref()
+                        .expect("best head is always occupied");
+                    let ordering = compare_by(
+                        candidate,
+                        current,
+                        &mut self.comparators,
+                        self.head,
+                        self.insensitive,
+                        self.natural,
+                        &mut compare_err,
+                    );
+                    if self.reverse {
+                        ordering != Ordering::Less
+                    } else {
+                        ordering == Ordering::Less
+                    }
+                }
+            };
+            if better {
+                best = Some(idx);
+            }
+        }
+        if let Some(err) = compare_err {
+            self.failed = true;
+            return Some(Value::error(err, self.head));
+        }
+
+        let best = best?;
+        match self.sources[best].next_value(self.head) {
+            Ok(next) => std::mem::replace(&mut self.heads[best], next),
+            Err(err) => {
+                self.failed = true;
+                Some(Value::error(err, self.head))
+            }
+        }
+    }
+}
@@ -1,7 +1,10 @@
 use nu_engine::ClosureEval;
-use nu_protocol::{ast::CellPath, PipelineData, Record, ShellError, Span, Value};
+use nu_protocol::{
+    ast::{CellPath, PathMember},
+    PipelineData, Record, ShellError, Span, Value,
+};
 use nu_utils::IgnoreCaseExt;
-use std::cmp::Ordering;
+use std::{cmp::Ordering, sync::Mutex};
 
 /// A specification of sort order for `sort_by`.
 ///
@@ -24,7 +27,32 @@ pub enum Comparator {
 /// Generally, values of different types are ordered by order of appearance in the `Value` enum.
 /// However, this is not always the case. For example, ints and floats will be grouped together since
 /// `Value`'s `PartialOrd` defines a non-decreasing ordering between non-decreasing integers and floats.
+/// Inputs at least this large are sorted on the rayon thread pool; comparing `Value`s is
+/// expensive enough that the split pays for itself well before this size.
+const PARALLEL_SORT_THRESHOLD: usize = 8 * 1024;
+
 pub fn sort(vec: &mut [Value], insensitive: bool, natural: bool) -> Result<(), ShellError> {
+    // Large inputs are worth the thread pool; the comparator has no side effects beyond
+    // error reporting, which goes through a mutex
+    if vec.len() >= PARALLEL_SORT_THRESHOLD {
+        use rayon::prelude::*;
+
+        let compare_err: Mutex<Option<ShellError>> = Mutex::new(None);
+        vec.par_sort_by(|a, b| {
+            compare_values(a, b, insensitive, natural).unwrap_or_else(|err| {
+                if let Ok(mut compare_err) = compare_err.lock() {
+                    compare_err.get_or_insert(err);
+                }
+                Ordering::Equal
+            })
+        });
+
+        return match compare_err.into_inner() {
+            Ok(Some(err)) => Err(err),
+            _ => Ok(()),
+        };
+    }
+
     // allow the comparator function to indicate error
     // by mutating this option captured by the closure,
     // since sort_by closure must be infallible
@@ -67,6 +95,43 @@ pub fn sort_by(
         });
     }
 
+    // Cell-path comparators are pure, so large inputs can be sorted on the rayon thread
+    // pool; closure comparators run user code against the engine and have to stay serial
+    let all_cell_paths = comparators
+        .iter()
+        .all(|comparator| matches!(comparator, Comparator::CellPath(_)));
+    if all_cell_paths && vec.len() >= PARALLEL_SORT_THRESHOLD {
+        use rayon::prelude::*;
+
+        let compare_err: Mutex<Option<ShellError>> = Mutex::new(None);
+        vec.par_sort_by(|a, b| {
+            comparators
+                .iter()
+                .map(|comparator| match comparator {
+                    Comparator::CellPath(cell_path) => {
+                        compare_cell_path(a, b, cell_path, insensitive, natural)
+                    }
+                    _ => unreachable!("checked all comparators are cell paths above"),
+                })
+                .find_map(|result| match result {
+                    Ok(Ordering::Equal) => None,
+                    Ok(ordering) => Some(ordering),
+                    Err(err) => {
+                        if let Ok(mut compare_err) = compare_err.lock() {
+                            compare_err.get_or_insert(err);
+                        }
+                        Some(Ordering::Equal)
+                    }
+                })
+                .unwrap_or(Ordering::Equal)
+        });
+
+        return match compare_err.into_inner() {
+            Ok(Some(err)) => Err(err),
+            _ => Ok(()),
+        };
+    }
+
     // allow the comparator function to indicate error
     // by mutating this option captured by the closure,
     // since sort_by closure must be infallible
@@ -239,11 +304,48 @@ pub fn compare_cell_path(
     insensitive: bool,
     natural: bool,
 ) -> Result<Ordering, ShellError> {
+    // The straightforward record-field/list-index case can be compared by reference; cloning
+    // both rows for every comparison used to dominate sort profiles on wide tables
+    if let (Some(left), Some(right)) = (
+        borrow_cell_path(left, &cell_path.members),
+        borrow_cell_path(right, &cell_path.members),
+    ) {
+        return compare_values(left, right, insensitive, natural);
+    }
     let left = left.clone().follow_cell_path(&cell_path.members, false)?;
     let right = right.clone().follow_cell_path(&cell_path.members, false)?;
     compare_values(&left, &right, insensitive, natural)
 }
 
+/// Follow a cell path by reference for the simple access patterns (record field by exact name,
+/// list element by index). Returns `None` for anything else, so callers can fall back to the
+/// owned walker.
+fn borrow_cell_path<'a>(value: &'a Value, members: &[PathMember]) -> Option<&'a Value> {
+    let mut current = value;
+    for member in members {
+        match (member, current) {
+            (
+                PathMember::String {
+                    val,
+                    optional: false,
+                    ..
+                },
+                Value::Record { val: record, .. },
+            ) => current = record.get(val)?,
+            (
+                PathMember::Int {
+                    val,
+                    optional: false,
+                    ..
+                },
+                Value::List { vals, .. },
+            ) => current = vals.get(*val)?,
+            _ => return None,
+        }
+    }
+    Some(current)
+}
+
 pub fn compare_key_closure(
     left: &Value,
     right: &Value,
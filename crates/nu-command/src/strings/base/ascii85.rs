@@ -0,0 +1,234 @@
+use nu_engine::command_prelude::*;
+
+const EXTRA_USAGE: &str = r"This uses the btoa/Adobe-style Ascii85 alphabet (`!` through `u`), without the
+`<~ ~>` frame, and `z` as a shorthand for four zero bytes.
+
+Note this command will collect stream input.";
+
+#[derive(Clone)]
+pub struct DecodeAscii85;
+
+impl Command for DecodeAscii85 {
+    fn name(&self) -> &str {
+        "decode ascii85"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("decode ascii85")
+            .input_output_types(vec![(Type::String, Type::Binary)])
+            .allow_variants_without_examples(true)
+            .category(Category::Formats)
+    }
+
+    fn description(&self) -> &str {
+        "Decode an Ascii85 value."
+    }
+
+    fn extra_description(&self) -> &str {
+        EXTRA_USAGE
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Decode an encoded string",
+            example: r#""87cUR" | decode ascii85 | decode"#,
+            result: None,
+        }]
+    }
+
+    fn is_const(&self) -> bool {
+        true
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        decode_ascii85(call.head, input)
+    }
+
+    fn run_const(
+        &self,
+        _working_set: &StateWorkingSet,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        decode_ascii85(call.head, input)
+    }
+}
+
+#[derive(Clone)]
+pub struct EncodeAscii85;
+
+impl Command for EncodeAscii85 {
+    fn name(&self) -> &str {
+        "encode ascii85"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("encode ascii85")
+            .input_output_types(vec![
+                (Type::String, Type::String),
+                (Type::Binary, Type::String),
+            ])
+            .allow_variants_without_examples(true)
+            .category(Category::Formats)
+    }
+
+    fn description(&self) -> &str {
+        "Encode a string or binary value using Ascii85."
+    }
+
+    fn extra_description(&self) -> &str {
+        EXTRA_USAGE
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Encode a string with Ascii85",
+            example: r#""hello world!" | encode ascii85"#,
+            result: Some(Value::test_string("BOu!rD]j7BEbo80")),
+        }]
+    }
+
+    fn is_const(&self) -> bool {
+        true
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        encode_ascii85(call.head, input)
+    }
+
+    fn run_const(
+        &self,
+        _working_set: &StateWorkingSet,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        encode_ascii85(call.head, input)
+    }
+}
+
+fn encode_ascii85(call_span: Span, input: PipelineData) -> Result<PipelineData, ShellError> {
+    let metadata = input.metadata();
+    let (bytes, _) = super::get_binary(input, call_span)?;
+
+    let mut output = String::new();
+    for chunk in bytes.chunks(4) {
+        let mut group = [0u8; 4];
+        group[..chunk.len()].copy_from_slice(chunk);
+        let value = u32::from_be_bytes(group);
+        if value == 0 && chunk.len() == 4 {
+            output.push('z');
+            continue;
+        }
+        let mut digits = [0u8; 5];
+        let mut rest = value;
+        for digit in digits.iter_mut().rev() {
+            *digit = (rest % 85) as u8;
+            rest /= 85;
+        }
+        // A partial final group of n bytes emits n + 1 digits
+        for digit in &digits[..chunk.len() + 1] {
+            output.push((digit + b'!') as char);
+        }
+    }
+
+    Ok(Value::string(output, call_span).into_pipeline_data_with_metadata(metadata))
+}
+
+fn decode_ascii85(call_span: Span, input: PipelineData) -> Result<PipelineData, ShellError> {
+    let metadata = input.metadata();
+    let (text, input_span) = super::get_string(input, call_span)?;
+
+    let invalid = |msg: String| ShellError::IncorrectValue {
+        msg,
+        val_span: input_span,
+        call_span,
+    };
+
+    let mut output = Vec::new();
+    let mut digits: Vec<u8> = Vec::with_capacity(5);
+    let mut flush = |digits: &mut Vec<u8>, output: &mut Vec<u8>| -> Result<(), ShellError> {
+        if digits.is_empty() {
+            return Ok(());
+        }
+        if digits.len() == 1 {
+            return Err(invalid("a group of one Ascii85 digit is invalid".into()));
+        }
+        let missing = 5 - digits.len();
+        let mut value: u32 = 0;
+        for digit in digits.iter().chain(std::iter::repeat(&84).take(missing)) {
+            value = value
+                .checked_mul(85)
+                .and_then(|value| value.checked_add(*digit as u32))
+                .ok_or_else(|| invalid("Ascii85 group out of range".into()))?;
+        }
+        let bytes = value.to_be_bytes();
+        output.extend_from_slice(&bytes[..4 - missing]);
+        digits.clear();
+        Ok(())
+    };
+
+    for c in text.chars() {
+        match c {
+            'z' if digits.is_empty() => output.extend_from_slice(&[0, 0, 0, 0]),
+            '!'..='u' => {
+                digits.push(c as u8 - b'!');
+                if digits.len() == 5 {
+                    flush(&mut digits, &mut output)?;
+                }
+            }
+            c if c.is_ascii_whitespace() => (),
+            c => return Err(invalid(format!("invalid Ascii85 character `{c}`"))),
+        }
+    }
+    flush(&mut digits, &mut output)?;
+
+    Ok(Value::binary(output, call_span).into_pipeline_data_with_metadata(metadata))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        crate::test_examples(DecodeAscii85 {});
+        crate::test_examples(EncodeAscii85 {})
+    }
+
+    #[test]
+    fn round_trip() {
+        let span = Span::test_data();
+        for input in [
+            &b""[..],
+            b"a",
+            b"ab",
+            b"abc",
+            b"abcd",
+            b"hello world!",
+            &[0, 0, 0, 0, 1],
+        ] {
+            let encoded = encode_ascii85(
+                span,
+                Value::test_binary(input.to_vec()).into_pipeline_data(),
+            )
+            .and_then(|data| data.into_value(span))
+            .expect("encoding failed");
+            let decoded = decode_ascii85(span, encoded.into_pipeline_data())
+                .and_then(|data| data.into_value(span))
+                .expect("decoding failed");
+            assert_eq!(decoded, Value::test_binary(input.to_vec()));
+        }
+    }
+}
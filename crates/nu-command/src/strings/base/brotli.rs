@@ -0,0 +1,131 @@
+use nu_engine::command_prelude::*;
+use std::io::Write;
+
+const EXTRA_USAGE: &str = "Note this command will collect stream input.";
+
+#[derive(Clone)]
+pub struct DecodeBrotli;
+
+impl Command for DecodeBrotli {
+    fn name(&self) -> &str {
+        "decode brotli"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("decode brotli")
+            .input_output_types(vec![(Type::Binary, Type::Binary)])
+            .allow_variants_without_examples(true)
+            .category(Category::Formats)
+    }
+
+    fn description(&self) -> &str {
+        "Decompress a brotli-compressed value."
+    }
+
+    fn extra_description(&self) -> &str {
+        EXTRA_USAGE
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Decompress a brotli-compressed file",
+            example: "open --raw file.br | decode brotli | decode",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let call_span = call.head;
+        let metadata = input.metadata();
+        let (bytes, input_span) = super::get_binary(input, call_span)?;
+        let mut output = Vec::new();
+        brotli::BrotliDecompress(&mut bytes.as_slice(), &mut output).map_err(|err| {
+            ShellError::IncorrectValue {
+                msg: format!("not valid brotli data: {err}"),
+                val_span: input_span,
+                call_span,
+            }
+        })?;
+        Ok(Value::binary(output, call_span).into_pipeline_data_with_metadata(metadata))
+    }
+}
+
+#[derive(Clone)]
+pub struct EncodeBrotli;
+
+impl Command for EncodeBrotli {
+    fn name(&self) -> &str {
+        "encode brotli"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("encode brotli")
+            .input_output_types(vec![
+                (Type::String, Type::Binary),
+                (Type::Binary, Type::Binary),
+            ])
+            .allow_variants_without_examples(true)
+            .named(
+                "quality",
+                SyntaxShape::Int,
+                "Compression quality, 0-11 (default 3)",
+                Some('q'),
+            )
+            .category(Category::Formats)
+    }
+
+    fn description(&self) -> &str {
+        "Compress a string or binary value with brotli."
+    }
+
+    fn extra_description(&self) -> &str {
+        EXTRA_USAGE
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Compress data before saving it",
+            example: "open --raw big.json | encode brotli | save big.json.br",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let call_span = call.head;
+        let quality = call
+            .get_flag::<i64>(engine_state, stack, "quality")?
+            .unwrap_or(3)
+            .clamp(0, 11) as u32;
+        let metadata = input.metadata();
+        let (bytes, _) = super::get_binary(input, call_span)?;
+        let mut output = Vec::new();
+        {
+            let mut writer = brotli::CompressorWriter::new(&mut output, 4096, quality, 20);
+            writer.write_all(&bytes).map_err(|err| {
+                nu_protocol::shell_error::io::IoError::new(err.kind(), call_span, None)
+            })?;
+        }
+        Ok(Value::binary(output, call_span).into_pipeline_data_with_metadata(metadata))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_examples() {
+        crate::test_examples(super::DecodeBrotli {});
+        crate::test_examples(super::EncodeBrotli {})
+    }
+}
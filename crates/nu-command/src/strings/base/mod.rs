@@ -4,14 +4,18 @@ use data_encoding::Encoding;
 
 use nu_engine::command_prelude::*;
 
+mod ascii85;
 mod base32;
 mod base32hex;
 mod base64;
+mod brotli;
 mod hex;
 
+pub use ascii85::{DecodeAscii85, EncodeAscii85};
 pub use base32::{DecodeBase32, EncodeBase32};
 pub use base32hex::{DecodeBase32Hex, EncodeBase32Hex};
 pub use base64::{DecodeBase64, EncodeBase64};
+pub use brotli::{DecodeBrotli, EncodeBrotli};
 pub use hex::{DecodeHex, EncodeHex};
 
 pub fn decode(
@@ -35,7 +35,7 @@ impl Command for FormatDuration {
             .required(
                 "format value",
                 SyntaxShape::String,
-                "The unit in which to display the duration.",
+                "The unit in which to display the duration, or `human` for a compound human-readable form.",
             )
             .rest(
                 "rest",
@@ -119,6 +119,11 @@ impl Command for FormatDuration {
                 example: "1000000µs | format duration sec",
                 result: Some(Value::test_string("1 sec")),
             },
+            Example {
+                description: "Display a duration in a compound human-readable form",
+                example: "2hr + 3min | format duration human",
+                result: Some(Value::test_string("2hr 3min")),
+            },
             Example {
                 description: "Convert durations to µs duration as strings",
                 example: "[1sec 2sec] | format duration µs",
@@ -142,6 +147,9 @@ fn format_value_impl(val: &Value, arg: &Arguments, span: Span) -> Value {
         Value::Duration { val: inner, .. } => {
             let duration = *inner;
             let float_precision = arg.float_precision;
+            if arg.format_value == "human" {
+                return Value::string(nu_protocol::format_duration(duration), inner_span);
+            }
             match convert_inner_to_unit(duration, &arg.format_value, span, inner_span) {
                 Ok(d) => {
                     let unit = if &arg.format_value == "us" {
@@ -0,0 +1,267 @@
+use nu_engine::command_prelude::*;
+use nu_parser::{flatten_block, parse, FlatShape};
+use nu_protocol::engine::StateWorkingSet;
+
+#[derive(Clone)]
+pub struct FormatNu;
+
+impl Command for FormatNu {
+    fn name(&self) -> &str {
+        "format nu"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("format nu")
+            .input_output_types(vec![(Type::String, Type::String)])
+            .switch(
+                "check",
+                "Error instead of producing output if the input is not already formatted",
+                Some('c'),
+            )
+            .category(Category::Strings)
+    }
+
+    fn description(&self) -> &str {
+        "Format nushell source code."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Each line is re-indented (4 spaces per block/list/paren level) based on the parsed
+structure, pipeline continuation lines starting with `|` get one extra level, and a
+redundant comma directly before a line break is dropped. Trailing whitespace is
+removed, runs of more than two blank lines are collapsed, and the source ends with
+exactly one trailing newline. Lines that overlap multi-line string literals are left
+alone, so those round-trip unchanged.
+
+With `--check`, no output is produced; instead an error is raised if formatting
+would change the input, which is useful in CI."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["fmt", "indent", "style"]
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                example: r#""ls   \n\n\n\nls" | format nu"#,
+                description: "Clean up the whitespace in a piece of nushell source code.",
+                result: Some(Value::test_string("ls\n\n\nls\n")),
+            },
+            Example {
+                example: r#""def foo [] {\nls |\n| get name\n}" | format nu"#,
+                description: "Re-indent a definition from its parsed structure.",
+                result: Some(Value::test_string(
+                    "def foo [] {\n    ls |\n        | get name\n}\n",
+                )),
+            },
+            Example {
+                example: "open --raw script.nu | format nu --check",
+                description: "Fail (e.g. in CI) if a script is not formatted.",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let check = call.has_flag(engine_state, stack, "check")?;
+        let metadata = input.metadata();
+        let source = input.into_value(head)?.coerce_into_string()?;
+
+        let formatted = format_source(engine_state, &source);
+
+        if check {
+            if formatted == source {
+                Ok(PipelineData::empty())
+            } else {
+                Err(ShellError::GenericError {
+                    error: "Source is not formatted".into(),
+                    msg: "running `format nu` would change this input".into(),
+                    span: Some(head),
+                    help: None,
+                    inner: vec![],
+                })
+            }
+        } else {
+            Ok(Value::string(formatted, head).into_pipeline_data_with_metadata(metadata))
+        }
+    }
+}
+
+const INDENT: &str = "    ";
+
+/// Pretty-print nushell source: each line is re-indented from the parsed block/list/paren
+/// structure, pipeline continuations get one extra level, and redundant trailing commas
+/// before a line break are dropped. Trailing whitespace, blank-line runs, and the trailing
+/// newline are normalized as before.
+///
+/// Lines that overlap multi-line string literals (including interpolations) are copied
+/// through untouched, and single-line string contents are masked out of the structure scan,
+/// so the formatting can never change what a script evaluates to. As a final guard, if the
+/// result no longer parses the way the input did, the input is returned unchanged.
+fn format_source(engine_state: &EngineState, source: &str) -> String {
+    let spans = string_spans(engine_state, source);
+    let formatted = reflow(source, &spans);
+
+    // If formatting somehow changed how the source parses (e.g. syntax this formatter doesn't
+    // understand), prefer returning the input over corrupting it
+    if formatted != source && string_spans(engine_state, &formatted).len() != spans.len() {
+        return source.to_string();
+    }
+    formatted
+}
+
+/// Byte ranges (relative to the source) of every string shape in the file.
+fn string_spans(engine_state: &EngineState, source: &str) -> Vec<(usize, usize)> {
+    let mut working_set = StateWorkingSet::new(engine_state);
+    let offset = working_set.next_span_start();
+    let block = parse(&mut working_set, None, source.as_bytes(), false);
+    flatten_block(&working_set, &block)
+        .into_iter()
+        .filter(|(_, shape)| {
+            matches!(
+                shape,
+                FlatShape::String | FlatShape::StringInterpolation | FlatShape::RawString
+            )
+        })
+        .map(|(span, _)| {
+            (
+                span.start.saturating_sub(offset),
+                span.end.saturating_sub(offset),
+            )
+        })
+        .collect()
+}
+
+fn reflow(source: &str, string_spans: &[(usize, usize)]) -> String {
+    let mut line_start = 0;
+    let mut blank_run = 0;
+    let mut depth: usize = 0;
+    let mut output = String::with_capacity(source.len());
+    for line in source.split_inclusive('\n') {
+        let line_end = line_start + line.len();
+        // Strings fully inside the line are masked by `scan_line`; only strings that cross a
+        // line boundary force the whole line through untouched
+        let in_multiline_string = string_spans.iter().any(|&(start, end)| {
+            start < line_end && end > line_start && (start < line_start || end > line_end)
+        });
+
+        if in_multiline_string {
+            // Multi-line strings (and lines sharing them) round-trip byte for byte
+            output.push_str(line);
+            blank_run = 0;
+            line_start = line_end;
+            continue;
+        }
+
+        let has_newline = line.ends_with('\n');
+        let body = line.trim();
+
+        if body.is_empty() {
+            blank_run += 1;
+            // Keep at most two consecutive blank lines
+            if blank_run <= 2 && has_newline {
+                output.push('\n');
+            }
+            line_start = line_end;
+            continue;
+        }
+        blank_run = 0;
+
+        // Structure of this line, with single-line string contents and comments masked out
+        let body_offset = line_start + (line.len() - line.trim_start().len());
+        let scan = scan_line(body, body_offset, string_spans);
+
+        // Closing brackets at the start of the line dedent it, and a leading `|` marks a
+        // pipeline continuation, which gets one extra level
+        let line_depth = depth.saturating_sub(scan.leading_closers)
+            + usize::from(body.starts_with('|') && !body.starts_with("||"));
+        for _ in 0..line_depth {
+            output.push_str(INDENT);
+        }
+
+        // A comma directly before the line break is redundant: the newline already
+        // separates list/record entries
+        if scan.removable_trailing_comma {
+            output.push_str(body[..body.len() - 1].trim_end());
+        } else {
+            output.push_str(body);
+        }
+        if has_newline {
+            output.push('\n');
+        }
+
+        depth = (depth as i64 + scan.depth_change).max(0) as usize;
+        line_start = line_end;
+    }
+
+    // End with exactly one newline
+    let trailing = output.trim_end_matches('\n').len();
+    output.truncate(trailing);
+    if !output.is_empty() {
+        output.push('\n');
+    }
+    output
+}
+
+struct LineScan {
+    /// Net bracket depth change over the line
+    depth_change: i64,
+    /// Closing brackets before anything else on the line
+    leading_closers: usize,
+    /// Whether the line ends with a `,` that is outside strings and comments
+    removable_trailing_comma: bool,
+}
+
+/// Scan one line's structure, skipping string spans and `#` comments.
+fn scan_line(body: &str, body_offset: usize, string_spans: &[(usize, usize)]) -> LineScan {
+    let mut scan = LineScan {
+        depth_change: 0,
+        leading_closers: 0,
+        removable_trailing_comma: false,
+    };
+    let mut only_closers_so_far = true;
+    let mut previous = None;
+    for (idx, c) in body.char_indices() {
+        let position = body_offset + idx;
+        if string_spans
+            .iter()
+            .any(|&(start, end)| position >= start && position < end)
+        {
+            scan.removable_trailing_comma = false;
+            previous = Some(c);
+            continue;
+        }
+        // `#` starts a comment at a token boundary (`foo#bar` is one bare word)
+        if c == '#'
+            && previous
+                .is_none_or(|p| p.is_whitespace() || matches!(p, '(' | '[' | '{' | '|' | ';'))
+        {
+            return scan;
+        }
+        match c {
+            '(' | '[' | '{' => {
+                scan.depth_change += 1;
+                only_closers_so_far = false;
+            }
+            ')' | ']' | '}' => {
+                scan.depth_change -= 1;
+                if only_closers_so_far {
+                    scan.leading_closers += 1;
+                }
+            }
+            _ if c.is_whitespace() => {}
+            _ => only_closers_so_far = false,
+        }
+        scan.removable_trailing_comma = c == ',';
+        previous = Some(c);
+    }
+    scan
+}
@@ -9,8 +9,8 @@ mod split;
 mod str_;
 
 pub use base::{
-    DecodeBase32, DecodeBase32Hex, DecodeBase64, DecodeHex, EncodeBase32, EncodeBase32Hex,
-    EncodeBase64, EncodeHex,
+    DecodeAscii85, DecodeBase32, DecodeBase32Hex, DecodeBase64, DecodeBrotli, DecodeHex,
+    EncodeAscii85, EncodeBase32, EncodeBase32Hex, EncodeBase64, EncodeBrotli, EncodeHex,
 };
 pub use char_::Char;
 pub use detect_columns::*;
@@ -1,5 +1,6 @@
 use fancy_regex::{escape, Regex};
 use nu_engine::command_prelude::*;
+use nu_protocol::ListStream;
 
 #[derive(Clone)]
 pub struct SplitRow;
@@ -167,12 +168,59 @@ fn split_row(
         help: None,
         inner: vec![],
     })?;
+    // A single large string is the common worst case (`open big.log | split row "\n"`):
+    // yield its pieces lazily instead of materializing all of them up front, so peak memory
+    // stays at the source buffer plus one piece, and a downstream `first`/`take` stops the
+    // copying early.
+    if let PipelineData::Value(Value::String { val, internal_span }, metadata) = input {
+        let ranges =
+            piece_ranges(&val, &regex, args.max_split).map_err(|e| ShellError::GenericError {
+                error: "Error with regular expression".into(),
+                msg: e.to_string(),
+                span: Some(internal_span),
+                help: None,
+                inner: vec![],
+            })?;
+        let pieces = ranges
+            .into_iter()
+            .map(move |(start, end)| Value::string(&val[start..end], internal_span));
+        return Ok(PipelineData::ListStream(
+            ListStream::new(pieces, name_span, engine_state.signals().clone()),
+            metadata,
+        ));
+    }
+
     input.flat_map(
         move |x| split_row_helper(&x, &regex, args.max_split, name_span),
         engine_state.signals(),
     )
 }
 
+/// The byte ranges of the pieces `regex.split` / `regex.splitn` would produce, so the pieces
+/// themselves can be allocated one at a time.
+fn piece_ranges(
+    s: &str,
+    regex: &Regex,
+    max_split: Option<usize>,
+) -> Result<Vec<(usize, usize)>, fancy_regex::Error> {
+    let mut ranges = Vec::new();
+    if max_split == Some(0) {
+        // splitn with a limit of zero produces no pieces at all
+        return Ok(ranges);
+    }
+    let mut start = 0;
+    for separator in regex.find_iter(s) {
+        if max_split.is_some_and(|max| ranges.len() + 1 >= max) {
+            break;
+        }
+        let separator = separator?;
+        ranges.push((start, separator.start()));
+        start = separator.end();
+    }
+    ranges.push((start, s.len()));
+    Ok(ranges)
+}
+
 fn split_row_helper(v: &Value, regex: &Regex, max_split: Option<usize>, name: Span) -> Vec<Value> {
     let span = v.span();
     match v {
@@ -1,5 +1,11 @@
 use nu_engine::command_prelude::*;
-use nu_protocol::OutDest;
+use nu_protocol::{process::ChildPipe, OutDest};
+use std::{
+    io::{BufRead, BufReader, Read},
+    sync::{Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
 
 #[derive(Clone)]
 pub struct Complete;
@@ -13,6 +19,11 @@ impl Command for Complete {
         Signature::build("complete")
             .category(Category::System)
             .input_output_types(vec![(Type::Any, Type::record())])
+            .switch(
+                "combined",
+                "Merge stdout and stderr into one chronological stream, with each line tagged with its source and elapsed time",
+                Some('c'),
+            )
     }
 
     fn description(&self) -> &str {
@@ -25,12 +36,13 @@ impl Command for Complete {
 
     fn run(
         &self,
-        _engine_state: &EngineState,
-        _stack: &mut Stack,
+        engine_state: &EngineState,
+        stack: &mut Stack,
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let head = call.head;
+        let combined = call.has_flag(engine_state, stack, "combined")?;
         match input {
             PipelineData::ByteStream(stream, ..) => {
                 let Ok(child) = stream.into_child() else {
@@ -43,6 +55,10 @@ impl Command for Complete {
                     });
                 };
 
+                if combined {
+                    return combined_output(child, head);
+                }
+
                 let output = child.wait_with_output()?;
                 let exit_code = output.exit_status.code();
                 let mut record = Record::new();
@@ -96,3 +112,94 @@ impl Command for Complete {
         (Some(OutDest::PipeSeparate), Some(OutDest::PipeSeparate))
     }
 }
+
+/// A captured line: when it arrived, which stream it came from, and its content.
+type CombinedEntry = (Duration, &'static str, String);
+
+/// Read the child's stdout and stderr concurrently, tagging each line with its source and the
+/// elapsed time since capture started, and merge them into one chronological table.
+fn combined_output(
+    mut child: nu_protocol::process::ChildProcess,
+    head: Span,
+) -> Result<PipelineData, ShellError> {
+    let start = Instant::now();
+    let entries: Arc<Mutex<Vec<CombinedEntry>>> = Arc::new(Mutex::new(Vec::new()));
+
+    let mut readers: Vec<(&'static str, Box<dyn Read + Send>)> = Vec::new();
+    if let Some(stdout) = child.stdout.take() {
+        readers.push((
+            "stdout",
+            match stdout {
+                ChildPipe::Pipe(pipe) => Box::new(pipe),
+                ChildPipe::Tee(tee) => tee,
+            },
+        ));
+    }
+    if let Some(stderr) = child.stderr.take() {
+        readers.push((
+            "stderr",
+            match stderr {
+                ChildPipe::Pipe(pipe) => Box::new(pipe),
+                ChildPipe::Tee(tee) => tee,
+            },
+        ));
+    }
+
+    let mut handles = Vec::new();
+    for (source, reader) in readers {
+        let entries = Arc::clone(&entries);
+        let handle = thread::Builder::new()
+            .name(format!("complete --combined {source} reader"))
+            .spawn(move || {
+                for line in BufReader::new(reader).lines() {
+                    let Ok(line) = line else { break };
+                    if let Ok(mut entries) = entries.lock() {
+                        entries.push((start.elapsed(), source, line));
+                    }
+                }
+            })
+            .map_err(|err| {
+                ShellError::Io(nu_protocol::shell_error::io::IoError::new(
+                    err.kind(),
+                    head,
+                    None,
+                ))
+            })?;
+        handles.push(handle);
+    }
+    for handle in handles {
+        let _ = handle.join();
+    }
+
+    // The pipes were taken above, so this only waits for the exit status
+    let output = child.wait_with_output()?;
+    let exit_code = output.exit_status.code();
+
+    let mut entries = Arc::try_unwrap(entries)
+        .map(|entries| entries.into_inner().unwrap_or_default())
+        .unwrap_or_default();
+    entries.sort_by_key(|(elapsed, ..)| *elapsed);
+
+    let lines = entries
+        .into_iter()
+        .map(|(elapsed, source, line)| {
+            Value::record(
+                record! {
+                    "stream" => Value::string(source, head),
+                    "elapsed" => Value::duration(elapsed.as_nanos().try_into().unwrap_or(i64::MAX), head),
+                    "line" => Value::string(line, head),
+                },
+                head,
+            )
+        })
+        .collect();
+
+    Ok(Value::record(
+        record! {
+            "combined" => Value::list(lines, head),
+            "exit_code" => Value::int(exit_code.into(), head),
+        },
+        head,
+    )
+    .into_pipeline_data())
+}
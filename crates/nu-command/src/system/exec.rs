@@ -18,6 +18,16 @@ impl Command for Exec {
                 SyntaxShape::OneOf(vec![SyntaxShape::GlobPattern, SyntaxShape::Any]),
                 "External command to run, with arguments.",
             )
+            .switch(
+                "login",
+                "Run the command as a login process (prepends '-' to its argv[0] on Unix)",
+                None,
+            )
+            .switch(
+                "clean-env",
+                "Don't pass the current environment to the command",
+                None,
+            )
             .allows_unknown_args()
             .category(Category::System)
     }
@@ -38,7 +48,10 @@ On Windows based systems, Nushell will wait for the command to finish and then e
         call: &Call,
         _input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
+        crate::reject_flags_after_command(call, stack)?;
         let cwd = engine_state.cwd(Some(stack))?;
+        let login = call.has_flag(engine_state, stack, "login")?;
+        let clean_env = call.has_flag(engine_state, stack, "clean-env")?;
         let rest = call.rest::<Value>(engine_state, stack, 0)?;
         let name_args = rest.split_first();
 
@@ -62,6 +75,7 @@ On Windows based systems, Nushell will wait for the command to finish and then e
             let Some(executable) = crate::which(name_str.as_ref(), &paths, cwd.as_ref()) else {
                 return Err(crate::command_not_found(
                     &name_str,
+                    call_args,
                     call.head,
                     engine_state,
                     stack,
@@ -78,12 +92,14 @@ On Windows based systems, Nushell will wait for the command to finish and then e
         command.current_dir(cwd);
 
         // Configure environment variables.
-        let envs = env_to_strings(engine_state, stack)?;
         command.env_clear();
-        command.envs(envs);
+        if !clean_env {
+            let envs = env_to_strings(engine_state, stack)?;
+            command.envs(envs);
+        }
         // Decrement SHLVL as removing the current shell from the stack
         // (only works in interactive mode, same as initialization)
-        if engine_state.is_interactive {
+        if !clean_env && engine_state.is_interactive {
             let shlvl = engine_state
                 .get_env_var("SHLVL")
                 .and_then(|shlvl_env| shlvl_env.coerce_str().ok()?.parse::<i64>().ok())
@@ -102,6 +118,18 @@ On Windows based systems, Nushell will wait for the command to finish and then e
         {
             use std::os::unix::process::CommandExt;
 
+            // Login processes announce themselves with a leading '-' in argv[0]
+            if login {
+                let arg0 = format!(
+                    "-{}",
+                    std::path::Path::new(name_str.as_ref())
+                        .file_name()
+                        .map(|name| name.to_string_lossy())
+                        .unwrap_or(name_str.clone())
+                );
+                command.arg0(arg0);
+            }
+
             let err = command.exec();
             Err(ShellError::ExternalCommand {
                 label: "Failed to exec into new process".into(),
@@ -111,6 +139,8 @@ On Windows based systems, Nushell will wait for the command to finish and then e
         }
         #[cfg(windows)]
         {
+            // Windows has no login-process convention; the flag only affects Unix
+            let _ = login;
             let mut child = command.spawn().map_err(|err| ShellError::ExternalCommand {
                 label: "Failed to exec into new process".into(),
                 help: err.to_string(),
@@ -33,7 +33,9 @@ pub use nu_check::NuCheck;
 pub use ps::Ps;
 #[cfg(windows)]
 pub use registry_query::RegistryQuery;
-pub use run_external::{command_not_found, eval_external_arguments, which, External};
+pub use run_external::{
+    command_not_found, eval_external_arguments, reject_flags_after_command, which, External,
+};
 pub use sys::*;
 pub use uname::UName;
 pub use which_::Which;
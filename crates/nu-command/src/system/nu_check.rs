@@ -1,10 +1,15 @@
 use nu_engine::{command_prelude::*, find_in_dirs_env, get_dirs_var_from_call};
 use nu_parser::{parse, parse_module_block, parse_module_file_or_dir, unescape_unquote_string};
 use nu_protocol::{
+    ast::{Expr, Expression, Traverse},
     engine::{FileStack, StateWorkingSet},
     shell_error::io::IoError,
+    ParseWarning, VarId,
+};
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
 };
-use std::path::{Path, PathBuf};
 
 #[derive(Clone)]
 pub struct NuCheck;
@@ -16,18 +21,25 @@ impl Command for NuCheck {
 
     fn signature(&self) -> Signature {
         Signature::build("nu-check")
+            // The output is a bool, or a table of findings with `--lint`; they are typed as Any
+            // because the output type can't depend on a flag at parse time
             .input_output_types(vec![
-                (Type::Nothing, Type::Bool),
-                (Type::String, Type::Bool),
-                (Type::List(Box::new(Type::Any)), Type::Bool),
+                (Type::Nothing, Type::Any),
+                (Type::String, Type::Any),
+                (Type::List(Box::new(Type::Any)), Type::Any),
                 // FIXME Type::Any input added to disable pipeline input type checking, as run-time checks can raise undesirable type errors
                 // which aren't caught by the parser. see https://github.com/nushell/nushell/pull/14922 for more details
-                (Type::Any, Type::Bool),
+                (Type::Any, Type::Any),
             ])
             // type is string to avoid automatically canonicalizing the path
             .optional("path", SyntaxShape::String, "File path to parse.")
             .switch("as-module", "Parse content as module", Some('m'))
             .switch("debug", "Show error messages", Some('d'))
+            .switch(
+                "lint",
+                "Return a table of lint findings (parse errors, deprecations, unused variables) instead of a bool. The input is always parsed as a script",
+                None,
+            )
             .category(Category::Strings)
     }
 
@@ -49,6 +61,32 @@ impl Command for NuCheck {
         let path_arg: Option<Spanned<String>> = call.opt(engine_state, stack, 0)?;
         let as_module = call.has_flag(engine_state, stack, "as-module")?;
         let is_debug = call.has_flag(engine_state, stack, "debug")?;
+        let lint = call.has_flag(engine_state, stack, "lint")?;
+
+        if lint {
+            let contents: Vec<u8> = match input {
+                PipelineData::Value(Value::String { val, .. }, ..) => val.into_bytes(),
+                PipelineData::ListStream(stream, ..) => {
+                    let config = stack.get_config(engine_state);
+                    Vec::from(stream.into_string("\n", &config))
+                }
+                PipelineData::ByteStream(stream, ..) => stream.into_bytes()?,
+                _ => {
+                    let Some(path_str) = &path_arg else {
+                        return Err(ShellError::MissingParameter {
+                            param_name: "path".into(),
+                            span: call.head,
+                        });
+                    };
+                    let cwd = engine_state.cwd(Some(stack))?;
+                    let path = nu_path::expand_path_with(&path_str.item, cwd, true);
+                    std::fs::read(&path)
+                        .map_err(|err| IoError::new(err.kind(), path_str.span, path))?
+                }
+            };
+            let findings = lint_source(engine_state, &contents, call.head);
+            return Ok(Value::list(findings, call.head).into_pipeline_data());
+        }
 
         // DO NOT ever try to merge the working_set in this command
         let mut working_set = StateWorkingSet::new(engine_state);
@@ -317,3 +355,102 @@ fn check_path(
         Ok(filename)
     }
 }
+
+/// Parse the source and collect lint findings: parse errors, parse-time warnings (e.g.
+/// deprecated command usage), and variables or parameters that are declared but never used.
+fn lint_source(engine_state: &EngineState, contents: &[u8], head: Span) -> Vec<Value> {
+    // DO NOT ever try to merge the working_set in this command
+    let mut working_set = StateWorkingSet::new(engine_state);
+    let offset = working_set.next_span_start();
+    let block = parse(&mut working_set, None, contents, false);
+
+    let mut findings = Vec::new();
+
+    for error in &working_set.parse_errors {
+        findings.push(lint_record(
+            "error",
+            error.to_string(),
+            error.span(),
+            offset,
+            head,
+        ));
+    }
+
+    for warning in &working_set.parse_warnings {
+        let ParseWarning::DeprecatedWarning {
+            old_command,
+            new_suggestion,
+            span,
+            ..
+        } = warning;
+        findings.push(lint_record(
+            "deprecated",
+            format!("`{old_command}` is deprecated, {new_suggestion} instead"),
+            *span,
+            offset,
+            head,
+        ));
+    }
+
+    // Every variable used anywhere in the parsed blocks; nested blocks (closures, bodies of
+    // custom commands) are visited by the traversal
+    let used: HashSet<VarId> = block
+        .flat_map(&working_set, &|expr: &Expression| match expr.expr {
+            Expr::Var(var_id) => Some(vec![var_id]),
+            _ => None,
+        })
+        .into_iter()
+        .collect();
+
+    // Group declarations by span: the parser can register the same source-level variable more
+    // than once (e.g. parameters during predeclaration), and the variable counts as used if any
+    // of its registrations is used
+    let mut declarations: Vec<(Span, bool)> = Vec::new();
+    for var_id in engine_state.num_vars()..working_set.num_vars() {
+        let var_id = VarId::new(var_id);
+        let span = working_set.get_variable(var_id).declaration_span;
+        let is_used = used.contains(&var_id);
+        match declarations.iter_mut().find(|(other, _)| *other == span) {
+            Some((_, any_used)) => *any_used |= is_used,
+            None => declarations.push((span, is_used)),
+        }
+    }
+    for (span, is_used) in declarations {
+        if is_used {
+            continue;
+        }
+        let name = String::from_utf8_lossy(working_set.get_span_contents(span)).to_string();
+        let name = name.trim_start_matches('$');
+        // Leading underscores mark intentionally unused variables, like in Rust
+        if name.is_empty() || name.starts_with('_') {
+            continue;
+        }
+        findings.push(lint_record(
+            "unused_variable",
+            format!("`{name}` is declared but never used"),
+            span,
+            offset,
+            head,
+        ));
+    }
+
+    findings.sort_by_key(|finding| {
+        finding
+            .get_data_by_key("start")
+            .and_then(|value| value.as_int().ok())
+            .unwrap_or_default()
+    });
+    findings
+}
+
+fn lint_record(kind: &str, message: String, span: Span, offset: usize, head: Span) -> Value {
+    Value::record(
+        record! {
+            "type" => Value::string(kind, head),
+            "message" => Value::string(message, head),
+            "start" => Value::int(span.start.saturating_sub(offset) as i64, head),
+            "end" => Value::int(span.end.saturating_sub(offset) as i64, head),
+        },
+        head,
+    )
+}
@@ -1,6 +1,6 @@
 use nu_cmd_base::hook::eval_hook;
 use nu_engine::{command_prelude::*, env_to_strings};
-use nu_path::{dots::expand_ndots_safe, expand_tilde, AbsolutePath};
+use nu_path::{dots::expand_ndots_safe, expand_tilde, AbsolutePath, AbsolutePathBuf};
 use nu_protocol::{
     did_you_mean,
     engine::{FrozenJob, Job},
@@ -43,9 +43,42 @@ impl Command for External {
                 SyntaxShape::OneOf(vec![SyntaxShape::GlobPattern, SyntaxShape::Any]),
                 "External command to run, with arguments.",
             )
+            .named(
+                "cwd",
+                SyntaxShape::Directory,
+                "Run the command in this directory instead of the current one",
+                None,
+            )
+            .named(
+                "env",
+                SyntaxShape::Record(vec![]),
+                "Additional environment variables to pass to the command",
+                None,
+            )
+            .named(
+                "limit-cpu",
+                SyntaxShape::Duration,
+                "Limit the command's CPU time (Unix only, via RLIMIT_CPU)",
+                None,
+            )
+            .named(
+                "limit-memory",
+                SyntaxShape::Filesize,
+                "Limit the command's address space (Unix only, via RLIMIT_AS)",
+                None,
+            )
             .category(Category::System)
     }
 
+    fn extra_description(&self) -> &str {
+        r#"Arguments given as variables or a spread list are passed to the command exactly as-is,
+with no re-tokenization or glob expansion, so invocations can be built programmatically:
+
+    run-external $cmd ...$args
+
+Pipe through `complete` to capture stdout, stderr, and the exit code as a record."#
+    }
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -53,7 +86,48 @@ impl Command for External {
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
-        let cwd = engine_state.cwd(Some(stack))?;
+        reject_flags_after_command(call, stack)?;
+        let cwd = match call.get_flag::<Spanned<String>>(engine_state, stack, "cwd")? {
+            Some(dir) => {
+                let base = engine_state.cwd(Some(stack))?;
+                let expanded = nu_path::expand_path_with(&dir.item, &base, true);
+                AbsolutePathBuf::try_from(expanded).map_err(|_| ShellError::GenericError {
+                    error: "Invalid value for `--cwd`".into(),
+                    msg: "not an absolute path after expansion".into(),
+                    span: Some(dir.span),
+                    help: None,
+                    inner: vec![],
+                })?
+            }
+            None => engine_state.cwd(Some(stack))?,
+        };
+        let extra_env: Option<Record> = call.get_flag(engine_state, stack, "env")?;
+        let limit_cpu = call
+            .get_flag::<Value>(engine_state, stack, "limit-cpu")?
+            .map(|value| {
+                value
+                    .as_duration()
+                    .map(|nanos| nanos.into_spanned(value.span()))
+            })
+            .transpose()?;
+        let limit_memory = call
+            .get_flag::<Value>(engine_state, stack, "limit-memory")?
+            .map(|value| {
+                value
+                    .as_filesize()
+                    .map(|size| size.get().into_spanned(value.span()))
+            })
+            .transpose()?;
+        #[cfg(not(unix))]
+        if let Some(spanned) = limit_cpu.as_ref().or(limit_memory.as_ref()) {
+            return Err(ShellError::GenericError {
+                error: "Resource limits are only supported on Unix".into(),
+                msg: "this platform can't apply the limit".into(),
+                span: Some(spanned.span),
+                help: None,
+                inner: vec![],
+            });
+        }
         let rest = call.rest::<Value>(engine_state, stack, 0)?;
         let name_args = rest.split_first();
 
@@ -138,6 +212,7 @@ impl Command for External {
             let Some(executable) = which(&expanded_name, &paths, cwd.as_ref()) else {
                 return Err(command_not_found(
                     &name_str,
+                    call_args,
                     call.head,
                     engine_state,
                     stack,
@@ -157,6 +232,35 @@ impl Command for External {
         let envs = env_to_strings(engine_state, stack)?;
         command.env_clear();
         command.envs(envs);
+        if let Some(extra_env) = extra_env {
+            for (var, value) in extra_env {
+                command.env(var, value.coerce_string()?);
+            }
+        }
+
+        // Apply resource limits to the child before it executes
+        #[cfg(unix)]
+        if limit_cpu.is_some() || limit_memory.is_some() {
+            use nix::sys::resource::{setrlimit, Resource};
+            use std::os::unix::process::CommandExt;
+
+            let cpu_seconds =
+                limit_cpu.map(|limit| (limit.item.max(0) as u64).div_ceil(1_000_000_000));
+            let memory_bytes = limit_memory.map(|limit| limit.item.max(0) as u64);
+            unsafe {
+                command.pre_exec(move || {
+                    if let Some(seconds) = cpu_seconds {
+                        setrlimit(Resource::RLIMIT_CPU, seconds, seconds)
+                            .map_err(std::io::Error::from)?;
+                    }
+                    if let Some(bytes) = memory_bytes {
+                        setrlimit(Resource::RLIMIT_AS, bytes, bytes)
+                            .map_err(std::io::Error::from)?;
+                    }
+                    Ok(())
+                });
+            }
+        }
 
         // Configure args.
         let args = eval_external_arguments(engine_state, stack, call_args.to_vec())?;
@@ -373,6 +477,86 @@ impl Command for External {
     }
 }
 
+/// Reject this command's own flags when they appear after the first positional argument.
+///
+/// The parser matches declared flags wherever they occur in a call, so in `exec bash --login`
+/// or `run-external grep --env FOO .` the flag would be applied to the nushell command and
+/// silently never reach the external. Requiring the command's options to come before the
+/// command name keeps that mistake loud; a quoted flag (`"--login"`) still passes through to
+/// the external literally.
+pub fn reject_flags_after_command(call: &Call, stack: &Stack) -> Result<(), ShellError> {
+    use nu_protocol::engine::CallImpl;
+
+    // (flag name if named, span), in call order
+    let mut arguments: Vec<(Option<String>, Span)> = Vec::new();
+    match &call.inner {
+        CallImpl::AstRef(call) => collect_ast_arguments(call, &mut arguments),
+        CallImpl::AstBox(call) => collect_ast_arguments(call, &mut arguments),
+        CallImpl::IrRef(call) => collect_ir_arguments(call, stack, &mut arguments),
+        CallImpl::IrBox(call) => collect_ir_arguments(call, stack, &mut arguments),
+    }
+
+    let mut seen_positional = false;
+    for (name, span) in arguments {
+        match name {
+            Some(name) if seen_positional => {
+                return Err(ShellError::GenericError {
+                    error: format!("`--{name}` must come before the command name"),
+                    msg: "this flag belongs to the calling command, not the external".into(),
+                    span: Some(span),
+                    help: Some(format!(
+                        "move it before the command name, or quote it (`\"--{name}\"`) to pass it to the external command"
+                    )),
+                    inner: vec![],
+                });
+            }
+            Some(_) => (),
+            None => seen_positional = true,
+        }
+    }
+    Ok(())
+}
+
+fn collect_ast_arguments(
+    call: &nu_protocol::ast::Call,
+    arguments: &mut Vec<(Option<String>, Span)>,
+) {
+    for argument in &call.arguments {
+        match argument {
+            nu_protocol::ast::Argument::Named((name, ..)) => {
+                arguments.push((Some(name.item.clone()), argument.span()))
+            }
+            _ => arguments.push((None, argument.span())),
+        }
+    }
+}
+
+fn collect_ir_arguments(
+    call: &nu_protocol::ir::Call,
+    stack: &Stack,
+    arguments: &mut Vec<(Option<String>, Span)>,
+) {
+    for (name, value) in call
+        .arguments(stack)
+        .iter()
+        .map(|argument| (argument_flag_name(argument), argument.span()))
+    {
+        if let Some(span) = value {
+            arguments.push((name, span));
+        }
+    }
+}
+
+fn argument_flag_name(argument: &nu_protocol::engine::Argument) -> Option<String> {
+    match argument {
+        nu_protocol::engine::Argument::Flag { data, name, .. }
+        | nu_protocol::engine::Argument::Named { data, name, .. } => {
+            Some(String::from_utf8_lossy(&data[*name]).into_owned())
+        }
+        _ => None,
+    }
+}
+
 /// Evaluate all arguments, performing expansions when necessary.
 pub fn eval_external_arguments(
     engine_state: &EngineState,
@@ -534,6 +718,7 @@ fn write_pipeline_data(
 /// Returns a helpful error message given an invalid command name,
 pub fn command_not_found(
     name: &str,
+    args: &[Value],
     span: Span,
     engine_state: &EngineState,
     stack: &mut Stack,
@@ -557,11 +742,23 @@ pub fn command_not_found(
         }
         stack.add_env_var(canary.into(), Value::bool(true, Span::unknown()));
 
+        let cmd_args = args
+            .iter()
+            .map(|arg| {
+                arg.clone()
+                    .coerce_into_string()
+                    .map(|arg| Value::string(arg, span))
+                    .unwrap_or_else(|_| arg.clone())
+            })
+            .collect();
         let output = eval_hook(
             &mut engine_state.clone(),
             &mut stack,
             None,
-            vec![("cmd_name".into(), Value::string(name, span))],
+            vec![
+                ("cmd_name".into(), Value::string(name, span)),
+                ("cmd_args".into(), Value::list(cmd_args, span)),
+            ],
             hook,
             "command_not_found",
         );
@@ -59,6 +59,7 @@ fn entry(
     arg: impl Into<String>,
     path: impl Into<String>,
     cmd_type: CommandType,
+    expansion: impl Into<String>,
     span: Span,
 ) -> Value {
     Value::record(
@@ -66,6 +67,7 @@ fn entry(
             "command" => Value::string(arg, span),
             "path" => Value::string(path, span),
             "type" => Value::string(cmd_type.to_string(), span),
+            "expansion" => Value::string(expansion, span),
         },
         span,
     )
@@ -74,7 +76,16 @@ fn entry(
 fn get_entry_in_commands(engine_state: &EngineState, name: &str, span: Span) -> Option<Value> {
     if let Some(decl_id) = engine_state.find_decl(name.as_bytes(), &[]) {
         let decl = engine_state.get_decl(decl_id);
-        Some(entry(name, "", decl.command_type(), span))
+        // For aliases, also show what the name expands to, since that's usually what the
+        // user is trying to figure out
+        let expansion = decl
+            .as_alias()
+            .map(|alias| {
+                String::from_utf8_lossy(engine_state.get_span_contents(alias.wrapped_call.span))
+                    .to_string()
+            })
+            .unwrap_or_default();
+        Some(entry(name, "", decl.command_type(), expansion, span))
     } else {
         None
     }
@@ -106,7 +117,15 @@ fn get_first_entry_in_path(
     paths: impl AsRef<OsStr>,
 ) -> Option<Value> {
     which::which_in(item, Some(paths), cwd)
-        .map(|path| entry(item, path.to_string_lossy(), CommandType::External, span))
+        .map(|path| {
+            entry(
+                item,
+                path.to_string_lossy(),
+                CommandType::External,
+                "",
+                span,
+            )
+        })
         .ok()
 }
 
@@ -118,8 +137,16 @@ fn get_all_entries_in_path(
 ) -> Vec<Value> {
     which::which_in_all(&item, Some(paths), cwd)
         .map(|iter| {
-            iter.map(|path| entry(item, path.to_string_lossy(), CommandType::External, span))
-                .collect()
+            iter.map(|path| {
+                entry(
+                    item,
+                    path.to_string_lossy(),
+                    CommandType::External,
+                    "",
+                    span,
+                )
+            })
+            .collect()
         })
         .unwrap_or_default()
 }
@@ -100,6 +100,11 @@ impl Command for Table {
                 "abbreviate the data in the table by truncating the middle part and only showing amount provided on top and bottom",
                 Some('a'),
             )
+            .switch(
+                "full",
+                "show every row, overriding the configured abbreviated_row_count",
+                Some('f'),
+            )
             .switch("list", "list available table modes/themes", Some('l'))
             .category(Category::Viewers)
     }
@@ -304,9 +309,12 @@ fn get_cli_args(call: &Call<'_>, state: &EngineState, stack: &mut Stack) -> Shel
     let expand_flatten_separator: Option<String> =
         call.get_flag(state, stack, "flatten-separator")?;
     let collapse: bool = call.has_flag(state, stack, "collapse")?;
-    let abbrivation: Option<usize> = call
-        .get_flag(state, stack, "abbreviated")?
-        .or_else(|| stack.get_config(state).table.abbreviated_row_count);
+    let abbrivation: Option<usize> = if call.has_flag(state, stack, "full")? {
+        None
+    } else {
+        call.get_flag(state, stack, "abbreviated")?
+            .or_else(|| stack.get_config(state).table.abbreviated_row_count)
+    };
     let theme =
         get_theme_flag(call, state, stack)?.unwrap_or_else(|| stack.get_config(state).table.mode);
     let index = get_index_flag(call, state, stack)?;
@@ -58,6 +58,20 @@ fn summarizes_by_values() {
     assert_eq!(actual.out, "2");
 }
 
+#[test]
+fn binned_rows_are_ordered_by_range_for_named_columns() {
+    let actual = nu!(pipeline(
+        "
+            echo [[size]; [1] [9] [9] [10]]
+            | histogram size --bins 2
+            | get size
+            | to json --raw
+        "
+    ));
+
+    assert_eq!(actual.out, r#"["1.00 ..< 5.50","5.50 ..< 10.00"]"#);
+}
+
 #[test]
 fn help() {
     let help_command = nu!("help histogram");
@@ -0,0 +1,156 @@
+//! A C-compatible embedding interface for the Nushell evaluator.
+//!
+//! This crate builds as a `cdylib` so non-Rust applications (and, with a `wasm32-wasip1`
+//! toolchain, web playgrounds) can evaluate nushell pipelines. Data crosses the boundary as
+//! JSON: `nu_embedded_eval` returns a JSON document of either `{"ok": <value>}` or
+//! `{"error": <message>}`.
+//!
+//! ```c
+//! NuEngine *engine = nu_embedded_new();
+//! char *result = nu_embedded_eval(engine, "1 + 2 * 3");   // {"ok":7}
+//! nu_embedded_string_free(result);
+//! nu_embedded_free(engine);
+//! ```
+//!
+//! Only the core language commands are available; filesystem and system commands live in
+//! higher-level crates that embedders can merge into the engine state from Rust via
+//! [`nu_cmd_lang::embedding::Engine`], which this crate wraps.
+
+use std::ffi::{c_char, CStr, CString};
+
+use nu_cmd_lang::embedding::Engine;
+use nu_protocol::{Record, Value};
+
+/// An opaque engine handle for the C API.
+pub struct NuEngine(Engine);
+
+/// Create an engine. Free it with [`nu_embedded_free`].
+#[no_mangle]
+pub extern "C" fn nu_embedded_new() -> *mut NuEngine {
+    Box::into_raw(Box::new(NuEngine(Engine::new())))
+}
+
+/// Free an engine created with [`nu_embedded_new`].
+///
+/// # Safety
+/// `engine` must be a pointer returned from [`nu_embedded_new`] that has not been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn nu_embedded_free(engine: *mut NuEngine) {
+    if !engine.is_null() {
+        drop(unsafe { Box::from_raw(engine) });
+    }
+}
+
+/// Evaluate a nushell snippet and return the result as a JSON string: either
+/// `{"ok": <value>}` or `{"error": <message>}`. Free the result with
+/// [`nu_embedded_string_free`].
+///
+/// # Safety
+/// `engine` must be a live pointer from [`nu_embedded_new`] and `source` a valid
+/// NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn nu_embedded_eval(
+    engine: *mut NuEngine,
+    source: *const c_char,
+) -> *mut c_char {
+    let result = (|| {
+        let engine = unsafe { engine.as_mut() }.ok_or("engine is null")?;
+        let source = unsafe { CStr::from_ptr(source) }
+            .to_str()
+            .map_err(|_| "source is not valid UTF-8".to_string())?;
+        engine
+            .0
+            .eval(source)
+            .map(|value| value_to_json(&value))
+            .map_err(|err| err.to_string())
+    })();
+
+    let json = match result {
+        Ok(value) => serde_json::json!({ "ok": value }),
+        Err(error) => serde_json::json!({ "error": error }),
+    };
+
+    let rendered = json.to_string();
+    CString::new(rendered).unwrap_or_default().into_raw()
+}
+
+/// Free a string returned by [`nu_embedded_eval`].
+///
+/// # Safety
+/// `string` must be a pointer returned from this crate that has not been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn nu_embedded_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(unsafe { CString::from_raw(string) });
+    }
+}
+
+/// Convert a nushell [`Value`] to JSON, approximating types that JSON can't represent
+/// (durations and filesizes become numbers, dates become RFC 3339 strings, binary becomes an
+/// array of bytes).
+fn value_to_json(value: &Value) -> serde_json::Value {
+    use serde_json::json;
+    match value {
+        Value::Bool { val, .. } => json!(val),
+        Value::Int { val, .. } => json!(val),
+        Value::Float { val, .. } => json!(val),
+        Value::Filesize { val, .. } => json!(val.get()),
+        Value::Duration { val, .. } => json!(val),
+        Value::Date { val, .. } => json!(val.to_rfc3339()),
+        Value::String { val, .. } | Value::Glob { val, .. } => json!(val),
+        Value::Record { val, .. } => record_to_json(val),
+        Value::List { vals, .. } => {
+            serde_json::Value::Array(vals.iter().map(value_to_json).collect())
+        }
+        Value::Binary { val, .. } => json!(val),
+        Value::Nothing { .. } => serde_json::Value::Null,
+        // An unbounded range would iterate forever, so it falls through to the string fallback
+        Value::Range { val, .. } if val.is_bounded() => serde_json::Value::Array(
+            val.into_range_iter(value.span(), nu_protocol::Signals::empty())
+                .map(|item| value_to_json(&item))
+                .collect(),
+        ),
+        other => json!(other.to_abbreviated_string(&nu_protocol::Config::default())),
+    }
+}
+
+fn record_to_json(record: &Record) -> serde_json::Value {
+    serde_json::Value::Object(
+        record
+            .iter()
+            .map(|(key, value)| (key.clone(), value_to_json(value)))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CString;
+
+    fn eval(engine: *mut NuEngine, source: &str) -> String {
+        let source = CString::new(source).unwrap();
+        unsafe {
+            let out = nu_embedded_eval(engine, source.as_ptr());
+            let rendered = CStr::from_ptr(out).to_str().unwrap().to_string();
+            nu_embedded_string_free(out);
+            rendered
+        }
+    }
+
+    #[test]
+    fn eval_round_trip() {
+        let engine = nu_embedded_new();
+        assert_eq!(eval(engine, "1 + 2 * 3"), r#"{"ok":7}"#);
+        assert_eq!(
+            eval(engine, "def double [x] { $x * 2 }; double 21"),
+            r#"{"ok":42}"#
+        );
+        assert_eq!(
+            eval(engine, "{name: nu, tags: [shell lang]}"),
+            r#"{"ok":{"name":"nu","tags":["shell","lang"]}}"#
+        );
+        assert!(eval(engine, "nonexistent-command").starts_with(r#"{"error":"#));
+        unsafe { nu_embedded_free(engine) };
+    }
+}
@@ -5,10 +5,11 @@ use nu_path::{expand_path_with, AbsolutePathBuf};
 use nu_protocol::{
     ast::{Assignment, Block, Call, Expr, Expression, ExternalArgument, PathMember},
     debugger::DebugContext,
-    engine::{Closure, EngineState, Stack},
+    engine::{Closure, Command, EngineState, Stack},
     eval_base::Eval,
-    BlockId, Config, DataSource, IntoPipelineData, PipelineData, PipelineMetadata, ShellError,
-    Span, Value, VarId, ENV_VARIABLE_ID,
+    shell_error::io::IoError,
+    BlockId, Config, DataSource, IntoPipelineData, PipelineData, PipelineMetadata, Record,
+    ShellError, Signature, Span, SyntaxShape, Value, VarId, ENV_VARIABLE_ID,
 };
 use nu_utils::IgnoreCaseExt;
 use std::sync::Arc;
@@ -149,6 +150,9 @@ pub fn eval_call<D: DebugContext>(
             }
         }
 
+        check_path_shape_arguments(engine_state, &callee_stack, &block.signature, call.head)?;
+        run_validation_attributes::<D>(engine_state, &mut callee_stack, decl, block, call.head)?;
+
         let result =
             eval_block_with_early_return::<D>(engine_state, &mut callee_stack, block, input);
 
@@ -287,6 +291,175 @@ pub fn eval_expression_with_input<D: DebugContext>(
     Ok(input)
 }
 
+/// Enforce the `existing-path` and `new-path` parameter shapes of a custom command: the bound
+/// path must (or must not, respectively) exist when the command is called, so the body gets an
+/// early, span-targeted error instead of failing halfway through.
+pub(crate) fn check_path_shape_arguments(
+    engine_state: &EngineState,
+    callee_stack: &Stack,
+    signature: &Signature,
+    head: Span,
+) -> Result<(), ShellError> {
+    let positional_shapes = signature
+        .required_positional
+        .iter()
+        .chain(&signature.optional_positional)
+        .map(|param| (&param.shape, param.var_id));
+    let flag_shapes = signature
+        .named
+        .iter()
+        .filter_map(|flag| flag.arg.as_ref().map(|shape| (shape, flag.var_id)));
+
+    let rest_shape = signature
+        .rest_positional
+        .as_ref()
+        .map(|param| (&param.shape, param.var_id));
+
+    for (shape, var_id) in positional_shapes.chain(flag_shapes).chain(rest_shape) {
+        let must_exist = match shape {
+            SyntaxShape::ExistingPath => true,
+            SyntaxShape::NewPath => false,
+            _ => continue,
+        };
+        let Some(var_id) = var_id else {
+            continue;
+        };
+        let Ok(value) = callee_stack.get_var(var_id, head) else {
+            continue;
+        };
+        // Rest parameters are bound as a list; check every element
+        let values = match value {
+            Value::List { vals, .. } => vals,
+            value => vec![value],
+        };
+        for value in values {
+            // A missing optional argument is bound to nothing; that's fine
+            if value.is_nothing() {
+                continue;
+            }
+            let span = value.span();
+            let Ok(path) = value.coerce_str() else {
+                continue;
+            };
+            let Ok(cwd) = engine_state.cwd(Some(callee_stack)) else {
+                continue;
+            };
+            let expanded = expand_path_with(path.as_ref(), cwd, true);
+            if must_exist && !expanded.exists() {
+                return Err(ShellError::Io(IoError::new(
+                    std::io::ErrorKind::NotFound,
+                    span,
+                    expanded,
+                )));
+            } else if !must_exist && expanded.exists() {
+                return Err(ShellError::GenericError {
+                    error: "Path already exists".into(),
+                    msg: "this parameter requires a path that doesn't exist yet".into(),
+                    span: Some(span),
+                    help: None,
+                    inner: vec![],
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Enforce any `@validate` and `@enum` attributes of a custom command against its bound
+/// parameters.
+///
+/// `@enum` attributes restrict the named parameters to their declared list of values.
+/// `@validate` blocks receive the parameters as a record on `$in`; an error raised by a block is
+/// propagated as-is, and a `false` result is turned into a generic invalid-arguments error. The
+/// command body never runs with parameters the attributes reject.
+pub(crate) fn run_validation_attributes<D: DebugContext>(
+    engine_state: &EngineState,
+    callee_stack: &mut Stack,
+    decl: &dyn Command,
+    block: &Block,
+    head: Span,
+) -> Result<(), ShellError> {
+    let mut validators: Vec<BlockId> = Vec::new();
+    for (name, value) in decl.attributes() {
+        match (name.as_str(), value) {
+            ("validate", Value::Int { val, .. }) => {
+                validators.extend(usize::try_from(val).ok().map(BlockId::new));
+            }
+            ("enum", Value::Record { val: allowed, .. }) => {
+                let params = gather_parameters(&block.signature, callee_stack, head);
+                for (param, choices) in allowed.iter() {
+                    let (Some(actual), Value::List { vals: choices, .. }) =
+                        (params.get(param), choices)
+                    else {
+                        continue;
+                    };
+                    if !actual.is_nothing() && !choices.contains(actual) {
+                        return Err(ShellError::InvalidValue {
+                            valid: format!(
+                                "one of: {}",
+                                choices
+                                    .iter()
+                                    .map(|choice| choice
+                                        .to_abbreviated_string(engine_state.get_config()))
+                                    .collect::<Vec<_>>()
+                                    .join(", ")
+                            ),
+                            actual: actual.to_abbreviated_string(engine_state.get_config()),
+                            span: actual.span(),
+                        });
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+
+    for block_id in validators {
+        let params = gather_parameters(&block.signature, callee_stack, head);
+        let validation_block = engine_state.get_block(block_id);
+        let mut validation_stack = callee_stack.clone().reset_pipes();
+        let result = eval_block_with_early_return::<D>(
+            engine_state,
+            &mut validation_stack,
+            validation_block,
+            Value::record(params, head).into_pipeline_data(),
+        )?;
+        if let Value::Bool { val: false, .. } = result.into_value(head)? {
+            return Err(ShellError::IncorrectValue {
+                msg: format!("Invalid arguments to `{}`", decl.name()),
+                val_span: head,
+                call_span: head,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Collect a custom command's bound parameters from the callee stack into a record.
+fn gather_parameters(signature: &Signature, stack: &Stack, head: Span) -> Record {
+    let mut record = Record::new();
+    let positional = signature
+        .required_positional
+        .iter()
+        .chain(&signature.optional_positional)
+        .chain(signature.rest_positional.as_ref());
+    for param in positional {
+        if let Some(var_id) = param.var_id {
+            if let Ok(value) = stack.get_var(var_id, head) {
+                record.push(param.name.clone(), value);
+            }
+        }
+    }
+    for flag in &signature.named {
+        if let Some(var_id) = flag.var_id {
+            if let Ok(value) = stack.get_var(var_id, head) {
+                record.push(flag.long.clone(), value);
+            }
+        }
+    }
+    record
+}
+
 pub fn eval_block_with_early_return<D: DebugContext>(
     engine_state: &EngineState,
     stack: &mut Stack,
@@ -410,7 +583,7 @@ impl Eval for EvalRuntime {
         quoted: bool,
         span: Span,
     ) -> Result<Value, ShellError> {
-        if quoted {
+        if quoted || is_url_like_path(&path) {
             Ok(Value::string(path, span))
         } else {
             let cwd = engine_state.cwd(Some(stack))?;
@@ -685,3 +858,16 @@ pub(crate) fn is_automatic_env_var(var: &str) -> bool {
         }
     })
 }
+
+/// Whether an unquoted filepath literal is really a URL (`scheme://...`). Commands like
+/// `open`, `ls`, and `save` route these to scheme handlers, so they are left alone instead of
+/// being expanded into nonsense like `/cwd/s3:/bucket`. Single-character schemes are excluded
+/// so Windows drive paths like `C://` still expand as filesystem paths.
+pub fn is_url_like_path(path: &str) -> bool {
+    path.split_once("://").is_some_and(|(scheme, _)| {
+        scheme.len() > 1
+            && scheme
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+    })
+}
@@ -211,6 +211,11 @@ fn eval_ir_block_impl<D: DebugContext>(
                 pc += 1;
             }
             Ok(InstructionResult::Branch(next_pc)) => {
+                // A backward branch means a loop iteration: check for ctrl-c here so that even
+                // loops that never call a command (e.g. pure arithmetic) can be interrupted
+                if next_pc <= pc {
+                    ctx.engine_state.signals().check(*span)?;
+                }
                 pc = next_pc;
             }
             Ok(InstructionResult::Return(reg_id)) => {
@@ -886,7 +891,7 @@ fn literal_value(
             no_expand,
         } => {
             let path = ctx.get_str(*path, span)?;
-            if *no_expand {
+            if *no_expand || crate::eval::is_url_like_path(path) {
                 Value::string(path, span)
             } else {
                 let cwd = ctx.engine_state.cwd(Some(ctx.stack))?;
@@ -1051,6 +1056,20 @@ fn eval_call<D: DebugContext>(
             // recoverable in Rust.
             callee_stack.recursion_count += 1;
 
+            crate::eval::check_path_shape_arguments(
+                engine_state,
+                &callee_stack,
+                &block.signature,
+                head,
+            )?;
+            crate::eval::run_validation_attributes::<D>(
+                engine_state,
+                &mut callee_stack,
+                decl,
+                block,
+                head,
+            )?;
+
             let result =
                 eval_block_with_early_return::<D>(engine_state, &mut callee_stack, block, input);
 
@@ -21,7 +21,7 @@ pub use documentation::get_full_help;
 pub use env::*;
 pub use eval::{
     eval_block, eval_block_with_early_return, eval_call, eval_expression,
-    eval_expression_with_input, eval_subexpression, eval_variable, redirect_env,
+    eval_expression_with_input, eval_subexpression, eval_variable, is_url_like_path, redirect_env,
 };
 pub use eval_helpers::*;
 pub use eval_ir::eval_ir_block;
@@ -149,9 +149,9 @@ impl LanguageServer {
             workspace_symbol_provider: Some(OneOf::Left(true)),
             semantic_tokens_provider: Some(
                 SemanticTokensServerCapabilities::SemanticTokensOptions(SemanticTokensOptions {
-                    // NOTE: only internal command names with space supported for now
+                    // NOTE: internal command names with space and variables supported for now
                     legend: SemanticTokensLegend {
-                        token_types: vec![SemanticTokenType::FUNCTION],
+                        token_types: vec![SemanticTokenType::FUNCTION, SemanticTokenType::VARIABLE],
                         token_modifiers: vec![],
                     },
                     full: Some(lsp_types::SemanticTokensFullOptions::Bool(true)),
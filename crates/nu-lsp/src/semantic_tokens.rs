@@ -10,16 +10,21 @@ use nu_protocol::{
 
 use crate::{span_to_range, LanguageServer};
 
+/// Indices into the `SemanticTokensLegend` declared in the server capabilities
+const TOKEN_TYPE_FUNCTION: u32 = 0;
+const TOKEN_TYPE_VARIABLE: u32 = 1;
+
 /// Important to keep spans in increasing order,
 /// since `SemanticToken`s are created by relative positions
 /// to one's previous token
 ///
 /// Currently supported types:
 /// 1. internal command names with space
+/// 2. variable uses and declarations
 fn extract_semantic_tokens_from_expression(
     expr: &Expression,
     working_set: &StateWorkingSet,
-) -> Option<Vec<Span>> {
+) -> Option<Vec<(Span, u32)>> {
     let closure = |e| extract_semantic_tokens_from_expression(e, working_set);
     match &expr.expr {
         Expr::Call(call) => {
@@ -29,7 +34,7 @@ fn extract_semantic_tokens_from_expression(
                 && !command_name_bytes.starts_with(b"export")
                 && !command_name_bytes.starts_with(b"overlay")
             {
-                vec![call.head]
+                vec![(call.head, TOKEN_TYPE_FUNCTION)]
             } else {
                 vec![]
             };
@@ -44,6 +49,7 @@ fn extract_semantic_tokens_from_expression(
                 .collect();
             Some(spans)
         }
+        Expr::Var(_) | Expr::VarDecl(_) => Some(vec![(expr.span, TOKEN_TYPE_VARIABLE)]),
         _ => None,
     }
 }
@@ -74,7 +80,7 @@ impl LanguageServer {
         let mut last_token_char = 0;
         let mut last_span = Span::unknown();
         let mut tokens = vec![];
-        for sp in spans {
+        for (sp, token_type) in spans {
             let range = span_to_range(&sp, file, offset);
             // shouldn't happen
             if sp < last_span {
@@ -88,8 +94,7 @@ impl LanguageServer {
                 delta_start,
                 delta_line: range.end.line.saturating_sub(last_token_line),
                 length: range.end.character.saturating_sub(range.start.character),
-                // 0 means function in semantic_token_legend
-                token_type: 0,
+                token_type,
                 token_modifiers_bitset: 0,
             });
             last_span = sp;
@@ -133,6 +138,31 @@ mod tests {
             .unwrap()
     }
 
+    #[test]
+    fn semantic_token_variables() {
+        let (client_connection, _recv) = initialize_language_server(None, None);
+
+        let mut script = fixtures();
+        script.push("lsp");
+        script.push("semantic_tokens");
+        script.push("variables.nu");
+        let script = path_to_uri(&script);
+
+        open_unchecked(&client_connection, script.clone());
+        let resp = send_semantic_token_request(&client_connection, script.clone());
+
+        assert_json_eq!(
+            result_from_message(resp),
+            serde_json::json!(
+            { "data": [
+                // delta_line, delta_start, length, token_type, token_modifiers_bitset
+                0, 4, 3, 1, 0, // `foo` in the declaration
+                1, 0, 4, 1, 0, // `$foo`
+                0, 11, 13, 0, 0 // `str substring`
+            ]})
+        );
+    }
+
     #[test]
     fn semantic_token_internals() {
         let (client_connection, _recv) = initialize_language_server(None, None);
@@ -62,6 +62,8 @@ pub fn parse_shape_name(
         b"nothing" => SyntaxShape::Nothing,
         b"number" => SyntaxShape::Number,
         b"path" => SyntaxShape::Filepath,
+        b"existing-path" => SyntaxShape::ExistingPath,
+        b"new-path" => SyntaxShape::NewPath,
         b"range" => SyntaxShape::Range,
         _ if bytes.starts_with(b"record") => {
             parse_collection_shape(working_set, bytes, span, use_loc)
@@ -4981,6 +4981,8 @@ pub fn parse_value(
             | SyntaxShape::Table(_)
             | SyntaxShape::Signature
             | SyntaxShape::Filepath
+            | SyntaxShape::ExistingPath
+            | SyntaxShape::NewPath
             | SyntaxShape::String
             | SyntaxShape::GlobPattern
             | SyntaxShape::ExternalArgument => {}
@@ -5019,7 +5021,9 @@ pub fn parse_value(
         SyntaxShape::Range => {
             parse_range(working_set, span).unwrap_or_else(|| garbage(working_set, span))
         }
-        SyntaxShape::Filepath => parse_filepath(working_set, span),
+        SyntaxShape::Filepath | SyntaxShape::ExistingPath | SyntaxShape::NewPath => {
+            parse_filepath(working_set, span)
+        }
         SyntaxShape::Directory => parse_directory(working_set, span),
         SyntaxShape::GlobPattern => parse_glob_pattern(working_set, span),
         SyntaxShape::String => parse_string(working_set, span),
@@ -5580,12 +5584,13 @@ pub fn parse_math_expression(
             }
 
             let op_span = Span::append(lhs.span, rhs.span);
-            expr_stack.push(Expression::new(
+            let binary_op = Expression::new(
                 working_set,
                 Expr::BinaryOp(Box::new(lhs), Box::new(op), Box::new(rhs)),
                 op_span,
                 result_ty,
-            ));
+            );
+            expr_stack.push(fold_constant_binary_op(working_set, binary_op));
         }
         expr_stack.push(op);
         expr_stack.push(rhs);
@@ -5616,12 +5621,13 @@ pub fn parse_math_expression(
         }
 
         let binary_op_span = Span::append(lhs.span, rhs.span);
-        expr_stack.push(Expression::new(
+        let binary_op = Expression::new(
             working_set,
             Expr::BinaryOp(Box::new(lhs), Box::new(op), Box::new(rhs)),
             binary_op_span,
             result_ty,
-        ));
+        );
+        expr_stack.push(fold_constant_binary_op(working_set, binary_op));
     }
 
     expr_stack
@@ -5629,6 +5635,51 @@ pub fn parse_math_expression(
         .expect("internal error: expression stack empty")
 }
 
+/// Fold a binary operation on literal operands into a literal value at parse time.
+///
+/// Only expressions whose operands are simple literals are candidates, so the constant evaluator
+/// can't observe scope or do anything expensive here. If const evaluation fails (e.g. division by
+/// zero), the expression is left intact so the error surfaces at runtime with the usual span.
+fn fold_constant_binary_op(working_set: &mut StateWorkingSet, expr: Expression) -> Expression {
+    fn is_foldable_literal(expr: &Expr) -> bool {
+        matches!(
+            expr,
+            Expr::Bool(_) | Expr::Int(_) | Expr::Float(_) | Expr::String(_)
+        )
+    }
+
+    {
+        let Expr::BinaryOp(lhs, op, rhs) = &expr.expr else {
+            return expr;
+        };
+        let Expr::Operator(operator) = &op.expr else {
+            return expr;
+        };
+        if !matches!(
+            operator,
+            Operator::Math(_) | Operator::Comparison(_) | Operator::Boolean(_) | Operator::Bits(_)
+        ) || !is_foldable_literal(&lhs.expr)
+            || !is_foldable_literal(&rhs.expr)
+        {
+            return expr;
+        }
+    }
+
+    let Ok(value) = eval_constant(working_set, &expr) else {
+        return expr;
+    };
+
+    let (folded, ty) = match value {
+        Value::Bool { val, .. } => (Expr::Bool(val), Type::Bool),
+        Value::Int { val, .. } => (Expr::Int(val), Type::Int),
+        Value::Float { val, .. } => (Expr::Float(val), Type::Float),
+        Value::String { val, .. } => (Expr::String(val), Type::String),
+        _ => return expr,
+    };
+
+    Expression::new(working_set, folded, expr.span, ty)
+}
+
 pub fn parse_expression(working_set: &mut StateWorkingSet, spans: &[Span]) -> Expression {
     trace!("parsing: expression");
 
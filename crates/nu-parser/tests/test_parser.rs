@@ -254,6 +254,47 @@ pub fn parse_int() {
     assert_eq!(element.expr.expr, Expr::Int(3));
 }
 
+#[test]
+pub fn parse_constant_math_is_folded() {
+    let engine_state = EngineState::new();
+    let mut working_set = StateWorkingSet::new(&engine_state);
+
+    let block = parse(&mut working_set, None, b"1 + 2 * 3", true);
+
+    assert!(working_set.parse_errors.is_empty());
+    assert_eq!(block.len(), 1);
+    let pipeline = &block.pipelines[0];
+    assert_eq!(pipeline.len(), 1);
+    let element = &pipeline.elements[0];
+    assert_eq!(element.expr.expr, Expr::Int(7));
+}
+
+#[test]
+pub fn parse_constant_string_concat_is_folded() {
+    let engine_state = EngineState::new();
+    let mut working_set = StateWorkingSet::new(&engine_state);
+
+    let block = parse(&mut working_set, None, b"'foo' ++ 'bar'", true);
+
+    assert!(working_set.parse_errors.is_empty());
+    let element = &block.pipelines[0].elements[0];
+    assert_eq!(element.expr.expr, Expr::String("foobar".into()));
+}
+
+#[test]
+pub fn parse_constant_division_by_zero_is_not_folded() {
+    let engine_state = EngineState::new();
+    let mut working_set = StateWorkingSet::new(&engine_state);
+
+    let block = parse(&mut working_set, None, b"1 / 0", true);
+
+    // The expression must survive as a binary op so the error surfaces at runtime with the
+    // usual span, not at parse time.
+    assert!(working_set.parse_errors.is_empty());
+    let element = &block.pipelines[0].elements[0];
+    assert!(matches!(element.expr.expr, Expr::BinaryOp(..)));
+}
+
 #[test]
 pub fn parse_int_with_underscores() {
     let engine_state = EngineState::new();
@@ -7,7 +7,7 @@ use nu_protocol::{
 };
 use std::{
     io::{Read, Write},
-    sync::Mutex,
+    sync::{LazyLock, Mutex},
     thread,
 };
 
@@ -24,11 +24,30 @@ mod tests;
 
 /// The maximum number of list stream values to send without acknowledgement. This should be tuned
 /// with consideration for memory usage.
-const LIST_STREAM_HIGH_PRESSURE: i32 = 100;
+///
+/// Can be overridden with the `NU_PLUGIN_LIST_STREAM_WINDOW` environment variable, which applies
+/// to both the engine and plugin side of the protocol. Larger windows keep more values in flight
+/// before waiting for acknowledgement; smaller windows bound memory usage more tightly.
+static LIST_STREAM_HIGH_PRESSURE: LazyLock<i32> =
+    LazyLock::new(|| stream_window_from_env("NU_PLUGIN_LIST_STREAM_WINDOW", 100));
 
 /// The maximum number of raw stream buffers to send without acknowledgement. This should be tuned
 /// with consideration for memory usage.
-const RAW_STREAM_HIGH_PRESSURE: i32 = 50;
+///
+/// Can be overridden with the `NU_PLUGIN_RAW_STREAM_WINDOW` environment variable.
+static RAW_STREAM_HIGH_PRESSURE: LazyLock<i32> =
+    LazyLock::new(|| stream_window_from_env("NU_PLUGIN_RAW_STREAM_WINDOW", 50));
+
+/// Read a stream window size override from the environment, ignoring values that aren't positive
+/// integers. This is read on both sides of the protocol, so plugins spawned by the engine inherit
+/// the engine's setting unless they override it themselves.
+fn stream_window_from_env(var: &str, default: i32) -> i32 {
+    std::env::var(var)
+        .ok()
+        .and_then(|value| value.parse::<i32>().ok())
+        .filter(|&window| window > 0)
+        .unwrap_or(default)
+}
 
 /// Read input/output from the stream.
 pub trait PluginRead<T> {
@@ -265,7 +284,7 @@ pub trait Interface: Clone + Send {
             )),
             PipelineData::Empty => Ok((PipelineDataHeader::Empty, PipelineDataWriter::None)),
             PipelineData::ListStream(stream, metadata) => {
-                let (id, writer) = new_stream(LIST_STREAM_HIGH_PRESSURE)?;
+                let (id, writer) = new_stream(*LIST_STREAM_HIGH_PRESSURE)?;
                 Ok((
                     PipelineDataHeader::ListStream(ListStreamInfo {
                         id,
@@ -279,7 +298,7 @@ pub trait Interface: Clone + Send {
                 let span = stream.span();
                 let type_ = stream.type_();
                 if let Some(reader) = stream.reader() {
-                    let (id, writer) = new_stream(RAW_STREAM_HIGH_PRESSURE)?;
+                    let (id, writer) = new_stream(*RAW_STREAM_HIGH_PRESSURE)?;
                     let header = PipelineDataHeader::ByteStream(ByteStreamInfo {
                         id,
                         span,
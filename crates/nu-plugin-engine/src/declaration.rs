@@ -1,6 +1,9 @@
 use nu_engine::{command_prelude::*, get_eval_expression};
-use nu_plugin_protocol::{CallInfo, EvaluatedCall};
-use nu_protocol::{engine::CommandType, PluginIdentity, PluginSignature};
+use nu_plugin_protocol::{CallInfo, CompletionRequest, EvaluatedCall};
+use nu_protocol::{
+    engine::{ArgumentCompletion, CommandType},
+    PluginIdentity, PluginSignature,
+};
 use std::sync::Arc;
 
 use crate::{GetPlugin, PluginExecutionCommandContext, PluginSource};
@@ -78,6 +81,24 @@ impl Command for PluginDeclaration {
         // Get the engine config
         let engine_config = stack.get_config(engine_state);
 
+        // With `$env.config.plugin_trust_required`, refuse to run plugins the user hasn't
+        // granted trust. There's no interactive prompt here (this can run mid-pipeline), so
+        // the error explains how to grant it.
+        if engine_config.plugin_trust_required
+            && !self.source.persistent(None).is_ok_and(|p| p.is_trusted())
+        {
+            let name = self.source.identity.name();
+            return Err(ShellError::GenericError {
+                error: format!("Plugin `{name}` is not trusted"),
+                msg: "this plugin hasn't been granted trust".into(),
+                span: Some(call.head),
+                help: Some(format!(
+                    "run `plugin trust {name}` (or `plugin add`) to grant it, then `plugin use {name}` to reload"
+                )),
+                inner: vec![],
+            });
+        }
+
         // Get, or start, the plugin.
         let plugin = self
             .source
@@ -123,4 +144,45 @@ impl Command for PluginDeclaration {
     fn plugin_identity(&self) -> Option<&PluginIdentity> {
         Some(&self.source.identity)
     }
+
+    fn complete_argument(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        line: &str,
+        position: usize,
+        prefix: &str,
+    ) -> Option<Vec<ArgumentCompletion>> {
+        // Completion shouldn't surface errors; a plugin that can't be reached (or that doesn't
+        // support the `ArgumentCompletions` protocol feature, in which case the interface
+        // returns an empty list) just falls back to the default completers.
+        let plugin = self.source.persistent(None).ok()?;
+        // The same trust gate as `run`: completion must not spawn a plugin the user wouldn't
+        // be allowed to run
+        if stack.get_config(engine_state).plugin_trust_required && !plugin.is_trusted() {
+            return None;
+        }
+        let interface = plugin.get_plugin(Some((engine_state, stack))).ok()?;
+        let items = interface
+            .complete_argument(CompletionRequest {
+                name: self.name.clone(),
+                line: line.into(),
+                position,
+                prefix: prefix.into(),
+            })
+            .ok()?;
+        if items.is_empty() {
+            None
+        } else {
+            Some(
+                items
+                    .into_iter()
+                    .map(|item| ArgumentCompletion {
+                        value: item.value,
+                        description: item.description,
+                    })
+                    .collect(),
+            )
+        }
+    }
 }
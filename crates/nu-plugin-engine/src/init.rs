@@ -253,6 +253,7 @@ pub fn load_plugin_registry_item(
 
     match &plugin.data {
         PluginRegistryItemData::Valid { metadata, commands } => {
+            let trusted = plugin.trusted;
             let plugin = add_plugin_to_working_set(working_set, &identity)?;
 
             // Ensure that the plugin is reset. We're going to load new signatures, so we want to
@@ -260,8 +261,9 @@ pub fn load_plugin_registry_item(
             // doesn't.
             plugin.reset()?;
 
-            // Set the plugin metadata from the file
+            // Set the plugin metadata and trust grant from the file
             plugin.set_metadata(Some(metadata.clone()));
+            plugin.set_trusted(trusted);
 
             // Create the declarations from the commands
             for signature in commands {
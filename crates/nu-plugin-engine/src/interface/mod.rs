@@ -6,9 +6,10 @@ use nu_plugin_core::{
     StreamManagerHandle,
 };
 use nu_plugin_protocol::{
-    CallInfo, CustomValueOp, EngineCall, EngineCallId, EngineCallResponse, EvaluatedCall, Ordering,
-    PluginCall, PluginCallId, PluginCallResponse, PluginCustomValue, PluginInput, PluginOption,
-    PluginOutput, ProtocolInfo, StreamId, StreamMessage,
+    CallInfo, CompletionItem, CompletionRequest, CustomValueOp, EngineCall, EngineCallId,
+    EngineCallResponse, EvaluatedCall, Feature, Ordering, PluginCall, PluginCallId,
+    PluginCallResponse, PluginCustomValue, PluginInput, PluginOption, PluginOutput, ProtocolInfo,
+    StreamId, StreamMessage,
 };
 use nu_protocol::{
     ast::Operator, engine::Sequence, CustomValue, IntoSpanned, PipelineData, PluginMetadata,
@@ -735,6 +736,9 @@ impl PluginInterface {
             PluginCall::CustomValueOp(value, op) => {
                 (PluginCall::CustomValueOp(value, op), Default::default())
             }
+            PluginCall::CompleteArgument(request) => {
+                (PluginCall::CompleteArgument(request), Default::default())
+            }
             PluginCall::Run(CallInfo { name, call, input }) => {
                 let (header, writer) = self.init_write_pipeline_data(input, &state)?;
                 (
@@ -950,6 +954,29 @@ impl PluginInterface {
         }
     }
 
+    /// Get completion candidates for an argument of one of the plugin's commands.
+    ///
+    /// Returns an empty list if the plugin's protocol version doesn't support argument
+    /// completions, so older plugins degrade to the engine's default completers.
+    pub fn complete_argument(
+        &self,
+        request: CompletionRequest,
+    ) -> Result<Vec<CompletionItem>, ShellError> {
+        if !self
+            .protocol_info()?
+            .supports_feature(&Feature::ArgumentCompletions)
+        {
+            return Ok(vec![]);
+        }
+        match self.plugin_call(PluginCall::CompleteArgument(request), None)? {
+            PluginCallResponse::Completion(items) => Ok(items),
+            PluginCallResponse::Error(err) => Err(err.into()),
+            _ => Err(ShellError::PluginFailedToDecode {
+                msg: "Received unexpected response to plugin CompleteArgument call".into(),
+            }),
+        }
+    }
+
     /// Run the plugin with the given call and execution context.
     pub fn run(
         &self,
@@ -1234,6 +1261,7 @@ impl CurrentCallState {
         match call {
             PluginCall::Metadata => Ok(()),
             PluginCall::Signature => Ok(()),
+            PluginCall::CompleteArgument(_) => Ok(()),
             PluginCall::Run(CallInfo { call, .. }) => self.prepare_call_args(call, source),
             PluginCall::CustomValueOp(_, op) => {
                 // Handle anything within the op.
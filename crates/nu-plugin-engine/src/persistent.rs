@@ -39,6 +39,8 @@ struct MutableState {
     preferred_mode: Option<PreferredCommunicationMode>,
     /// Garbage collector config
     gc_config: PluginGcConfig,
+    /// Whether the user has granted this plugin trust (from the plugin registry file)
+    trusted: bool,
     /// RAII guard for this plugin's signal handler
     signal_guard: Option<HandlerGuard>,
 }
@@ -68,11 +70,25 @@ impl PersistentPlugin {
                 metadata: None,
                 preferred_mode: None,
                 gc_config,
+                trusted: false,
                 signal_guard: None,
             }),
         }
     }
 
+    /// Whether the user has granted this plugin trust (via `plugin add` or `plugin trust`).
+    /// Only consulted when `$env.config.plugin_trust_required` is set.
+    pub fn is_trusted(&self) -> bool {
+        self.mutable.lock().is_ok_and(|mutable| mutable.trusted)
+    }
+
+    /// Record whether the user has granted this plugin trust.
+    pub fn set_trusted(&self, trusted: bool) {
+        if let Ok(mut mutable) = self.mutable.lock() {
+            mutable.trusted = trusted;
+        }
+    }
+
     /// Get the plugin interface of the running plugin, or spawn it if it's not currently running.
     ///
     /// Will call `envs` to get environment variables to spawn the plugin if the plugin needs to be
@@ -345,9 +361,19 @@ pub trait GetPlugin: RegisteredPlugin {
         self: Arc<Self>,
         context: Option<(&EngineState, &mut Stack)>,
     ) -> Result<PluginInterface, ShellError>;
+
+    /// Whether the user has granted this plugin trust. Plugins that don't come from the plugin
+    /// registry file (e.g. in tests) are implicitly trusted.
+    fn is_trusted(&self) -> bool {
+        true
+    }
 }
 
 impl GetPlugin for PersistentPlugin {
+    fn is_trusted(&self) -> bool {
+        PersistentPlugin::is_trusted(self)
+    }
+
     fn get_plugin(
         self: Arc<Self>,
         mut context: Option<(&EngineState, &mut Stack)>,
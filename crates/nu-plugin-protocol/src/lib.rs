@@ -162,6 +162,7 @@ pub enum PluginCall<D> {
     Signature,
     Run(CallInfo<D>),
     CustomValueOp(Spanned<PluginCustomValue>, CustomValueOp),
+    CompleteArgument(CompletionRequest),
 }
 
 impl<D> PluginCall<D> {
@@ -178,6 +179,7 @@ impl<D> PluginCall<D> {
             PluginCall::CustomValueOp(custom_value, op) => {
                 PluginCall::CustomValueOp(custom_value, op)
             }
+            PluginCall::CompleteArgument(request) => PluginCall::CompleteArgument(request),
         })
     }
 
@@ -188,10 +190,36 @@ impl<D> PluginCall<D> {
             PluginCall::Signature => None,
             PluginCall::Run(CallInfo { call, .. }) => Some(call.head),
             PluginCall::CustomValueOp(val, _) => Some(val.span),
+            PluginCall::CompleteArgument(_) => None,
         }
     }
 }
 
+/// Line editor context for a `CompleteArgument` plugin call.
+///
+/// This describes where completion was requested so the plugin can decide what candidates make
+/// sense for the argument or flag under the cursor.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct CompletionRequest {
+    /// The name of the plugin command being completed.
+    pub name: String,
+    /// The full command line being edited.
+    pub line: String,
+    /// The byte position of the cursor within `line`.
+    pub position: usize,
+    /// The partial word under the cursor, if any.
+    pub prefix: String,
+}
+
+/// A completion candidate returned from a `CompleteArgument` plugin call.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct CompletionItem {
+    /// The text to insert.
+    pub value: String,
+    /// An optional description shown alongside the candidate in the completion menu.
+    pub description: Option<String>,
+}
+
 /// Operations supported for custom values.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum CustomValueOp {
@@ -357,6 +385,7 @@ pub enum PluginCallResponse<D> {
     Signature(Vec<PluginSignature>),
     Ordering(Option<Ordering>),
     PipelineData(D),
+    Completion(Vec<CompletionItem>),
 }
 
 impl<D> PluginCallResponse<D> {
@@ -372,6 +401,7 @@ impl<D> PluginCallResponse<D> {
             PluginCallResponse::Signature(sigs) => PluginCallResponse::Signature(sigs),
             PluginCallResponse::Ordering(ordering) => PluginCallResponse::Ordering(ordering),
             PluginCallResponse::PipelineData(input) => PluginCallResponse::PipelineData(f(input)?),
+            PluginCallResponse::Completion(items) => PluginCallResponse::Completion(items),
         })
     }
 }
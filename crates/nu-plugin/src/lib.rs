@@ -76,7 +76,7 @@ pub use plugin::{serve_plugin, EngineInterface, Plugin, PluginCommand, SimplePlu
 
 // Re-exports. Consider semver implications carefully.
 pub use nu_plugin_core::{JsonSerializer, MsgPackSerializer, PluginEncoder};
-pub use nu_plugin_protocol::EvaluatedCall;
+pub use nu_plugin_protocol::{CompletionItem, CompletionRequest, EvaluatedCall};
 
 // Required by other internal crates.
 #[doc(hidden)]
@@ -1,3 +1,4 @@
+use nu_plugin_protocol::{CompletionItem, CompletionRequest};
 use nu_protocol::{
     Example, IntoSpanned, LabeledError, PipelineData, PluginExample, PluginSignature, ShellError,
     Signature, Value,
@@ -151,6 +152,22 @@ pub trait PluginCommand: Sync {
         call: &EvaluatedCall,
         input: PipelineData,
     ) -> Result<PipelineData, LabeledError>;
+
+    /// Provide completion candidates for an argument or flag of this command.
+    ///
+    /// The engine calls this while the user is editing a command line that invokes this plugin
+    /// command. `request` describes the line and cursor position; candidates whose `value` starts
+    /// with the partial word under the cursor are the most useful. The default implementation
+    /// returns no candidates, which lets the engine fall back to its own completers.
+    fn complete_argument(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        request: &CompletionRequest,
+    ) -> Vec<CompletionItem> {
+        let _ = (plugin, engine, request);
+        vec![]
+    }
 }
 
 /// The API for a simple Nushell plugin command
@@ -287,6 +304,20 @@ pub trait SimplePluginCommand: Sync {
         call: &EvaluatedCall,
         input: &Value,
     ) -> Result<Value, LabeledError>;
+
+    /// Provide completion candidates when the user presses tab on an argument of this command.
+    ///
+    /// See [`PluginCommand::complete_argument`] for details. The default implementation returns
+    /// no candidates, which lets the engine fall back to its own completers.
+    fn complete_argument(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        request: &CompletionRequest,
+    ) -> Vec<CompletionItem> {
+        let _ = (plugin, engine, request);
+        vec![]
+    }
 }
 
 /// All [`SimplePluginCommand`]s can be used as [`PluginCommand`]s, but input streams will be fully
@@ -336,6 +367,15 @@ where
     fn description(&self) -> &str {
         <Self as SimplePluginCommand>::description(self)
     }
+
+    fn complete_argument(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        request: &CompletionRequest,
+    ) -> Vec<CompletionItem> {
+        <Self as SimplePluginCommand>::complete_argument(self, plugin, engine, request)
+    }
 }
 
 /// Build a [`PluginSignature`] from the signature-related methods on [`PluginCommand`].
@@ -6,9 +6,9 @@ use nu_plugin_core::{
     StreamManagerHandle,
 };
 use nu_plugin_protocol::{
-    CallInfo, CustomValueOp, EngineCall, EngineCallId, EngineCallResponse, EvaluatedCall, Ordering,
-    PluginCall, PluginCallId, PluginCallResponse, PluginCustomValue, PluginInput, PluginOption,
-    PluginOutput, ProtocolInfo,
+    CallInfo, CompletionItem, CompletionRequest, CustomValueOp, EngineCall, EngineCallId,
+    EngineCallResponse, EvaluatedCall, Ordering, PluginCall, PluginCallId, PluginCallResponse,
+    PluginCustomValue, PluginInput, PluginOption, PluginOutput, ProtocolInfo,
 };
 use nu_protocol::{
     engine::{Closure, Sequence},
@@ -46,6 +46,10 @@ pub enum ReceivedPluginCall {
         custom_value: Spanned<PluginCustomValue>,
         op: CustomValueOp,
     },
+    CompleteArgument {
+        engine: EngineInterface,
+        request: CompletionRequest,
+    },
 }
 
 #[cfg(test)]
@@ -321,6 +325,13 @@ impl InterfaceManager for EngineInterfaceManager {
                             op,
                         })
                     }
+                    // Ask the plugin for completion candidates
+                    PluginCall::CompleteArgument(request) => {
+                        self.send_plugin_call(ReceivedPluginCall::CompleteArgument {
+                            engine: interface,
+                            request,
+                        })
+                    }
                 }
             }
             PluginInput::Goodbye => {
@@ -459,6 +470,13 @@ impl EngineInterface {
         self.flush()
     }
 
+    /// Write a call response of completion candidates.
+    pub(crate) fn write_completion(&self, items: Vec<CompletionItem>) -> Result<(), ShellError> {
+        let response = PluginCallResponse::Completion(items);
+        self.write(PluginOutput::CallResponse(self.context()?, response))?;
+        self.flush()
+    }
+
     /// Write an engine call message. Returns the writer for the stream, and the receiver for
     /// the response to the engine call.
     fn write_engine_call(
@@ -569,6 +569,14 @@ where
                 } => {
                     custom_value_op(plugin, &engine, custom_value, op).try_to_report(&engine)?;
                 }
+                // Ask the named command for completion candidates
+                ReceivedPluginCall::CompleteArgument { engine, request } => {
+                    let items = commands
+                        .get(&request.name)
+                        .map(|command| command.complete_argument(plugin, &engine, &request))
+                        .unwrap_or_default();
+                    engine.write_completion(items).try_to_report(&engine)?;
+                }
             }
         }
 
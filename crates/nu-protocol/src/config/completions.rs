@@ -106,6 +106,11 @@ pub struct CompletionConfig {
     pub algorithm: CompletionAlgorithm,
     pub external: ExternalCompleterConfig,
     pub use_ls_colors: bool,
+    /// A closure that reorders the final completion suggestions before they are shown.
+    pub sorter: Option<Closure>,
+    /// Whether to include the kind of a suggestion (command, variable, flag, ...) in its
+    /// description, so menus that render descriptions show it.
+    pub show_kind: bool,
 }
 
 impl Default for CompletionConfig {
@@ -118,6 +123,8 @@ impl Default for CompletionConfig {
             algorithm: CompletionAlgorithm::default(),
             external: ExternalCompleterConfig::default(),
             use_ls_colors: true,
+            sorter: None,
+            show_kind: false,
         }
     }
 }
@@ -138,12 +145,18 @@ impl UpdateFromValue for CompletionConfig {
             let path = &mut path.push(col);
             match col.as_str() {
                 "sort" => self.sort.update(val, path, errors),
+                "sorter" => match val {
+                    Value::Nothing { .. } => self.sorter = None,
+                    Value::Closure { val, .. } => self.sorter = Some(val.as_ref().clone()),
+                    _ => errors.type_mismatch(path, Type::custom("closure or nothing"), val),
+                },
                 "quick" => self.quick.update(val, path, errors),
                 "partial" => self.partial.update(val, path, errors),
                 "algorithm" => self.algorithm.update(val, path, errors),
                 "case_sensitive" => self.case_sensitive.update(val, path, errors),
                 "external" => self.external.update(val, path, errors),
                 "use_ls_colors" => self.use_ls_colors.update(val, path, errors),
+                "show_kind" => self.show_kind.update(val, path, errors),
                 _ => errors.unknown_option(path, val),
             }
         }
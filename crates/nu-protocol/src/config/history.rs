@@ -37,12 +37,15 @@ impl UpdateFromValue for HistoryFileFormat {
     }
 }
 
-#[derive(Clone, Copy, Debug, IntoValue, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, IntoValue, PartialEq, Eq, Serialize, Deserialize)]
 pub struct HistoryConfig {
     pub max_size: i64,
     pub sync_on_enter: bool,
     pub file_format: HistoryFileFormat,
     pub isolation: bool,
+    /// Regex patterns for commands that should never be recorded in history, like a leading
+    /// space but configurable (e.g. `['^ *', 'password']`).
+    pub ignore: Vec<String>,
 }
 
 impl HistoryConfig {
@@ -61,6 +64,7 @@ impl Default for HistoryConfig {
             sync_on_enter: true,
             file_format: HistoryFileFormat::Plaintext,
             isolation: false,
+            ignore: Vec::new(),
         }
     }
 }
@@ -84,6 +88,15 @@ impl UpdateFromValue for HistoryConfig {
                 "sync_on_enter" => self.sync_on_enter.update(val, path, errors),
                 "max_size" => self.max_size.update(val, path, errors),
                 "file_format" => self.file_format.update(val, path, errors),
+                "ignore" => match val.as_list() {
+                    Ok(patterns) => {
+                        self.ignore = patterns
+                            .iter()
+                            .filter_map(|pattern| pattern.as_str().ok().map(String::from))
+                            .collect()
+                    }
+                    Err(_) => errors.type_mismatch(path, Type::list(Type::String), val),
+                },
                 _ => errors.unknown_option(path, val),
             }
         }
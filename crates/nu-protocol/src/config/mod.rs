@@ -51,6 +51,10 @@ pub struct Config {
     pub footer_mode: FooterMode,
     pub float_precision: i64,
     pub recursion_limit: i64,
+    /// When positive, `sort-by` keeps at most this many rows in memory at once; input beyond
+    /// the budget is spilled to sorted temporary files and merged back as a stream. `0`
+    /// (the default) keeps sorting fully in memory.
+    pub sort_spill_threshold: i64,
     pub use_ansi_coloring: UseAnsiColoring,
     pub completions: CompletionConfig,
     pub edit_mode: EditBindings,
@@ -64,6 +68,16 @@ pub struct Config {
     pub show_banner: Value,
     pub bracketed_paste: bool,
     pub render_right_prompt_on_last_line: bool,
+    /// Render the right prompt on its own line above the input line, instead of on the
+    /// input line itself. Useful for long right prompts that would crowd out the buffer.
+    pub render_right_prompt_above: bool,
+    /// How long a `$env.PROMPT_COMMAND` (or related prompt segment) closure may run before the
+    /// prompt gives up on it and renders a placeholder instead. A zero duration disables the
+    /// timeout.
+    pub prompt_timeout: PromptTimeout,
+    /// Names of destructive commands (e.g. "rm", "mv", "cp") that should always ask for
+    /// confirmation, as if `--interactive` had been passed.
+    pub always_confirm_destructive: Vec<String>,
     pub explore: HashMap<String, Value>,
     pub cursor_shape: CursorShapeConfig,
     pub datetime_format: DatetimeFormatConfig,
@@ -79,6 +93,9 @@ pub struct Config {
     pub plugins: HashMap<String, Value>,
     /// Configuration for plugin garbage collection.
     pub plugin_gc: PluginGcConfigs,
+    /// Refuse to run commands from plugins that haven't been granted trust with `plugin add`
+    /// or `plugin trust`.
+    pub plugin_trust_required: bool,
 }
 
 impl Default for Config {
@@ -100,6 +117,8 @@ impl Default for Config {
 
             recursion_limit: 50,
 
+            sort_spill_threshold: 0,
+
             filesize: FilesizeConfig::default(),
 
             cursor_shape: CursorShapeConfig::default(),
@@ -115,6 +134,11 @@ impl Default for Config {
             shell_integration: ShellIntegrationConfig::default(),
 
             render_right_prompt_on_last_line: false,
+            render_right_prompt_above: false,
+
+            prompt_timeout: PromptTimeout::default(),
+
+            always_confirm_destructive: Vec::new(),
 
             hooks: Hooks::new(),
 
@@ -130,6 +154,7 @@ impl Default for Config {
 
             plugins: HashMap::new(),
             plugin_gc: PluginGcConfigs::default(),
+            plugin_trust_required: false,
         }
     }
 }
@@ -183,6 +208,24 @@ impl UpdateFromValue for Config {
                 "render_right_prompt_on_last_line" => self
                     .render_right_prompt_on_last_line
                     .update(val, path, errors),
+                "render_right_prompt_above" => {
+                    self.render_right_prompt_above.update(val, path, errors)
+                }
+                "always_confirm_destructive" => match val.as_list() {
+                    Ok(items) => {
+                        self.always_confirm_destructive = items
+                            .iter()
+                            .filter_map(|item| item.as_str().ok().map(String::from))
+                            .collect()
+                    }
+                    Err(_) => errors.type_mismatch(path, Type::list(Type::String), val),
+                },
+                "prompt_timeout" => match val {
+                    Value::Duration { val, .. } if *val >= 0 => {
+                        self.prompt_timeout = PromptTimeout(*val)
+                    }
+                    _ => errors.type_mismatch(path, Type::Duration, val),
+                },
                 "bracketed_paste" => self.bracketed_paste.update(val, path, errors),
                 "use_kitty_protocol" => self.use_kitty_protocol.update(val, path, errors),
                 "highlight_resolved_externals" => {
@@ -190,6 +233,7 @@ impl UpdateFromValue for Config {
                 }
                 "plugins" => self.plugins.update(val, path, errors),
                 "plugin_gc" => self.plugin_gc.update(val, path, errors),
+                "plugin_trust_required" => self.plugin_trust_required.update(val, path, errors),
                 "menus" => match Vec::from_value(val.clone()) {
                     Ok(menus) => self.menus = menus,
                     Err(err) => errors.error(err.into()),
@@ -201,6 +245,10 @@ impl UpdateFromValue for Config {
                 "hooks" => self.hooks.update(val, path, errors),
                 "datetime_format" => self.datetime_format.update(val, path, errors),
                 "error_style" => self.error_style.update(val, path, errors),
+                "sort_spill_threshold" => match val {
+                    Value::Int { val, .. } if *val >= 0 => self.sort_spill_threshold = *val,
+                    _ => errors.type_mismatch(path, Type::Int, val),
+                },
                 "recursion_limit" => {
                     if let Ok(limit) = val.as_int() {
                         if limit > 1 {
@@ -218,6 +266,23 @@ impl UpdateFromValue for Config {
     }
 }
 
+/// A prompt evaluation timeout, stored in nanoseconds but exposed in `$env.config` as a
+/// duration (so a round trip through `$env.config = $env.config` keeps its type).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PromptTimeout(i64);
+
+impl PromptTimeout {
+    pub fn as_nanos(&self) -> i64 {
+        self.0
+    }
+}
+
+impl IntoValue for PromptTimeout {
+    fn into_value(self, span: Span) -> Value {
+        Value::duration(self.0, span)
+    }
+}
+
 impl Config {
     pub fn update_from_value(&mut self, old: &Config, value: &Value) -> Option<ShellError> {
         // Current behaviour is that config errors are displayed, but do not prevent the rest
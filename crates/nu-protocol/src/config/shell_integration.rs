@@ -1,7 +1,8 @@
 use super::prelude::*;
 use crate as nu_protocol;
+use crate::engine::Closure;
 
-#[derive(Clone, Copy, Debug, IntoValue, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, IntoValue, Serialize, Deserialize)]
 pub struct ShellIntegrationConfig {
     pub osc2: bool,
     pub osc7: bool,
@@ -10,6 +11,10 @@ pub struct ShellIntegrationConfig {
     pub osc133: bool,
     pub osc633: bool,
     pub reset_application_mode: bool,
+    /// A closure that builds the terminal/tab title for osc2. It receives a record with the
+    /// fields `cwd` and `command` (null between commands) and returns the title string. When
+    /// unset, the default "<cwd>> <command>" title is used.
+    pub title: Option<Closure>,
 }
 
 #[allow(clippy::derivable_impls)]
@@ -23,6 +28,7 @@ impl Default for ShellIntegrationConfig {
             osc133: true,
             osc633: true,
             reset_application_mode: true,
+            title: None,
         }
     }
 }
@@ -49,6 +55,11 @@ impl UpdateFromValue for ShellIntegrationConfig {
                 "osc133" => self.osc133.update(val, path, errors),
                 "osc633" => self.osc633.update(val, path, errors),
                 "reset_application_mode" => self.reset_application_mode.update(val, path, errors),
+                "title" => match val {
+                    Value::Nothing { .. } => self.title = None,
+                    Value::Closure { val, .. } => self.title = Some(val.as_ref().clone()),
+                    _ => errors.type_mismatch(path, Type::custom("closure or nothing"), val),
+                },
                 _ => errors.unknown_option(path, val),
             }
         }
@@ -105,6 +105,22 @@ pub trait Command: Send + Sync + CommandClone {
         None
     }
 
+    /// Completion candidates for the argument under the cursor, or `None` if this command
+    /// doesn't provide any of its own (the default completers then apply).
+    ///
+    /// Implemented by plugin commands, which forward the request over the plugin protocol.
+    #[allow(unused_variables)]
+    fn complete_argument(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        line: &str,
+        position: usize,
+        prefix: &str,
+    ) -> Option<Vec<ArgumentCompletion>> {
+        None
+    }
+
     fn command_type(&self) -> CommandType {
         CommandType::Builtin
     }
@@ -144,6 +160,15 @@ pub trait Command: Send + Sync + CommandClone {
     }
 }
 
+/// A completion candidate produced by [`Command::complete_argument`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArgumentCompletion {
+    /// The text to insert.
+    pub value: String,
+    /// An optional description shown alongside the candidate in the completion menu.
+    pub description: Option<String>,
+}
+
 pub trait CommandClone {
     fn clone_box(&self) -> Box<dyn Command>;
 }
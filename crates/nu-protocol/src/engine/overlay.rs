@@ -20,6 +20,20 @@ impl Visibility {
         *self.decl_ids.get(decl_id).unwrap_or(&true) // by default it's visible
     }
 
+    /// The number of explicit visibility entries (from `hide` and re-imports).
+    pub fn len(&self) -> usize {
+        self.decl_ids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.decl_ids.is_empty()
+    }
+
+    /// The number of declarations explicitly hidden (e.g. by `hide`).
+    pub fn num_hidden(&self) -> usize {
+        self.decl_ids.values().filter(|visible| !**visible).count()
+    }
+
     pub fn hide_decl_id(&mut self, decl_id: &DeclId) {
         self.decl_ids.insert(*decl_id, false);
     }
@@ -7,12 +7,52 @@ use serde::{Deserialize, Serialize};
 pub struct PluginMetadata {
     /// The version of the plugin itself, as self-reported.
     pub version: Option<String>,
+
+    /// Capabilities the plugin declares that it needs, as self-reported. This is informational
+    /// for now: it is surfaced to the user (e.g. in `plugin list`) so they can review what a
+    /// plugin intends to access before trusting it.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub capabilities: Vec<PluginCapability>,
+}
+
+/// A capability a plugin declares that it needs in [`PluginMetadata`].
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum PluginCapability {
+    /// The plugin reads or writes the filesystem beyond what is piped to it.
+    Filesystem,
+    /// The plugin makes network connections.
+    Network,
+    /// The plugin reads environment variables beyond what the engine provides it.
+    Environment,
+    /// The plugin spawns other processes.
+    Processes,
+    /// A capability that was not recognized on deserialization, e.g. one declared by a plugin
+    /// built against a newer version of nushell.
+    #[serde(other)]
+    Unknown,
+}
+
+impl PluginCapability {
+    /// The name of the capability, as shown to the user.
+    pub fn name(&self) -> &'static str {
+        match self {
+            PluginCapability::Filesystem => "filesystem",
+            PluginCapability::Network => "network",
+            PluginCapability::Environment => "environment",
+            PluginCapability::Processes => "processes",
+            PluginCapability::Unknown => "unknown",
+        }
+    }
 }
 
 impl PluginMetadata {
     /// Create empty metadata.
     pub const fn new() -> PluginMetadata {
-        PluginMetadata { version: None }
+        PluginMetadata {
+            version: None,
+            capabilities: vec![],
+        }
     }
 
     /// Set the version of the plugin on the metadata. A suggested way to construct this is:
@@ -29,6 +69,22 @@ impl PluginMetadata {
         self.version = Some(version.into());
         self
     }
+
+    /// Declare the capabilities the plugin needs on the metadata:
+    ///
+    /// ```no_run
+    /// # use nu_protocol::{PluginCapability, PluginMetadata};
+    /// # fn example() -> PluginMetadata {
+    /// PluginMetadata::new().with_capabilities([PluginCapability::Network])
+    /// # }
+    /// ```
+    pub fn with_capabilities(
+        mut self,
+        capabilities: impl IntoIterator<Item = PluginCapability>,
+    ) -> Self {
+        self.capabilities = capabilities.into_iter().collect();
+        self
+    }
 }
 
 impl Default for PluginMetadata {
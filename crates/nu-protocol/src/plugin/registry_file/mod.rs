@@ -114,6 +114,12 @@ pub struct PluginRegistryItem {
     /// The shell program used to run the plugin, if applicable.
     pub shell: Option<PathBuf>,
 
+    /// Whether the user has granted this plugin trust (via `plugin add` or `plugin trust`).
+    /// Only enforced when `$env.config.plugin_trust_required` is set; files written before
+    /// this field existed read as untrusted.
+    #[serde(default)]
+    pub trusted: bool,
+
     /// Additional data that might be invalid so that we don't fail to load the whole plugin file
     /// if there's a deserialization error.
     #[serde(flatten)]
@@ -134,6 +140,8 @@ impl PluginRegistryItem {
             name: identity.name().to_owned(),
             filename: identity.filename().to_owned(),
             shell: identity.shell().map(|p| p.to_owned()),
+            // Explicitly adding a plugin is the consent step
+            trusted: true,
             data: PluginRegistryItemData::Valid { metadata, commands },
         }
     }
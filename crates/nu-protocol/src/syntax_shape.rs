@@ -56,6 +56,13 @@ pub enum SyntaxShape {
     /// A filepath is allowed
     Filepath,
 
+    /// A filepath, like [`SyntaxShape::Filepath`], that must exist when the command is called
+    ExistingPath,
+
+    /// A filepath, like [`SyntaxShape::Filepath`], that must not exist yet when the command is
+    /// called
+    NewPath,
+
     /// A filesize value is allowed, eg `10kb`
     Filesize,
 
@@ -153,6 +160,8 @@ impl SyntaxShape {
             SyntaxShape::Expression => Type::Any,
             SyntaxShape::ExternalArgument => Type::Any,
             SyntaxShape::Filepath => Type::String,
+            SyntaxShape::ExistingPath => Type::String,
+            SyntaxShape::NewPath => Type::String,
             SyntaxShape::Directory => Type::String,
             SyntaxShape::Float => Type::Float,
             SyntaxShape::Filesize => Type::Filesize,
@@ -206,6 +215,8 @@ impl Display for SyntaxShape {
             SyntaxShape::Int => write!(f, "int"),
             SyntaxShape::Float => write!(f, "float"),
             SyntaxShape::Filepath => write!(f, "path"),
+            SyntaxShape::ExistingPath => write!(f, "existing-path"),
+            SyntaxShape::NewPath => write!(f, "new-path"),
             SyntaxShape::Directory => write!(f, "directory"),
             SyntaxShape::GlobPattern => write!(f, "glob"),
             SyntaxShape::ImportPattern => write!(f, "import"),
@@ -5,6 +5,7 @@ mod from_value;
 mod glob;
 mod into_value;
 mod range;
+mod secret;
 #[cfg(test)]
 mod test_derive;
 
@@ -18,6 +19,7 @@ pub use glob::*;
 pub use into_value::{IntoValue, TryIntoValue};
 pub use range::{FloatRange, IntRange, Range};
 pub use record::Record;
+pub use secret::SecretValue;
 
 use crate::{
     ast::{Bits, Boolean, CellPath, Comparison, Math, Operator, PathMember},
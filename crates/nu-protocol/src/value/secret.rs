@@ -0,0 +1,58 @@
+//! A wrapper that keeps sensitive strings out of displays and serialized output: [`SecretValue`]
+use crate::{CustomValue, ShellError, Span, Value};
+use serde::{Deserialize, Serialize};
+
+/// A sensitive string that renders as `<redacted>`.
+///
+/// Tables, `to json`, `to nuon`, and friends all go through
+/// [`to_base_value`](CustomValue::to_base_value) and only ever see the redacted form, so a token
+/// can flow through a pipeline without ending up in output by accident. The content is only
+/// accessible deliberately, via `secret reveal` (or [`SecretValue::reveal`] in Rust).
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SecretValue(String);
+
+// A manual impl so that debug formatting can't expose the content either
+impl std::fmt::Debug for SecretValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("SecretValue").field(&"<redacted>").finish()
+    }
+}
+
+impl SecretValue {
+    pub fn new(secret: impl Into<String>) -> Self {
+        SecretValue(secret.into())
+    }
+
+    /// Wrap into a [`Value`].
+    pub fn into_value(self, span: Span) -> Value {
+        Value::custom(Box::new(self), span)
+    }
+
+    /// Deliberately access the wrapped content.
+    pub fn reveal(&self) -> &str {
+        &self.0
+    }
+}
+
+#[typetag::serde]
+impl CustomValue for SecretValue {
+    fn clone_value(&self, span: Span) -> Value {
+        self.clone().into_value(span)
+    }
+
+    fn type_name(&self) -> String {
+        "secret".into()
+    }
+
+    fn to_base_value(&self, span: Span) -> Result<Value, ShellError> {
+        Ok(Value::string("<redacted>", span))
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
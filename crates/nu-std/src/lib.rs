@@ -1,9 +1,8 @@
 #![doc = include_str!("../README.md")]
 use log::trace;
-use nu_parser::parse;
 use nu_protocol::{
-    engine::{FileStack, StateWorkingSet, VirtualPath},
-    report_parse_error, VirtualPathId,
+    engine::{StateWorkingSet, VirtualPath},
+    VirtualPathId,
 };
 use std::path::PathBuf;
 
@@ -34,7 +33,7 @@ pub fn load_standard_library(
 
     // Submodules/subdirectories ... std/<module>/mod.nu
     let mut std_submodules = vec![
-        // Loaded at startup - Not technically part of std
+        // Home of `banner`; imported on demand when the banner is shown
         (
             "mod.nu",
             "std/prelude",
@@ -98,23 +97,98 @@ pub fn load_standard_library(
 
     // Submodules/subdirectories ... std-rfc/<module>/mod.nu
     let mut std_rfc_submodules = vec![
+        (
+            "mod.nu",
+            "std-rfc/abbr",
+            include_str!("../std-rfc/abbr/mod.nu"),
+        ),
         (
             "mod.nu",
             "std-rfc/clip",
             include_str!("../std-rfc/clip/mod.nu"),
         ),
+        (
+            "mod.nu",
+            "std-rfc/completions",
+            include_str!("../std-rfc/completions/mod.nu"),
+        ),
         (
             "mod.nu",
             "std-rfc/conversions",
             include_str!("../std-rfc/conversions/mod.nu"),
         ),
+        (
+            "mod.nu",
+            "std-rfc/cloud",
+            include_str!("../std-rfc/cloud/mod.nu"),
+        ),
+        (
+            "mod.nu",
+            "std-rfc/container",
+            include_str!("../std-rfc/container/mod.nu"),
+        ),
+        (
+            "mod.nu",
+            "std-rfc/darwin",
+            include_str!("../std-rfc/darwin/mod.nu"),
+        ),
+        (
+            "mod.nu",
+            "std-rfc/kube",
+            include_str!("../std-rfc/kube/mod.nu"),
+        ),
+        (
+            "mod.nu",
+            "std-rfc/jump",
+            include_str!("../std-rfc/jump/mod.nu"),
+        ),
         #[cfg(feature = "sqlite")]
         ("mod.nu", "std-rfc/kv", include_str!("../std-rfc/kv/mod.nu")),
+        (
+            "mod.nu",
+            "std-rfc/rename-files",
+            include_str!("../std-rfc/rename-files/mod.nu"),
+        ),
+        (
+            "mod.nu",
+            "std-rfc/secrets",
+            include_str!("../std-rfc/secrets/mod.nu"),
+        ),
+        (
+            "mod.nu",
+            "std-rfc/series",
+            include_str!("../std-rfc/series/mod.nu"),
+        ),
+        (
+            "mod.nu",
+            "std-rfc/session",
+            include_str!("../std-rfc/session/mod.nu"),
+        ),
         (
             "mod.nu",
             "std-rfc/path",
             include_str!("../std-rfc/path/mod.nu"),
         ),
+        (
+            "mod.nu",
+            "std-rfc/windows",
+            include_str!("../std-rfc/windows/mod.nu"),
+        ),
+        (
+            "mod.nu",
+            "std-rfc/systemd",
+            include_str!("../std-rfc/systemd/mod.nu"),
+        ),
+        (
+            "mod.nu",
+            "std-rfc/tour",
+            include_str!("../std-rfc/tour/mod.nu"),
+        ),
+        (
+            "mod.nu",
+            "std-rfc/theme",
+            include_str!("../std-rfc/theme/mod.nu"),
+        ),
         (
             "mod.nu",
             "std-rfc/str",
@@ -147,36 +221,11 @@ pub fn load_standard_library(
     let std_rfc_dir = PathBuf::from("std-rfc").to_string_lossy().to_string();
     let _ = working_set.add_virtual_path(std_rfc_dir, VirtualPath::Dir(std_rfc_virt_paths));
 
-    // Load prelude
-    let (_, delta) = {
-        let source = r#"
-# Prelude
-use std/prelude *
-"#;
-
-        // Add a placeholder file to the stack of files being evaluated.
-        // The name of this file doesn't matter; it's only there to set the current working directory to NU_STDLIB_VIRTUAL_DIR.
-        let placeholder = PathBuf::from("load std/prelude");
-        working_set.files = FileStack::with_file(placeholder);
-
-        let block = parse(
-            &mut working_set,
-            Some("loading stdlib prelude"),
-            source.as_bytes(),
-            false,
-        );
-
-        // Remove the placeholder file from the stack of files being evaluated.
-        working_set.files.pop();
-
-        if let Some(err) = working_set.parse_errors.first() {
-            report_parse_error(&working_set, err);
-        }
-
-        (block, working_set.render())
-    };
-
-    engine_state.merge_delta(delta)?;
+    // Note that nothing is parsed here: registering the virtual files is cheap, and each
+    // module (including std/prelude) is only parsed when it is first `use`d. Parsing the
+    // prelude eagerly used to cost more than the rest of startup combined, for two commands
+    // (`banner` and `pwd`) that are now built-ins / loaded on demand.
+    engine_state.merge_delta(working_set.render())?;
 
     Ok(())
 }
@@ -1,4 +1,6 @@
-use nu_plugin::{EngineInterface, EvaluatedCall, SimplePluginCommand};
+use nu_plugin::{
+    CompletionItem, CompletionRequest, EngineInterface, EvaluatedCall, SimplePluginCommand,
+};
 use nu_protocol::{Category, IntoValue, LabeledError, Signature, SyntaxShape, Value};
 
 use crate::ExamplePlugin;
@@ -59,4 +61,21 @@ impl SimplePluginCommand for Two {
 
         Ok(Value::list(vals, call.head))
     }
+
+    // Demonstrates plugin-provided argument completions: press tab on an argument of
+    // `example two` to see these candidates (the engine filters them by the partial word)
+    fn complete_argument(
+        &self,
+        _plugin: &ExamplePlugin,
+        _engine: &EngineInterface,
+        _request: &CompletionRequest,
+    ) -> Vec<CompletionItem> {
+        ["alpha", "beta", "gamma"]
+            .into_iter()
+            .map(|value| CompletionItem {
+                value: value.into(),
+                description: Some("example candidate".into()),
+            })
+            .collect()
+    }
 }
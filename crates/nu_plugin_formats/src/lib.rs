@@ -8,7 +8,9 @@ use from::ics::FromIcs;
 use from::ini::FromIni;
 use from::plist::FromPlist;
 use from::vcf::FromVcf;
+use to::ics::ToIcs;
 use to::plist::IntoPlist;
+use to::vcf::ToVcf;
 
 pub struct FormatCmdsPlugin;
 
@@ -25,6 +27,8 @@ impl Plugin for FormatCmdsPlugin {
             Box::new(FromVcf),
             Box::new(FromPlist),
             Box::new(IntoPlist),
+            Box::new(ToIcs),
+            Box::new(ToVcf),
         ]
     }
 }
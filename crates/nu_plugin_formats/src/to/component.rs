@@ -0,0 +1,166 @@
+//! Shared serialization of the `{name, value, params}` property structure that `from ics` and
+//! `from vcf` produce, back into the iCalendar/vCard text format.
+
+use nu_protocol::{LabeledError, Value};
+
+/// Serialize a list of `{name, value, params}` property records as content lines into `output`.
+pub fn write_properties(output: &mut String, properties: &Value) -> Result<(), LabeledError> {
+    let Value::List { vals, .. } = properties else {
+        return Ok(());
+    };
+    for property in vals {
+        let Value::Record { val: property, .. } = property else {
+            continue;
+        };
+        let Some(name) = property.get("name").and_then(|name| name.as_str().ok()) else {
+            continue;
+        };
+        let mut line = String::from(name);
+        if let Some(Value::Record { val: params, .. }) = property.get("params") {
+            for (param, values) in params.iter() {
+                line.push(';');
+                line.push_str(param);
+                line.push('=');
+                match values {
+                    Value::List { vals, .. } => {
+                        let values: Vec<String> = vals
+                            .iter()
+                            .filter_map(|value| value.as_str().ok().map(String::from))
+                            .collect();
+                        line.push_str(&values.join(","));
+                    }
+                    other => {
+                        if let Ok(value) = other.coerce_str() {
+                            line.push_str(&value);
+                        }
+                    }
+                }
+            }
+        }
+        line.push(':');
+        if let Some(value) = property.get("value") {
+            if let Ok(value) = value.coerce_str() {
+                line.push_str(&escape_text(&value));
+            }
+        }
+        write_folded(output, &line);
+    }
+    Ok(())
+}
+
+/// The nested component lists each component kind may carry, matching the shape produced by
+/// `from ics`.
+fn nested_components(kind: &str) -> &'static [(&'static str, &'static str)] {
+    match kind {
+        "VCALENDAR" => &[
+            ("events", "VEVENT"),
+            ("alarms", "VALARM"),
+            ("to_dos", "VTODO"),
+            ("journals", "VJOURNAL"),
+            ("free_busys", "VFREEBUSY"),
+            ("timezones", "VTIMEZONE"),
+        ],
+        "VEVENT" | "VTODO" => &[("alarms", "VALARM")],
+        _ => &[],
+    }
+}
+
+/// Serialize a `BEGIN:<kind>` .. `END:<kind>` component with its properties and any nested
+/// components.
+pub fn write_component(
+    output: &mut String,
+    kind: &str,
+    component: &Value,
+) -> Result<(), LabeledError> {
+    output.push_str(&format!("BEGIN:{kind}\r\n"));
+    if let Value::Record { val, .. } = component {
+        if let Some(properties) = val.get("properties") {
+            write_properties(output, properties)?;
+        }
+        for (key, nested_kind) in nested_components(kind) {
+            if let Some(Value::List { vals, .. }) = val.get(*key) {
+                for inner in vals {
+                    write_component(output, nested_kind, inner)?;
+                }
+            }
+        }
+    }
+    output.push_str(&format!("END:{kind}\r\n"));
+    Ok(())
+}
+
+/// Escape TEXT values per RFC 5545/6350: `\`, `;`, `,`, and newlines. `from ics` and `from vcf`
+/// hand existing escape sequences through verbatim, so those are left alone to keep
+/// `from ics | to ics` round trips byte-identical.
+fn escape_text(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if matches!(chars.peek(), Some('\\' | ';' | ',' | 'n' | 'N')) {
+                    escaped.push('\\');
+                    escaped.push(chars.next().expect("peeked above"));
+                } else {
+                    escaped.push_str("\\\\");
+                }
+            }
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Write a content line folded at 75 octets, continuing with CRLF plus a space (RFC 5545 §3.1).
+fn write_folded(output: &mut String, line: &str) {
+    let mut remaining = line;
+    // The space continuation lines start with counts against their 75 octets
+    let mut limit = 75;
+    while remaining.len() > limit {
+        let mut split = limit;
+        while !remaining.is_char_boundary(split) {
+            split -= 1;
+        }
+        output.push_str(&remaining[..split]);
+        output.push_str("\r\n ");
+        remaining = &remaining[split..];
+        limit = 74;
+    }
+    output.push_str(remaining);
+    output.push_str("\r\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_special_characters() {
+        assert_eq!(escape_text("a, b; c\nd"), "a\\, b\\; c\\nd");
+        assert_eq!(escape_text("back\\slash"), "back\\\\slash");
+    }
+
+    #[test]
+    fn keeps_existing_escapes_verbatim() {
+        assert_eq!(
+            escape_text("a\\, b\\; c \\\\ d\\nend"),
+            "a\\, b\\; c \\\\ d\\nend"
+        );
+    }
+
+    #[test]
+    fn folds_long_lines() {
+        let mut output = String::new();
+        write_folded(&mut output, &format!("SUMMARY:{}", "x".repeat(100)));
+        for line in output.split("\r\n") {
+            assert!(line.len() <= 75, "line too long: {}", line.len());
+        }
+        assert_eq!(
+            output.replace("\r\n ", ""),
+            format!("SUMMARY:{}\r\n", "x".repeat(100))
+        );
+    }
+}
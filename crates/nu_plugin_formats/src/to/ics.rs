@@ -0,0 +1,84 @@
+use crate::{to::component::write_component, FormatCmdsPlugin};
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand, SimplePluginCommand};
+use nu_protocol::{Category, Example, LabeledError, Signature, Type, Value};
+
+pub struct ToIcs;
+
+impl SimplePluginCommand for ToIcs {
+    type Plugin = FormatCmdsPlugin;
+
+    fn name(&self) -> &str {
+        "to ics"
+    }
+
+    fn description(&self) -> &str {
+        "Convert calendar data (in the shape produced by `from ics`) to .ics text."
+    }
+
+    fn extra_description(&self) -> &str {
+        "`... | from ics | to ics` round-trips, so calendars can be read, edited as tables,
+and written back."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(PluginCommand::name(self))
+            .input_output_types(vec![(Type::Any, Type::String)])
+            .category(Category::Formats)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            example: "[{properties: [[name value params]; [VERSION \"2.0\" null]], events: [], alarms: [], to_dos: [], journals: [], free_busys: [], timezones: []}] | to ics",
+            description: "Write a minimal calendar as ics text",
+            result: Some(Value::test_string(
+                "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nEND:VCALENDAR\r\n",
+            )),
+        }]
+    }
+
+    fn run(
+        &self,
+        _plugin: &FormatCmdsPlugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let calendars = match input {
+            Value::List { vals, .. } => vals.clone(),
+            record @ Value::Record { .. } => vec![record.clone()],
+            other => {
+                return Err(LabeledError::new("Expected calendar data")
+                    .with_label(format!("found {}", other.get_type()), other.span()));
+            }
+        };
+
+        let mut output = String::new();
+        for calendar in &calendars {
+            write_component(&mut output, "VCALENDAR", calendar)?;
+        }
+        Ok(Value::string(output, call.head))
+    }
+}
+
+#[test]
+fn test_examples() -> Result<(), nu_protocol::ShellError> {
+    use nu_plugin_test_support::PluginTest;
+
+    PluginTest::new("formats", crate::FormatCmdsPlugin.into())?.test_command_examples(&ToIcs)
+}
+
+#[test]
+fn escaped_text_round_trips() -> Result<(), nu_protocol::ShellError> {
+    use nu_plugin_test_support::PluginTest;
+    use nu_protocol::{IntoPipelineData, Span};
+
+    let source = "BEGIN:VCALENDAR\r\nBEGIN:VEVENT\r\nSUMMARY:lunch\\, then 1\\; maybe 2\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n";
+    let output = PluginTest::new("formats", crate::FormatCmdsPlugin.into())?
+        .eval_with(
+            "from ics | to ics",
+            Value::string(source, Span::test_data()).into_pipeline_data(),
+        )?
+        .into_value(Span::test_data())?;
+    assert_eq!(output.as_str()?, source);
+    Ok(())
+}
@@ -1 +1,4 @@
+pub(crate) mod component;
+pub(crate) mod ics;
 pub(crate) mod plist;
+pub(crate) mod vcf;
@@ -0,0 +1,66 @@
+use crate::{to::component::write_component, FormatCmdsPlugin};
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand, SimplePluginCommand};
+use nu_protocol::{Category, Example, LabeledError, Signature, Type, Value};
+
+pub struct ToVcf;
+
+impl SimplePluginCommand for ToVcf {
+    type Plugin = FormatCmdsPlugin;
+
+    fn name(&self) -> &str {
+        "to vcf"
+    }
+
+    fn description(&self) -> &str {
+        "Convert contact data (in the shape produced by `from vcf`) to .vcf text."
+    }
+
+    fn extra_description(&self) -> &str {
+        "`... | from vcf | to vcf` round-trips, so contacts can be read, edited as tables,
+and written back."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(PluginCommand::name(self))
+            .input_output_types(vec![(Type::Any, Type::String)])
+            .category(Category::Formats)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            example: "[{properties: [[name value params]; [FN Bar null]]}] | to vcf",
+            description: "Write a single contact as vcf text",
+            result: Some(Value::test_string("BEGIN:VCARD\r\nFN:Bar\r\nEND:VCARD\r\n")),
+        }]
+    }
+
+    fn run(
+        &self,
+        _plugin: &FormatCmdsPlugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let contacts = match input {
+            Value::List { vals, .. } => vals.clone(),
+            record @ Value::Record { .. } => vec![record.clone()],
+            other => {
+                return Err(LabeledError::new("Expected contact data")
+                    .with_label(format!("found {}", other.get_type()), other.span()));
+            }
+        };
+
+        let mut output = String::new();
+        for contact in &contacts {
+            write_component(&mut output, "VCARD", contact)?;
+        }
+        Ok(Value::string(output, call.head))
+    }
+}
+
+#[test]
+fn test_examples() -> Result<(), nu_protocol::ShellError> {
+    use nu_plugin_test_support::PluginTest;
+
+    PluginTest::new("formats", crate::FormatCmdsPlugin.into())?.test_command_examples(&ToVcf)
+}
@@ -0,0 +1,82 @@
+use git2::Repository;
+use nu_protocol::{record, LabeledError, Span, Value};
+use std::path::Path;
+
+/// The `gstat log` command: a structured view of the commit log.
+#[derive(Default)]
+pub struct GLog;
+
+impl GLog {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn glog(
+        &self,
+        current_dir: &str,
+        path: Option<String>,
+        max_count: usize,
+        with_stat: bool,
+        span: Span,
+    ) -> Result<Value, LabeledError> {
+        let path = path.unwrap_or_else(|| ".".into());
+        let absolute_path = Path::new(current_dir).join(path);
+        let repo = Repository::discover(&absolute_path).map_err(|err| {
+            LabeledError::new("error opening repository").with_label(err.to_string(), span)
+        })?;
+
+        let mut revwalk = repo.revwalk().map_err(|err| {
+            LabeledError::new("error walking repository").with_label(err.to_string(), span)
+        })?;
+        revwalk.push_head().map_err(|err| {
+            LabeledError::new("error walking repository").with_label(err.to_string(), span)
+        })?;
+
+        let mut commits = Vec::new();
+        for oid in revwalk.take(max_count) {
+            let Ok(oid) = oid else { continue };
+            let Ok(commit) = repo.find_commit(oid) else {
+                continue;
+            };
+            let author = commit.author();
+            let date = Value::date(
+                chrono::DateTime::from_timestamp(commit.time().seconds(), 0)
+                    .unwrap_or_default()
+                    .fixed_offset(),
+                span,
+            );
+
+            let mut entry = record! {
+                "hash" => Value::string(oid.to_string(), span),
+                "author" => Value::string(author.name().unwrap_or_default(), span),
+                "email" => Value::string(author.email().unwrap_or_default(), span),
+                "date" => date,
+                "message" => Value::string(
+                    commit.message().unwrap_or_default().trim_end(),
+                    span,
+                ),
+            };
+
+            if with_stat {
+                // Per-commit diff stats against the first parent (or the empty tree)
+                let parent_tree = commit.parent(0).ok().and_then(|parent| parent.tree().ok());
+                let tree = commit.tree().ok();
+                let stats = repo
+                    .diff_tree_to_tree(parent_tree.as_ref(), tree.as_ref(), None)
+                    .and_then(|diff| diff.stats());
+                if let Ok(stats) = stats {
+                    entry.push(
+                        "files_changed",
+                        Value::int(stats.files_changed() as i64, span),
+                    );
+                    entry.push("insertions", Value::int(stats.insertions() as i64, span));
+                    entry.push("deletions", Value::int(stats.deletions() as i64, span));
+                }
+            }
+
+            commits.push(Value::record(entry, span));
+        }
+
+        Ok(Value::list(commits, span))
+    }
+}
@@ -21,6 +21,7 @@ impl GStat {
         value: &Value,
         current_dir: &str,
         path: Option<Spanned<String>>,
+        all_branches: bool,
         span: Span,
     ) -> Result<Value, LabeledError> {
         // use std::any::Any;
@@ -80,7 +81,7 @@ impl GStat {
         };
 
         let (stats, repo) = if let Ok(mut repo) = Repository::discover(repo_path) {
-            (Stats::new(&mut repo), repo)
+            (Stats::new(&mut repo, all_branches), repo)
         } else {
             return Ok(self.create_empty_git_status(span));
         };
@@ -132,6 +133,39 @@ impl GStat {
                 "tag" => Value::string(tag, span),
                 "branch" => Value::string(stats.branch, span),
                 "remote" => Value::string(stats.remote, span),
+                "file_statuses" => Value::list(
+                    stats
+                        .file_statuses
+                        .into_iter()
+                        .map(|(name, status)| {
+                            Value::record(
+                                record! {
+                                    "name" => Value::string(name, span),
+                                    "status" => Value::string(status, span),
+                                },
+                                span,
+                            )
+                        })
+                        .collect(),
+                    span,
+                ),
+                "branches" => Value::list(
+                    stats
+                        .branches
+                        .into_iter()
+                        .map(|(name, ahead, behind)| {
+                            Value::record(
+                                record! {
+                                    "name" => Value::string(name, span),
+                                    "ahead" => Value::int(ahead, span),
+                                    "behind" => Value::int(behind, span),
+                                },
+                                span,
+                            )
+                        })
+                        .collect(),
+                    span,
+                ),
             },
             span,
         ))
@@ -159,6 +193,8 @@ impl GStat {
                 "tag" => Value::string("no_tag", span),
                 "branch" => Value::string("no_branch", span),
                 "remote" => Value::string("no_remote", span),
+                "file_statuses" => Value::list(vec![], span),
+                "branches" => Value::list(vec![], span),
             },
             span,
         )
@@ -168,6 +204,10 @@ impl GStat {
 /// Stats which the interpreter uses to populate the gist expression
 #[derive(Debug, PartialEq, Eq, Default, Clone)]
 pub struct Stats {
+    /// Per-file status entries as (path, status) pairs
+    pub file_statuses: Vec<(String, &'static str)>,
+    /// Per-branch (name, ahead, behind) relative to its upstream, where known
+    pub branches: Vec<(String, i64, i64)>,
     /// Number of files to be added
     pub idx_added_staged: u16,
     /// Number of staged changes to files
@@ -209,7 +249,7 @@ pub struct Stats {
 
 impl Stats {
     /// Populate stats with the status of the given repository
-    pub fn new(repo: &mut Repository) -> Stats {
+    pub fn new(repo: &mut Repository, all_branches: bool) -> Stats {
         let mut st: Stats = Default::default();
 
         st.read_branch(repo);
@@ -224,6 +264,12 @@ impl Stats {
             for status in statuses.iter() {
                 let flags = status.status();
 
+                if let Some(path) = status.path() {
+                    if let Some(name) = status_name(flags) {
+                        st.file_statuses.push((path.to_string(), name));
+                    }
+                }
+
                 if check(flags, git2::Status::INDEX_NEW) {
                     st.idx_added_staged += 1;
                 }
@@ -265,6 +311,12 @@ impl Stats {
             }
         }
 
+        // Walking every branch's ahead/behind graph can be slow in big repos, and gstat is
+        // meant for prompts, so it's opt-in
+        if all_branches {
+            st.read_all_branches(repo);
+        }
+
         let _ = repo.stash_foreach(|_, &_, &_| {
             st.stashes += 1;
             true
@@ -332,6 +384,30 @@ impl Stats {
         };
     }
 
+    /// Collect every local branch with its ahead/behind counts relative to its upstream
+    fn read_all_branches(&mut self, repo: &Repository) {
+        let Ok(branches) = repo.branches(Some(git2::BranchType::Local)) else {
+            return;
+        };
+        for branch in branches.flatten() {
+            let (branch, _) = branch;
+            let Some(name) = branch.name().ok().flatten().map(String::from) else {
+                continue;
+            };
+            let (ahead, behind) = match branch.upstream() {
+                Ok(upstream) => match (branch.get().target(), upstream.get().target()) {
+                    (Some(local), Some(upstream)) => repo
+                        .graph_ahead_behind(local, upstream)
+                        .map(|(ahead, behind)| (ahead as i64, behind as i64))
+                        .unwrap_or((-1, -1)),
+                    _ => (-1, -1),
+                },
+                Err(_) => (-1, -1),
+            };
+            self.branches.push((name, ahead, behind));
+        }
+    }
+
     /// Read ahead-behind information between the local and upstream branches
     fn read_ahead_behind(&mut self, repo: &Repository, local: &Branch, upstream: &Branch) {
         if let (Some(local), Some(upstream)) = (local.get().target(), upstream.get().target()) {
@@ -359,6 +435,35 @@ impl Stats {
 //     }
 // }
 
+/// The most significant status flag of a file, as a short name
+fn status_name(flags: git2::Status) -> Option<&'static str> {
+    if check(flags, git2::Status::CONFLICTED) {
+        Some("conflicted")
+    } else if check(flags, git2::Status::INDEX_NEW) {
+        Some("staged_added")
+    } else if check(flags, git2::Status::INDEX_MODIFIED) {
+        Some("staged_modified")
+    } else if check(flags, git2::Status::INDEX_DELETED) {
+        Some("staged_deleted")
+    } else if check(flags, git2::Status::INDEX_RENAMED) {
+        Some("staged_renamed")
+    } else if check(flags, git2::Status::INDEX_TYPECHANGE) {
+        Some("staged_type_changed")
+    } else if check(flags, git2::Status::WT_NEW) {
+        Some("untracked")
+    } else if check(flags, git2::Status::WT_MODIFIED) {
+        Some("modified")
+    } else if check(flags, git2::Status::WT_DELETED) {
+        Some("deleted")
+    } else if check(flags, git2::Status::WT_RENAMED) {
+        Some("renamed")
+    } else if check(flags, git2::Status::WT_TYPECHANGE) {
+        Some("type_changed")
+    } else {
+        None
+    }
+}
+
 /// Check the bits of a flag against the value to see if they are set
 #[inline]
 fn check<B>(val: B, flag: B) -> bool
@@ -1,5 +1,7 @@
+mod glog;
 mod gstat;
 mod nu;
 
+pub use glog::GLog;
 pub use gstat::GStat;
 pub use nu::GStatPlugin;
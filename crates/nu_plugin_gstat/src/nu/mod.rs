@@ -1,4 +1,4 @@
-use crate::GStat;
+use crate::{GLog, GStat};
 use nu_plugin::{EngineInterface, EvaluatedCall, Plugin, PluginCommand, SimplePluginCommand};
 use nu_protocol::{Category, LabeledError, Signature, Spanned, SyntaxShape, Value};
 
@@ -10,7 +10,7 @@ impl Plugin for GStatPlugin {
     }
 
     fn commands(&self) -> Vec<Box<dyn PluginCommand<Plugin = Self>>> {
-        vec![Box::new(GStat)]
+        vec![Box::new(GStat), Box::new(GLog)]
     }
 }
 
@@ -28,6 +28,11 @@ impl SimplePluginCommand for GStat {
     fn signature(&self) -> Signature {
         Signature::build(PluginCommand::name(self))
             .optional("path", SyntaxShape::Filepath, "path to repo")
+            .switch(
+                "all-branches",
+                "include every local branch with its ahead/behind counts (can be slow in large repos)",
+                Some('b'),
+            )
             .category(Category::Custom("prompt".to_string()))
     }
 
@@ -39,8 +44,61 @@ impl SimplePluginCommand for GStat {
         input: &Value,
     ) -> Result<Value, LabeledError> {
         let repo_path: Option<Spanned<String>> = call.opt(0)?;
+        let all_branches = call.has_flag("all-branches")?;
         // eprintln!("input value: {:#?}", &input);
         let current_dir = engine.get_current_dir()?;
-        self.gstat(input, &current_dir, repo_path, call.head)
+        self.gstat(input, &current_dir, repo_path, all_branches, call.head)
+    }
+}
+
+impl SimplePluginCommand for GLog {
+    type Plugin = GStatPlugin;
+
+    fn name(&self) -> &str {
+        "gstat log"
+    }
+
+    fn description(&self) -> &str {
+        "Get the git commit log of a repo as a table"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(PluginCommand::name(self))
+            .optional("path", SyntaxShape::Filepath, "path to repo")
+            .named(
+                "max-count",
+                SyntaxShape::Int,
+                "maximum number of commits to return (default 100)",
+                Some('n'),
+            )
+            .switch(
+                "stat",
+                "include files_changed/insertions/deletions per commit",
+                Some('s'),
+            )
+            .category(Category::Custom("prompt".to_string()))
+    }
+
+    fn run(
+        &self,
+        _plugin: &GStatPlugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        _input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let repo_path: Option<Spanned<String>> = call.opt(0)?;
+        let max_count: usize = call
+            .get_flag::<i64>("max-count")?
+            .map(|n| n.max(0) as usize)
+            .unwrap_or(100);
+        let with_stat = call.has_flag("stat")?;
+        let current_dir = engine.get_current_dir()?;
+        self.glog(
+            &current_dir,
+            repo_path.map(|path| path.item),
+            max_count,
+            with_stat,
+            call.head,
+        )
     }
 }
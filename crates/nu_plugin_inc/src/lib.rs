@@ -1,5 +1,7 @@
 mod inc;
 mod nu;
+mod semver_cmds;
 
 pub use inc::Inc;
 pub use nu::IncPlugin;
+pub use semver_cmds::{IncMatch, IncParse};
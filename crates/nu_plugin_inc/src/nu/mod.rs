@@ -1,4 +1,4 @@
-use crate::{inc::SemVerAction, Inc};
+use crate::{inc::SemVerAction, semver_cmds, Inc, IncMatch, IncParse};
 use nu_plugin::{EngineInterface, EvaluatedCall, Plugin, PluginCommand, SimplePluginCommand};
 use nu_protocol::{ast::CellPath, LabeledError, Signature, SyntaxShape, Value};
 
@@ -10,7 +10,7 @@ impl Plugin for IncPlugin {
     }
 
     fn commands(&self) -> Vec<Box<dyn PluginCommand<Plugin = Self>>> {
-        vec![Box::new(Inc::new())]
+        vec![Box::new(Inc::new()), Box::new(IncParse), Box::new(IncMatch)]
     }
 }
 
@@ -71,3 +71,70 @@ impl SimplePluginCommand for Inc {
         inc.inc(call.head, input)
     }
 }
+
+impl SimplePluginCommand for IncParse {
+    type Plugin = IncPlugin;
+
+    fn name(&self) -> &str {
+        "inc parse"
+    }
+
+    fn description(&self) -> &str {
+        "Parse a semver version into a record of its components."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(PluginCommand::name(self))
+    }
+
+    fn run(
+        &self,
+        _plugin: &IncPlugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let version = input.coerce_str()?;
+        let version = semver_cmds::parse_version(&version, call.head, input.span())?;
+        Ok(semver_cmds::version_to_record(&version, call.head))
+    }
+}
+
+impl SimplePluginCommand for IncMatch {
+    type Plugin = IncPlugin;
+
+    fn name(&self) -> &str {
+        "inc match"
+    }
+
+    fn description(&self) -> &str {
+        "Check whether a semver version satisfies a requirement like `^1.2` or `>=1, <2`."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(PluginCommand::name(self)).required(
+            "requirement",
+            SyntaxShape::String,
+            "the semver requirement to match against",
+        )
+    }
+
+    fn run(
+        &self,
+        _plugin: &IncPlugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let requirement: nu_protocol::Spanned<String> = call.req(0)?;
+        let version = input.coerce_str()?;
+        let matches = semver_cmds::matches_requirement(
+            &version,
+            &requirement.item,
+            call.head,
+            input.span(),
+            requirement.span,
+        )?;
+        Ok(Value::bool(matches, call.head))
+    }
+}
@@ -0,0 +1,46 @@
+use nu_protocol::{record, LabeledError, Span, Value};
+use semver::{Version, VersionReq};
+
+/// `inc parse`: break a semver string into its components.
+#[derive(Default, Clone)]
+pub struct IncParse;
+
+/// `inc match`: check a version against a semver requirement like `^1.2`.
+#[derive(Default, Clone)]
+pub struct IncMatch;
+
+pub fn parse_version(input: &str, head: Span, value_span: Span) -> Result<Version, LabeledError> {
+    Version::parse(input.trim().trim_start_matches('v')).map_err(|err| {
+        LabeledError::new("Value is not a valid semver version")
+            .with_label(err.to_string(), value_span)
+            .with_label("while parsing this version", head)
+    })
+}
+
+pub fn version_to_record(version: &Version, head: Span) -> Value {
+    Value::record(
+        record! {
+            "major" => Value::int(version.major as i64, head),
+            "minor" => Value::int(version.minor as i64, head),
+            "patch" => Value::int(version.patch as i64, head),
+            "pre" => Value::string(version.pre.as_str(), head),
+            "build" => Value::string(version.build.as_str(), head),
+        },
+        head,
+    )
+}
+
+pub fn matches_requirement(
+    version: &str,
+    requirement: &str,
+    head: Span,
+    version_span: Span,
+    requirement_span: Span,
+) -> Result<bool, LabeledError> {
+    let version = parse_version(version, head, version_span)?;
+    let requirement = VersionReq::parse(requirement).map_err(|err| {
+        LabeledError::new("Value is not a valid semver requirement")
+            .with_label(err.to_string(), requirement_span)
+    })?;
+    Ok(requirement.matches(&version))
+}
@@ -179,6 +179,7 @@ fn command(
             PolarsFileType::Json => from_json(plugin, engine, call, resource, is_eager),
             PolarsFileType::NdJson => from_ndjson(plugin, engine, call, resource, is_eager),
             PolarsFileType::Avro => from_avro(plugin, engine, call, resource, is_eager),
+            PolarsFileType::Delta => from_delta(plugin, engine, call, resource, is_eager),
             _ => Err(PolarsFileType::build_unsupported_error(
                 &ext,
                 &[
@@ -188,10 +189,15 @@ fn command(
                     PolarsFileType::Arrow,
                     PolarsFileType::NdJson,
                     PolarsFileType::Avro,
+                    PolarsFileType::Delta,
                 ],
                 blamed,
             )),
         },
+        // A directory containing a _delta_log is a Delta Lake table
+        None if PathBuf::from(&resource.path).join("_delta_log").is_dir() => {
+            from_delta(plugin, engine, call, resource, is_eager)
+        }
         None => Err(ShellError::Io(IoError::new_with_additional_context(
             std::io::ErrorKind::NotFound,
             spanned_file.span,
@@ -202,6 +208,105 @@ fn command(
     .map(|value| PipelineData::Value(value, None))
 }
 
+/// Open a Delta Lake table by replaying its JSON commit log and scanning the active parquet
+/// files. Checkpointed tables aren't supported yet; those logs require reading the checkpoint
+/// parquet as well.
+fn from_delta(
+    plugin: &PolarsPlugin,
+    engine: &nu_plugin::EngineInterface,
+    call: &nu_plugin::EvaluatedCall,
+    resource: Resource,
+    is_eager: bool,
+) -> Result<Value, ShellError> {
+    let file_span = resource.span;
+    let table_root = PathBuf::from(&resource.path);
+    let log_dir = table_root.join("_delta_log");
+    let delta_error = |msg: String| ShellError::GenericError {
+        error: "Delta table error".into(),
+        msg,
+        span: Some(file_span),
+        help: None,
+        inner: vec![],
+    };
+
+    if log_dir.join("_last_checkpoint").exists() {
+        return Err(delta_error(
+            "checkpointed delta tables are not supported yet".into(),
+        ));
+    }
+
+    let mut commits: Vec<PathBuf> = std::fs::read_dir(&log_dir)
+        .map_err(|e| delta_error(format!("can't read _delta_log: {e}")))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    commits.sort();
+
+    // Replay the log: `add` actions introduce data files, `remove` actions retire them
+    let mut active: Vec<String> = Vec::new();
+    for commit in commits {
+        let contents = std::fs::read_to_string(&commit)
+            .map_err(|e| delta_error(format!("can't read {}: {e}", commit.display())))?;
+        for line in contents.lines().filter(|line| !line.trim().is_empty()) {
+            let action: serde_json::Value = serde_json::from_str(line)
+                .map_err(|e| delta_error(format!("invalid commit entry: {e}")))?;
+            // Partition columns only exist in the log, not in the parquet files, and deletion
+            // vectors mask rows we would otherwise return; refuse both rather than produce
+            // silently wrong data
+            if action["metaData"]["partitionColumns"]
+                .as_array()
+                .is_some_and(|columns| !columns.is_empty())
+            {
+                return Err(delta_error(
+                    "partitioned delta tables are not supported yet".into(),
+                ));
+            }
+            if let Some(path) = action["add"]["path"].as_str() {
+                if action["add"]["partitionValues"]
+                    .as_object()
+                    .is_some_and(|values| !values.is_empty())
+                {
+                    return Err(delta_error(
+                        "partitioned delta tables are not supported yet".into(),
+                    ));
+                }
+                if !action["add"]["deletionVector"].is_null() {
+                    return Err(delta_error(
+                        "delta tables with deletion vectors are not supported yet".into(),
+                    ));
+                }
+                if !active.iter().any(|p| p == path) {
+                    active.push(path.to_string());
+                }
+            } else if let Some(path) = action["remove"]["path"].as_str() {
+                active.retain(|p| p != path);
+            }
+        }
+    }
+
+    if active.is_empty() {
+        return Err(delta_error(
+            "the delta table has no active data files".into(),
+        ));
+    }
+
+    let files: Vec<PathBuf> = active
+        .into_iter()
+        .map(|path| table_root.join(path))
+        .collect();
+
+    let df: NuLazyFrame = LazyFrame::scan_parquet_files(files.into(), ScanArgsParquet::default())
+        .map_err(|e| delta_error(format!("can't scan table data: {e}")))?
+        .into();
+
+    if is_eager {
+        let df = df.collect(call.head)?;
+        df.cache_and_to_value(plugin, engine, call.head)
+    } else {
+        df.cache_and_to_value(plugin, engine, call.head)
+    }
+}
+
 fn from_parquet(
     plugin: &PolarsPlugin,
     engine: &nu_plugin::EngineInterface,
@@ -27,6 +27,11 @@ impl PluginCommand for LazyCollect {
                 Type::Custom("dataframe".into()),
                 Type::Custom("dataframe".into()),
             )
+            .switch(
+                "streaming",
+                "collect with the streaming engine, which works in batches that can exceed memory and can spill to disk",
+                Some('s'),
+            )
             .category(Category::Custom("lazyframe".into()))
     }
 
@@ -61,10 +66,15 @@ impl PluginCommand for LazyCollect {
         call: &EvaluatedCall,
         input: PipelineData,
     ) -> Result<PipelineData, LabeledError> {
+        let streaming = call.has_flag("streaming")?;
         let value = input.into_value(call.head)?;
         match PolarsPluginObject::try_from_value(plugin, &value)? {
             PolarsPluginObject::NuLazyFrame(lazy) => {
-                let mut eager = lazy.collect(call.head)?;
+                let mut eager = if streaming {
+                    lazy.collect_streaming(call.head)?
+                } else {
+                    lazy.collect(call.head)?
+                };
                 // We don't want this converted back to a lazy frame
                 eager.from_lazy = true;
                 Ok(PipelineData::Value(
@@ -9,6 +9,7 @@ pub enum PolarsFileType {
     Json,
     Avro,
     NdJson,
+    Delta,
     Unknown,
 }
 
@@ -38,6 +39,7 @@ impl PolarsFileType {
             PolarsFileType::Csv => "csv",
             PolarsFileType::Tsv => "tsv",
             PolarsFileType::Parquet => "parquet",
+            PolarsFileType::Delta => "delta",
             PolarsFileType::Arrow => "arrow",
             PolarsFileType::Json => "json",
             PolarsFileType::Avro => "avro",
@@ -57,6 +59,7 @@ impl From<&str> for PolarsFileType {
             "json" => PolarsFileType::Json,
             "avro" => PolarsFileType::Avro,
             "jsonl" | "ndjson" => PolarsFileType::NdJson,
+            "delta" => PolarsFileType::Delta,
             _ => PolarsFileType::Unknown,
         }
     }
@@ -72,6 +72,27 @@ impl NuLazyFrame {
         )
     }
 
+    /// Collect with polars' streaming engine, which processes the query in batches and can
+    /// spill to disk, so inputs larger than memory work.
+    pub fn collect_streaming(self, span: Span) -> Result<NuDataFrame, ShellError> {
+        crate::handle_panic(
+            || {
+                self.to_polars()
+                    .with_streaming(true)
+                    .collect()
+                    .map_err(|e| ShellError::GenericError {
+                        error: "Error collecting lazy frame with the streaming engine".into(),
+                        msg: e.to_string(),
+                        span: Some(span),
+                        help: None,
+                        inner: vec![],
+                    })
+                    .map(|df| NuDataFrame::new(true, df))
+            },
+            span,
+        )
+    }
+
     pub fn apply_with_expr<F>(self, expr: NuExpression, f: F) -> Self
     where
         F: Fn(LazyFrame, Expr) -> LazyFrame,
@@ -37,7 +37,8 @@ pub(crate) fn gather_commandline_args() -> (Vec<String>, String, Vec<String>) {
             "--plugin-config" => args.next().map(|a| escape_quote_string(&a)),
             "--log-level" | "--log-target" | "--log-include" | "--log-exclude" | "--testbin"
             | "--threads" | "-t" | "--include-path" | "--lsp" | "--ide-goto-def"
-            | "--ide-hover" | "--ide-complete" | "--ide-check" => args.next(),
+            | "--ide-hover" | "--ide-complete" | "--ide-check" | "--output-format"
+            | "--stdin-format" | "--generate" => args.next(),
             #[cfg(feature = "plugin")]
             "--plugins" => args.next(),
             _ => None,
@@ -98,6 +99,10 @@ pub(crate) fn parse_commandline_args(
             let config_file = call.get_flag_expr("config");
             let env_file = call.get_flag_expr("env-config");
             let log_level = call.get_flag_expr("log-level");
+            let output_format = call.get_flag_expr("output-format");
+            let stdin_format = call.get_flag_expr("stdin-format");
+            let generate = call.get_flag_expr("generate");
+            let profile_startup = call.get_named_arg("profile-startup");
             let log_target = call.get_flag_expr("log-target");
             let log_include = call.get_flag_expr("log-include");
             let log_exclude = call.get_flag_expr("log-exclude");
@@ -196,6 +201,9 @@ pub(crate) fn parse_commandline_args(
             let config_file = extract_path(config_file)?;
             let env_file = extract_path(env_file)?;
             let log_level = extract_contents(log_level)?;
+            let output_format = extract_contents(output_format)?;
+            let stdin_format = extract_contents(stdin_format)?;
+            let generate = extract_contents(generate)?;
             let log_target = extract_contents(log_target)?;
             let log_include = extract_list(log_include, "string", |expr| expr.as_string())?;
             let log_exclude = extract_list(log_exclude, "string", |expr| expr.as_string())?;
@@ -240,6 +248,10 @@ pub(crate) fn parse_commandline_args(
                 log_target,
                 log_include,
                 log_exclude,
+                profile_startup,
+                output_format,
+                stdin_format,
+                generate,
                 execute,
                 include_path,
                 ide_goto_def,
@@ -281,6 +293,10 @@ pub(crate) struct NushellCliArgs {
     pub(crate) log_target: Option<Spanned<String>>,
     pub(crate) log_include: Option<Vec<Spanned<String>>>,
     pub(crate) log_exclude: Option<Vec<Spanned<String>>>,
+    pub(crate) profile_startup: Option<Spanned<String>>,
+    pub(crate) output_format: Option<Spanned<String>>,
+    pub(crate) stdin_format: Option<Spanned<String>>,
+    pub(crate) generate: Option<Spanned<String>>,
     pub(crate) execute: Option<Spanned<String>>,
     pub(crate) table_mode: Option<Value>,
     pub(crate) error_style: Option<Value>,
@@ -295,7 +311,7 @@ pub(crate) struct NushellCliArgs {
 }
 
 #[derive(Clone)]
-struct Nu;
+pub(crate) struct Nu;
 
 impl Command for Nu {
     fn name(&self) -> &str {
@@ -441,6 +457,29 @@ impl Command for Nu {
                 "set the Rust module prefixes to exclude from the log output",
                 None,
             )
+            .named(
+                "output-format",
+                SyntaxShape::String,
+                "with -c, emit the final pipeline value in the given format (json, nuon, csv, msgpack, ...)",
+                None,
+            )
+            .named(
+                "stdin-format",
+                SyntaxShape::String,
+                "with -c, parse stdin into structured data with the given format (implies --stdin)",
+                None,
+            )
+            .named(
+                "generate",
+                SyntaxShape::String,
+                "generate documentation or completion scripts from the registered commands (man, markdown, bash, zsh, fish) and exit",
+                None,
+            )
+            .switch(
+                "profile-startup",
+                "show startup performance timings on stderr (shorthand for --log-level info)",
+                None,
+            )
             .switch(
                 "stdin",
                 "redirect standard input to a command (with `-c`) or a script file",
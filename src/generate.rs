@@ -0,0 +1,140 @@
+//! Generation of man pages, markdown docs, and shell completion scripts from signature data,
+//! for the top-level `--generate` flag.
+
+use nu_protocol::engine::EngineState;
+
+/// Generate the requested artifact to stdout. Returns an error message for unknown formats.
+pub(crate) fn generate(engine_state: &EngineState, format: &str) -> Result<String, String> {
+    match format {
+        "markdown" => Ok(generate_markdown(engine_state)),
+        "man" => Ok(generate_man(engine_state)),
+        "bash" | "zsh" | "fish" => Ok(generate_completions(engine_state, format)),
+        other => Err(format!(
+            "unknown --generate format `{other}`: expected man, markdown, bash, zsh, or fish"
+        )),
+    }
+}
+
+fn visible_signatures(engine_state: &EngineState) -> Vec<nu_protocol::Signature> {
+    let mut signatures: Vec<_> = engine_state
+        .get_signatures_and_declids(false)
+        .into_iter()
+        .map(|(signature, _)| signature)
+        .collect();
+    signatures.sort_by(|a, b| a.name.cmp(&b.name));
+    signatures
+}
+
+/// Markdown documentation for every registered command, generated from its signature.
+fn generate_markdown(engine_state: &EngineState) -> String {
+    let mut output = String::from("# Nushell commands\n");
+    for signature in visible_signatures(engine_state) {
+        output.push_str(&format!(
+            "\n## {}\n\n{}\n",
+            signature.name, signature.description
+        ));
+        if !signature.extra_description.is_empty() {
+            output.push_str(&format!("\n{}\n", signature.extra_description));
+        }
+        output.push_str(&format!(
+            "\n```\n{}\n```\n",
+            signature.clone().formatted_flags()
+        ));
+        for positional in signature
+            .required_positional
+            .iter()
+            .chain(&signature.optional_positional)
+        {
+            output.push_str(&format!("- `{}`: {}\n", positional.name, positional.desc));
+        }
+        for flag in &signature.named {
+            if flag.long == "help" {
+                continue;
+            }
+            let short = flag
+                .short
+                .map(|short| format!(" (-{short})"))
+                .unwrap_or_default();
+            output.push_str(&format!("- `--{}`{}: {}\n", flag.long, short, flag.desc));
+        }
+    }
+    output
+}
+
+/// A single troff man page with a section per command.
+fn generate_man(engine_state: &EngineState) -> String {
+    let mut output = String::from(
+        ".TH NU-COMMANDS 1 \"\" \"nushell\" \"Nushell Commands\"\n.SH NAME\nnu-commands \\- the commands built into nushell\n",
+    );
+    for signature in visible_signatures(engine_state) {
+        output.push_str(&format!(
+            ".SH {}\n{}\n",
+            signature.name.to_uppercase().replace('-', "\\-"),
+            escape_troff(&signature.description)
+        ));
+        for flag in &signature.named {
+            if flag.long == "help" {
+                continue;
+            }
+            output.push_str(&format!(
+                ".TP\n\\fB\\-\\-{}\\fR\n{}\n",
+                flag.long.replace('-', "\\-"),
+                escape_troff(&flag.desc)
+            ));
+        }
+    }
+    output
+}
+
+fn escape_troff(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('-', "\\-")
+}
+
+/// A completion script for invoking `nu` itself, from the binary's own signature.
+fn generate_completions(_engine_state: &EngineState, shell: &str) -> String {
+    use nu_protocol::engine::Command;
+    let signature = crate::command::Nu.signature();
+    let mut long_flags = Vec::new();
+    let mut entries = Vec::new();
+    for flag in &signature.named {
+        long_flags.push(format!("--{}", flag.long));
+        let desc = flag.desc.replace(['\'', '\n'], " ");
+        entries.push((flag.long.clone(), flag.short, desc));
+    }
+
+    match shell {
+        "bash" => format!(
+            r#"# bash completion for nu
+_nu() {{
+    local cur
+    cur="${{COMP_WORDS[COMP_CWORD]}}"
+    if [[ "$cur" == -* ]]; then
+        COMPREPLY=( $(compgen -W "{flags}" -- "$cur") )
+    else
+        COMPREPLY=( $(compgen -f -- "$cur") )
+    fi
+}}
+complete -F _nu nu
+"#,
+            flags = long_flags.join(" ")
+        ),
+        "zsh" => {
+            let args = entries
+                .iter()
+                .map(|(long, _, desc)| format!("        '--{long}[{desc}]'"))
+                .collect::<Vec<_>>()
+                .join(" \\\n");
+            format!("#compdef nu\n_arguments \\\n{args} \\\n        '*:script:_files'\n")
+        }
+        _ => {
+            let mut out = String::from("# fish completion for nu\n");
+            for (long, short, desc) in entries {
+                let short = short
+                    .map(|short| format!(" -s {short}"))
+                    .unwrap_or_default();
+                out.push_str(&format!("complete -c nu -l {long}{short} -d '{desc}'\n"));
+            }
+            out
+        }
+    }
+}
@@ -1,6 +1,7 @@
 mod command;
 mod command_context;
 mod config_files;
+mod generate;
 mod ide;
 mod logger;
 mod run;
@@ -208,10 +209,19 @@ fn main() -> Result<()> {
         .get(&engine_state);
 
     // Set up logger
+    //
+    // `--profile-startup` is shorthand for `--log-level info`, which is the level the startup
+    // `perf!` timings are emitted at.
     if let Some(level) = parsed_nu_cli_args
         .log_level
         .as_ref()
         .map(|level| level.item.clone())
+        .or_else(|| {
+            parsed_nu_cli_args
+                .profile_startup
+                .as_ref()
+                .map(|_| "info".to_string())
+        })
     {
         let level = if Level::from_str(&level).is_ok() {
             level
@@ -389,7 +399,11 @@ fn main() -> Result<()> {
     perf!("run test_bins", start_time, use_color);
 
     start_time = std::time::Instant::now();
-    let input = if let Some(redirect_stdin) = &parsed_nu_cli_args.redirect_stdin {
+    let input = if let Some(redirect_stdin) = parsed_nu_cli_args
+        .redirect_stdin
+        .as_ref()
+        .or(parsed_nu_cli_args.stdin_format.as_ref())
+    {
         trace!("redirecting stdin");
         PipelineData::ByteStream(ByteStream::stdin(redirect_stdin.span)?, None)
     } else {
@@ -434,8 +448,9 @@ fn main() -> Result<()> {
             // Spawn the plugin to get the metadata and signatures
             let interface = plugin.clone().get_plugin(None)?;
 
-            // Set its metadata
+            // Set its metadata, and count the explicit --plugins flag as a trust grant
             plugin.set_metadata(Some(interface.get_metadata()?));
+            plugin.set_trusted(true);
 
             // Add the commands from the signature to the working set
             for signature in interface.get_signature()? {
@@ -448,6 +463,19 @@ fn main() -> Result<()> {
         perf!("load plugins specified in --plugins", start_time, use_color)
     }
 
+    if let Some(format) = &parsed_nu_cli_args.generate {
+        match generate::generate(&engine_state, &format.item) {
+            Ok(output) => {
+                print!("{output}");
+                return Ok(());
+            }
+            Err(message) => {
+                eprintln!("Error: {message}");
+                std::process::exit(1);
+            }
+        }
+    }
+
     start_time = std::time::Instant::now();
     if parsed_nu_cli_args.lsp {
         perf!("lsp starting", start_time, use_color);
@@ -94,6 +94,8 @@ pub(crate) fn run_commands(
             table_mode: parsed_nu_cli_args.table_mode,
             error_style: parsed_nu_cli_args.error_style,
             no_newline: parsed_nu_cli_args.no_newline.is_some(),
+            output_format: parsed_nu_cli_args.output_format,
+            stdin_format: parsed_nu_cli_args.stdin_format,
         },
     );
     perf!("evaluate_commands", start_time, use_color);
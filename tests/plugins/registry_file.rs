@@ -156,6 +156,7 @@ fn plugin_rm_then_restart_nu() {
             name: "example".into(),
             filename: example_plugin_path,
             shell: None,
+            trusted: true,
             data: valid_plugin_item_data(),
         });
 
@@ -164,6 +165,7 @@ fn plugin_rm_then_restart_nu() {
             // this doesn't exist, but it should be ok
             filename: dirs.test().join("nu_plugin_foo").into(),
             shell: None,
+            trusted: true,
             data: valid_plugin_item_data(),
         });
 
@@ -232,6 +234,7 @@ fn plugin_rm_from_custom_path() {
             name: "example".into(),
             filename: example_plugin_path,
             shell: None,
+            trusted: true,
             data: valid_plugin_item_data(),
         });
 
@@ -240,6 +243,7 @@ fn plugin_rm_from_custom_path() {
             // this doesn't exist, but it should be ok
             filename: dirs.test().join("nu_plugin_foo").into(),
             shell: None,
+            trusted: true,
             data: valid_plugin_item_data(),
         });
 
@@ -280,6 +284,7 @@ fn plugin_rm_using_filename() {
             name: "example".into(),
             filename: example_plugin_path.clone(),
             shell: None,
+            trusted: true,
             data: valid_plugin_item_data(),
         });
 
@@ -288,6 +293,7 @@ fn plugin_rm_using_filename() {
             // this doesn't exist, but it should be ok
             filename: dirs.test().join("nu_plugin_foo").into(),
             shell: None,
+            trusted: true,
             data: valid_plugin_item_data(),
         });
 
@@ -338,6 +344,7 @@ fn warning_on_invalid_plugin_item() {
             name: "example".into(),
             filename: example_plugin_path,
             shell: None,
+            trusted: true,
             data: valid_plugin_item_data(),
         });
 
@@ -346,6 +353,7 @@ fn warning_on_invalid_plugin_item() {
             // this doesn't exist, but it should be ok
             filename: dirs.test().join("nu_plugin_badtest").into(),
             shell: None,
+            trusted: false,
             data: PluginRegistryItemData::Invalid,
         });
 